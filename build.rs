@@ -0,0 +1,22 @@
+//! Generates the card enums, their data tables, deck builder URL codes and the frozen
+//! encoding tables from `cards.json`. The generator itself lives in `build/cards.rs`, where the
+//! crate's tests can also run it.
+
+#[path = "build/cards.rs"]
+mod cards;
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", cards::DATA);
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build/cards.rs");
+
+    let text = fs::read_to_string(cards::DATA).unwrap_or_else(|error| cards::fail(format!("can't read it: {error}")));
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("cards");
+    fs::create_dir_all(&out).unwrap();
+
+    for (file, generated) in cards::generate(&text) {
+        fs::write(out.join(file), generated).unwrap();
+    }
+}
@@ -0,0 +1,603 @@
+//! Generates the card enums, their data tables, deck builder URL codes and the frozen
+//! encoding tables from `cards.json`, so that adding a card only takes one entry there.
+//!
+//! Every field of every card has to be present (`null` where it doesn't apply), and the build
+//! fails on unknown fields, duplicate variants, names or URL codes, and encoding slots that
+//! aren't exactly the ones frozen for that version.
+//!
+//! URL codes have to be the deck builder's own, so cards it doesn't have use a `null` code and
+//! can't be written to a deck builder URL.
+
+use std::{collections::HashSet, fmt::Write};
+use serde_json::{Map, Value};
+
+pub(crate) const DATA: &str = "cards.json";
+
+const ELEMENTS: [&str; 7] = ["Cryo", "Hydro", "Pyro", "Electro", "Anemo", "Geo", "Dendro"];
+const WEAPON_TYPES: [&str; 5] = ["Sword", "Claymore", "Polearm", "Catalyst", "Bow"];
+
+/// The category-specific field of an action card, last in its `info_dump`
+#[derive(Clone, Copy)]
+enum Extra {
+    None,
+    WeaponType,
+    Element,
+    OptionalElement,
+    Character,
+}
+
+struct Category {
+    /// Key of the category in the data file, and name of the generated file
+    key: &'static str,
+    name: &'static str,
+    /// How [`Self::name`]'s `ALL` calls its cards
+    label: &'static str,
+    doc: &'static [&'static str],
+    /// The `PlayingCard` method returning this card
+    accessor: &'static str,
+    /// The variant of [`Self::parent`] holding this card
+    variant: &'static str,
+    parent: &'static str,
+    extra: (&'static str, Extra),
+    /// The table frozen for `ENCODING_VERSION` 2, and its length
+    v2: (&'static str, usize),
+}
+
+const CATEGORIES: [Category; 10] = [
+    Category {
+        key: "talents", name: "TalentCard", label: "talent", doc: &[],
+        accessor: "talent", variant: "Talent", parent: "EquipmentCard",
+        extra: ("character", Extra::Character), v2: ("TALENTS", 32),
+    },
+    Category {
+        key: "weapons", name: "WeaponCard", label: "weapon", doc: &[],
+        accessor: "weapon", variant: "Weapon", parent: "EquipmentCard",
+        extra: ("weapon", Extra::WeaponType), v2: ("WEAPONS", 20),
+    },
+    Category {
+        key: "artifacts", name: "ArtifactCard", label: "artifact", doc: &[],
+        accessor: "artifact", variant: "Artifact", parent: "EquipmentCard",
+        extra: ("element", Extra::OptionalElement), v2: ("ARTIFACTS", 22),
+    },
+    Category {
+        key: "locations", name: "LocationCard", label: "location", doc: &[],
+        accessor: "location", variant: "Location", parent: "SupportCard",
+        extra: ("", Extra::None), v2: ("LOCATIONS", 8),
+    },
+    Category {
+        key: "companions", name: "CompanionCard", label: "companion", doc: &[],
+        accessor: "companion", variant: "Companion", parent: "SupportCard",
+        extra: ("", Extra::None), v2: ("COMPANIONS", 14),
+    },
+    Category {
+        key: "items", name: "ItemCard", label: "item", doc: &[],
+        accessor: "item", variant: "Item", parent: "SupportCard",
+        extra: ("", Extra::None), v2: ("ITEMS", 3),
+    },
+    Category {
+        key: "resonances", name: "ElementalResonanceCard", label: "elemental resonance", doc: &[],
+        accessor: "resonance", variant: "Resonance", parent: "EventCard",
+        extra: ("element", Extra::Element), v2: ("RESONANCES", 14),
+    },
+    Category {
+        key: "normal_events", name: "NormalEventCard", label: "normal event", doc: &[],
+        accessor: "normal_event", variant: "Normal", parent: "EventCard",
+        extra: ("", Extra::None), v2: ("NORMAL_EVENTS", 17),
+    },
+    Category {
+        key: "food", name: "FoodCard", label: "food", doc: &[],
+        accessor: "food", variant: "Food", parent: "EventCard",
+        extra: ("", Extra::None), v2: ("FOOD", 10),
+    },
+    Category {
+        key: "arcane_legends", name: "ArcaneLegendCard", label: "Arcane Legend",
+        doc: &[
+            "Arcane Legend cards, of which a deck can only hold one copy each, and a player can only",
+            "play one per match",
+        ],
+        accessor: "arcane", variant: "Arcane", parent: "EventCard",
+        extra: ("", Extra::None), v2: ("ARCANE_LEGENDS", 3),
+    },
+];
+
+const CHARACTER_FIELDS: [&str; 10] = ["variant", "name", "element", "weapon", "faction", "health", "energy", "version", "code", "v2"];
+const CHARACTERS_V2: (&str, usize) = ("CHARACTERS", 32);
+
+/// The generated files and their contents, from the text of the data file
+/// 
+/// Panics with a message naming the offending card if the data is invalid.
+pub(crate) fn generate(text: &str) -> Vec<(String, String)> {
+    let data: Value = serde_json::from_str(text).unwrap_or_else(|error| fail(format!("invalid JSON: {error}")));
+    let Some(data) = data.as_object() else { fail("expected an object of categories".into()) };
+
+    let expected: Vec<&str> = ["characters"].into_iter().chain(CATEGORIES.iter().map(|category| category.key)).collect();
+    if let Some(key) = data.keys().find(|key| !expected.contains(&key.as_str())) {
+        fail(format!("unknown category `{key}`"));
+    }
+
+    let mut files = Vec::new();
+    let mut names = HashSet::new();
+    let mut codes = HashSet::new();
+    let mut url = Urls::default();
+    let mut tables = String::new();
+
+    let characters = cards(data, "characters");
+    let mut variants = Vec::new();
+    let mut entries = Vec::new();
+    let mut v2 = Vec::new();
+
+    for (index, card) in characters.iter().enumerate() {
+        let card = fields(card, "characters", index, &CHARACTER_FIELDS);
+        let variant = identifier(&card, "variant");
+        let context = format!("characters.{variant}");
+
+        if variants.contains(&variant) {
+            fail(format!("{context}: duplicate variant"));
+        }
+
+        let name = unique_name(&card, &context, &mut names);
+        let code = unique_code(&card, &context, 1, &mut codes);
+        let element = one_of(&card, "element", &ELEMENTS, &context);
+        let weapon = match card["weapon"] {
+            Value::Null => "None".to_string(),
+            _ => format!("Some(crate::WeaponType::{})", one_of(&card, "weapon", &WEAPON_TYPES, &context)),
+        };
+        let faction = identifier(&card, "faction");
+        let health = number(&card, "health", &context, u8::MAX as u64);
+        let energy = number(&card, "energy", &context, u8::MAX as u64);
+        let version = version(&card, &context);
+
+        entries.push(format!(
+            "Self::{variant} => ({name:?}, crate::Element::{element}, {weapon}, crate::Faction::{faction}, {health}, {energy}, {version}),"
+        ));
+        if let Some(code) = code {
+            url.character(&variant, &code);
+        }
+        slot(&card, &context, &mut v2, &variant);
+        variants.push(variant);
+    }
+
+    let character_variants: HashSet<String> = variants.iter().cloned().collect();
+    let variants: Vec<&String> = variants.iter().collect();
+    let mut generated = String::new();
+    write_enum(&mut generated, "CharacterCard", &[], &variants);
+    write_all(&mut generated, "CharacterCard", "character", &variants);
+    writeln!(generated, "impl CharacterCard {{").unwrap();
+    writeln!(generated, "    fn info_dump(&self) -> (&'static str, crate::Element, Option<crate::WeaponType>, crate::Faction, u8, u8, crate::GameVersion) {{").unwrap();
+    write_match(&mut generated, &entries);
+    writeln!(generated, "    }}\n}}\n").unwrap();
+    write_ord(&mut generated, "CharacterCard");
+    files.push(("characters.rs".to_string(), generated));
+    write_table(&mut tables, "CharacterCard", CHARACTERS_V2, v2);
+
+    for category in &CATEGORIES {
+        let mut fields_of = vec!["variant", "name", "price", "cost"];
+        if !category.extra.0.is_empty() {
+            fields_of.push(category.extra.0);
+        }
+        fields_of.extend(["version", "code", "v2"]);
+
+        let list = cards(data, category.key);
+        let mut variants = Vec::new();
+        let mut entries = Vec::new();
+        let mut v2 = Vec::new();
+
+        for (index, card) in list.iter().enumerate() {
+            let card = fields(card, category.key, index, &fields_of);
+            let variant = identifier(&card, "variant");
+            let context = format!("{}.{variant}", category.key);
+
+            if variants.contains(&variant) {
+                fail(format!("{context}: duplicate variant"));
+            }
+
+            let name = unique_name(&card, &context, &mut names);
+            let code = unique_code(&card, &context, 2, &mut codes);
+            let price = match card["price"] {
+                Value::Null => "None".to_string(),
+                _ => format!("Some({})", number(&card, "price", &context, u16::MAX as u64)),
+            };
+            let cost = cost(&card["cost"], &context);
+            let extra = match category.extra.1 {
+                Extra::None => String::new(),
+                Extra::WeaponType => format!(", crate::WeaponType::{}", one_of(&card, "weapon", &WEAPON_TYPES, &context)),
+                Extra::Element => format!(", crate::Element::{}", one_of(&card, "element", &ELEMENTS, &context)),
+                Extra::OptionalElement => match card["element"] {
+                    Value::Null => ", None".to_string(),
+                    _ => format!(", Some(crate::Element::{})", one_of(&card, "element", &ELEMENTS, &context)),
+                },
+                Extra::Character => {
+                    let character = identifier(&card, "character");
+                    if !character_variants.contains(&character) {
+                        fail(format!("{context}: unknown character `{character}`"));
+                    }
+                    format!(", crate::CharacterCard::{character}")
+                },
+            };
+
+            let version = version(&card, &context);
+
+            entries.push(format!("Self::{variant} => ({name:?}, {price}, {cost}{extra}, {version}),"));
+            if let Some(code) = code {
+                url.action(category, &variant, &code);
+            }
+
+            slot(&card, &context, &mut v2, &variant);
+
+            variants.push(variant);
+        }
+
+        let variants: Vec<&String> = variants.iter().collect();
+        let name = category.name;
+        // the version comes right after the category-specific field, if there's one
+        let version_index = if category.extra.0.is_empty() { 3 } else { 4 };
+        let extra_type = match category.extra.1 {
+            Extra::None => "",
+            Extra::WeaponType => ", crate::WeaponType",
+            Extra::Element => ", crate::Element",
+            Extra::OptionalElement => ", Option<crate::Element>",
+            Extra::Character => ", crate::CharacterCard",
+        };
+
+        let mut generated = String::new();
+        write_enum(&mut generated, name, category.doc, &variants);
+        write_all(&mut generated, name, category.label, &variants);
+        writeln!(generated, "impl {name} {{").unwrap();
+        writeln!(generated, "    fn info_dump(&self) -> (&'static str, Option<super::Price>, crate::CardCost{extra_type}, crate::GameVersion) {{").unwrap();
+        write_match(&mut generated, &entries);
+        writeln!(generated, "    }}\n}}\n").unwrap();
+
+        writeln!(generated, "impl super::PlayingCard for {name} {{").unwrap();
+        writeln!(generated, "    fn name(&self) -> &'static str {{\n        self.info_dump().0\n    }}\n").unwrap();
+        writeln!(generated, "    fn shop_price(&self) -> Option<super::Price> {{\n        self.info_dump().1\n    }}\n").unwrap();
+        writeln!(generated, "    fn cost(&self) -> crate::CardCost {{\n        self.info_dump().2\n    }}\n").unwrap();
+        writeln!(generated, "    fn version(&self) -> Option<crate::GameVersion> {{\n        Some(self.info_dump().{version_index})\n    }}\n").unwrap();
+        writeln!(generated, "    fn {}(&self) -> Option<{name}> {{\n        Some(*self)\n    }}\n}}\n", category.accessor).unwrap();
+
+        write_ord(&mut generated, name);
+        writeln!(
+            generated,
+            "impl_from!({}: {name} => crate::{} => crate::ActionCard => crate::Card);",
+            category.variant, category.parent,
+        ).unwrap();
+        files.push((format!("{}.rs", category.key), generated));
+
+        write_table(&mut tables, name, category.v2, v2);
+    }
+
+    files.push(("deck_url.rs".to_string(), url.finish()));
+    files.push(("encoding.rs".to_string(), tables));
+    files
+}
+
+pub(crate) fn fail(message: String) -> ! {
+    panic!("{DATA}: {message}")
+}
+
+fn cards<'d>(data: &'d Map<String, Value>, key: &str) -> &'d Vec<Value> {
+    match data.get(key) {
+        Some(Value::Array(cards)) => cards,
+        Some(_) => fail(format!("`{key}` has to be an array of cards")),
+        None => fail(format!("missing category `{key}`")),
+    }
+}
+
+/// The fields of a card, which has to have exactly `expected`
+fn fields(card: &Value, key: &str, index: usize, expected: &[&str]) -> Map<String, Value> {
+    let Some(card) = card.as_object() else { fail(format!("{key}[{index}] has to be an object")) };
+    let context = match card.get("variant").and_then(Value::as_str) {
+        Some(variant) => format!("{key}.{variant}"),
+        None => format!("{key}[{index}]"),
+    };
+
+    if let Some(field) = expected.iter().find(|field| !card.contains_key(**field)) {
+        fail(format!("{context}: missing field `{field}`"));
+    }
+    if let Some(field) = card.keys().find(|field| !expected.contains(&field.as_str())) {
+        fail(format!("{context}: unknown field `{field}`"));
+    }
+
+    card.clone()
+}
+
+fn string<'c>(card: &'c Map<String, Value>, field: &str) -> &'c str {
+    match card[field].as_str() {
+        Some(text) => text,
+        None => fail(format!("`{field}` of {} has to be a string", card["variant"])),
+    }
+}
+
+/// A field used as a Rust identifier
+fn identifier(card: &Map<String, Value>, field: &str) -> String {
+    let text = string(card, field);
+    let valid = text.starts_with(|first: char| first.is_ascii_uppercase())
+        && text.chars().all(|char| char.is_ascii_alphanumeric());
+
+    if !valid {
+        fail(format!("`{field}` has to be a CamelCase identifier, found {text:?}"));
+    }
+
+    text.to_string()
+}
+
+fn one_of<'c>(card: &'c Map<String, Value>, field: &str, options: &[&str], context: &str) -> &'c str {
+    let text = string(card, field);
+
+    if !options.contains(&text) {
+        fail(format!("{context}: `{field}` has to be one of {options:?}, found {text:?}"));
+    }
+
+    text
+}
+
+fn number(card: &Map<String, Value>, field: &str, context: &str, max: u64) -> u64 {
+    match card[field].as_u64() {
+        Some(number) if number <= max => number,
+        _ => fail(format!("{context}: `{field}` has to be a number up to {max}")),
+    }
+}
+
+fn unique_name(card: &Map<String, Value>, context: &str, names: &mut HashSet<String>) -> String {
+    let name = string(card, "name").to_string();
+
+    if !names.insert(name.clone()) {
+        fail(format!("{context}: duplicate name {name:?}"));
+    }
+
+    name
+}
+
+/// A deck builder URL code, one character long for characters and two for action cards, or
+/// `None` for cards the deck builder doesn't have
+fn unique_code(card: &Map<String, Value>, context: &str, length: usize, codes: &mut HashSet<String>) -> Option<String> {
+    if card["code"].is_null() {
+        return None
+    }
+
+    let code = string(card, "code").to_string();
+
+    if code.len() != length || !code.chars().all(|char| char.is_ascii_alphanumeric()) {
+        fail(format!("{context}: `code` has to be {length} letters or digits, found {code:?}"));
+    }
+    if !codes.insert(code.clone()) {
+        fail(format!("{context}: duplicate code {code:?}"));
+    }
+
+    Some(code)
+}
+
+/// A cost like `{"exact": ["Cryo", 3], "energy": 2}`, where missing components are zero
+fn cost(cost: &Value, context: &str) -> String {
+    let Some(cost) = cost.as_object() else { fail(format!("{context}: `cost` has to be an object")) };
+
+    // the common costs have shorthands, like the hand-written tables used
+    let only = |component: &str, amount: u64| cost.len() == 1 && cost.get(component).and_then(Value::as_u64) == Some(amount);
+    if only("unaligned", 2) {
+        return "crate::CardCost::ANY2".to_string()
+    } else if only("matching", 2) {
+        return "crate::CardCost::MATCH2".to_string()
+    } else if only("matching", 3) {
+        return "crate::CardCost::MATCH3".to_string()
+    }
+
+    let mut expression = "crate::CardCost::ZERO".to_string();
+
+    for (component, value) in cost {
+        match component.as_str() {
+            "exact" => {
+                let element = value.get(0).and_then(Value::as_str).filter(|element| ELEMENTS.contains(element));
+                let amount = value.get(1).and_then(Value::as_u64).filter(|amount| *amount <= u8::MAX as u64);
+
+                match (element, amount, value.as_array().map(Vec::len)) {
+                    (Some(element), Some(amount), Some(2)) => {
+                        write!(expression, ".with_exact(crate::Element::{element}, {amount})").unwrap()
+                    },
+                    _ => fail(format!("{context}: `cost.exact` has to be an element and an amount")),
+                }
+            },
+            "matching" | "unaligned" | "energy" | "discard" => match value.as_u64() {
+                Some(amount) if amount <= u8::MAX as u64 => write!(expression, ".with_{component}({amount})").unwrap(),
+                _ => fail(format!("{context}: `cost.{component}` has to be a number up to 255")),
+            },
+            _ => fail(format!("{context}: unknown cost component `{component}`")),
+        }
+    }
+
+    expression
+}
+
+/// The game version that added a card, like `"3.7"`
+fn version(card: &Map<String, Value>, context: &str) -> String {
+    let text = string(card, "version");
+    let parsed = text.split_once('.')
+        .and_then(|(major, minor)| Some((major.parse::<u8>().ok()?, minor.parse::<u8>().ok()?)));
+
+    match parsed {
+        Some((major, minor)) => format!("crate::GameVersion::new({major}, {minor})"),
+        None => fail(format!("{context}: `version` has to look like \"3.7\", found {text:?}")),
+    }
+}
+
+/// Records the slot of a card in its frozen encoding table, if it has one
+fn slot(card: &Map<String, Value>, context: &str, slots: &mut Vec<(u64, String)>, variant: &str) {
+    match &card["v2"] {
+        Value::Null => (),
+        value => match value.as_u64() {
+            Some(slot) => slots.push((slot, variant.to_string())),
+            None => fail(format!("{context}: `v2` has to be a slot number or null")),
+        },
+    }
+}
+
+fn write_enum(out: &mut String, name: &str, doc: &[&str], variants: &[&String]) {
+    for line in doc {
+        writeln!(out, "/// {line}").unwrap();
+    }
+
+    writeln!(out, "#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]").unwrap();
+    writeln!(out, "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]").unwrap();
+    writeln!(out, "#[non_exhaustive]").unwrap();
+    writeln!(out, "pub enum {name} {{").unwrap();
+    for variant in variants {
+        writeln!(out, "    {variant},").unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+}
+
+fn write_all(out: &mut String, name: &str, label: &str, variants: &[&String]) {
+    writeln!(out, "impl {name} {{").unwrap();
+    writeln!(out, "    /// Every {label} card, in declaration order").unwrap();
+    writeln!(out, "    pub const ALL: [Self; {}] = [", variants.len()).unwrap();
+    for variant in variants {
+        writeln!(out, "        Self::{variant},").unwrap();
+    }
+    writeln!(out, "    ];\n}}\n").unwrap();
+}
+
+fn write_match(out: &mut String, entries: &[String]) {
+    writeln!(out, "        match self {{").unwrap();
+    for entry in entries {
+        writeln!(out, "            {entry}").unwrap();
+    }
+    writeln!(out, "        }}").unwrap();
+}
+
+fn write_ord(out: &mut String, name: &str) {
+    writeln!(out, "impl super::CardOrd for {name} {{").unwrap();
+    writeln!(out, "    fn cmp(&self, other: &Self) -> std::cmp::Ordering {{").unwrap();
+    writeln!(out, "        (*self as u32).cmp(&(*other as u32))").unwrap();
+    writeln!(out, "    }}\n}}\n").unwrap();
+}
+
+/// A table frozen for `ENCODING_VERSION` 2, which has to have every slot exactly once
+fn write_table(out: &mut String, name: &str, (table, length): (&str, usize), mut slots: Vec<(u64, String)>) {
+    slots.sort();
+
+    for (expected, (slot, variant)) in slots.iter().enumerate() {
+        if *slot != expected as u64 {
+            fail(format!("{name}::{variant}: `v2` slot {slot} is taken or skips a slot of {table}"));
+        }
+    }
+    if slots.len() != length {
+        fail(format!("{table} is frozen with {length} slots, but {} cards have one", slots.len()));
+    }
+
+    writeln!(out, "const {table}: [crate::{name}; {length}] = [").unwrap();
+    for (_, variant) in &slots {
+        writeln!(out, "    crate::{name}::{variant},").unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+}
+
+/// The match arms of the deck builder URL encoding and decoding functions
+#[derive(Default)]
+struct Urls {
+    encode_characters: Vec<String>,
+    decode_characters: Vec<String>,
+    encode_actions: Vec<String>,
+    decode_actions: Vec<String>,
+}
+
+impl Urls {
+    fn character(&mut self, variant: &str, code: &str) {
+        self.encode_characters.push(format!("crate::CharacterCard::{variant} => Some({code:?}),"));
+        self.decode_characters.push(format!("{code:?} => Ok(crate::Card::Character(crate::CharacterCard::{variant})),"));
+    }
+
+    fn action(&mut self, category: &Category, variant: &str, code: &str) {
+        let (name, parent) = (category.name, category.parent);
+        let card = format!("crate::ActionCard::{}(crate::{parent}::{}(crate::{name}::{variant}))", parent.trim_end_matches("Card"), category.variant);
+
+        self.encode_actions.push(format!("{card} => Some({code:?}),"));
+        self.decode_actions.push(format!("{code:?} => Ok(crate::Card::Action({card})),"));
+    }
+
+    fn finish(self) -> String {
+        let mut out = String::new();
+
+        // cards without a code fall through to `None`, an unreachable arm if every card has one
+        let uncoded = "#[allow(unreachable_patterns)]\n        _ => None,".to_string();
+        let mut encode_characters = self.encode_characters;
+        encode_characters.push(uncoded.clone());
+        let mut encode_actions = self.encode_actions;
+        encode_actions.push("#[cfg(feature = \"custom-cards\")]\n        crate::ActionCard::Custom(card) => Some(card.code()),".to_string());
+        encode_actions.push(uncoded);
+
+        writeln!(out, "fn encode_character_card(card: crate::CharacterCard) -> Option<&'static str> {{").unwrap();
+        write_arms(&mut out, "card", &encode_characters, None);
+        writeln!(out, "fn encode_action_card(card: crate::ActionCard) -> Option<&'static str> {{").unwrap();
+        write_arms(&mut out, "card", &encode_actions, None);
+        writeln!(out, "fn decode_character_card(card: &str) -> Result<crate::Card, &str> {{").unwrap();
+        write_arms(&mut out, "card", &self.decode_characters, Some("Err(card)"));
+        writeln!(out, "fn decode_action_card(card: &str) -> Result<crate::Card, &str> {{").unwrap();
+        write_arms(&mut out, "card", &self.decode_actions, Some("Err(card)"));
+
+        out
+    }
+}
+
+fn write_arms(out: &mut String, scrutinee: &str, arms: &[String], fallback: Option<&str>) {
+    writeln!(out, "    match {scrutinee} {{").unwrap();
+    for arm in arms {
+        writeln!(out, "        {arm}").unwrap();
+    }
+    if let Some(fallback) = fallback {
+        writeln!(out, "        _ => {fallback},").unwrap();
+    }
+    writeln!(out, "    }}\n}}\n").unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+    use super::generate;
+
+    /// The real data, with `change` applied to it
+    fn generate_with(change: impl FnOnce(&mut Value)) -> Vec<(String, String)> {
+        let mut data: Value = serde_json::from_str(include_str!("../cards.json")).unwrap();
+        change(&mut data);
+
+        generate(&data.to_string())
+    }
+
+    /// A copy of the first card of `key`, added as the last one with `changes` applied to it
+    fn push_copy(data: &mut Value, key: &str, changes: Value) {
+        let cards = data[key].as_array_mut().unwrap();
+        let mut card = cards[0].clone();
+
+        for (field, value) in changes.as_object().unwrap() {
+            card[field] = value.clone();
+        }
+        cards.push(card);
+    }
+
+    #[test]
+    fn real_data_generates_every_file() {
+        let files: Vec<String> = generate_with(|_| ()).into_iter().map(|(file, _)| file).collect();
+
+        assert!(files.contains(&"deck_url.rs".to_string()));
+        assert!(files.contains(&"encoding.rs".to_string()));
+        assert_eq!(files.len(), 13);
+    }
+
+    #[test]
+    #[should_panic(expected = "characters.KamisatoAyaka: duplicate variant")]
+    fn rejects_duplicate_variants() {
+        generate_with(|data| push_copy(data, "characters", json!({ "name": "Ganyu Again", "code": null, "v2": null })));
+    }
+
+    #[test]
+    #[should_panic(expected = "weapons.Homebrew: duplicate name")]
+    fn rejects_duplicate_names_across_categories() {
+        generate_with(|data| {
+            let name = data["characters"][0]["name"].clone();
+            push_copy(data, "weapons", json!({ "variant": "Homebrew", "name": name, "code": null, "v2": null }));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "food.Homebrew: duplicate code")]
+    fn rejects_duplicate_codes() {
+        generate_with(|data| push_copy(data, "food", json!({ "variant": "Homebrew", "name": "Homebrew", "v2": null })));
+    }
+}
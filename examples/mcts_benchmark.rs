@@ -0,0 +1,94 @@
+//! Pits the MCTS player against a random one, using the valid decks from the tests in
+//! `src/deck.rs`. Each pairing is played from both seats
+//!
+//! ```sh
+//! cargo run --release --example mcts_benchmark -- [matches per pairing] [iterations]
+//! ```
+
+use std::time::Instant;
+use genius_invokation::*;
+
+use CharacterCard::*;
+
+const GA: ActionCard = ActionCard::Equipment(EquipmentCard::Artifact(ArtifactCard::GamblersEarrings));
+const LU: ActionCard = ActionCard::Equipment(EquipmentCard::Artifact(ArtifactCard::LuckyDogsSilverCirclet));
+const DE: ActionCard = ActionCard::Equipment(EquipmentCard::Artifact(ArtifactCard::DeepwoodMemories));
+const VI: ActionCard = ActionCard::Equipment(EquipmentCard::Artifact(ArtifactCard::ViridescentVenerer));
+const IG: ActionCard = ActionCard::Equipment(EquipmentCard::Talent(TalentCard::IGotYourBack));
+const AQ: ActionCard = ActionCard::Equipment(EquipmentCard::Weapon(WeaponCard::AquilaFavonia));
+const WO: ActionCard = ActionCard::Equipment(EquipmentCard::Weapon(WeaponCard::WolfsGravestone));
+const WH: ActionCard = ActionCard::Equipment(EquipmentCard::Weapon(WeaponCard::WhiteTassel));
+const LO: ActionCard = ActionCard::Event(EventCard::Food(FoodCard::LotusFlowerCrisp));
+const MI: ActionCard = ActionCard::Event(EventCard::Food(FoodCard::MintyMeatRolls));
+const AD: ActionCard = ActionCard::Event(EventCard::Food(FoodCard::AdeptusTemptation));
+const JU: ActionCard = ActionCard::Event(EventCard::Food(FoodCard::JueyunGuoba));
+const LE: ActionCard = ActionCard::Event(EventCard::Normal(NormalEventCard::LeaveItToMe));
+const ST: ActionCard = ActionCard::Event(EventCard::Normal(NormalEventCard::Strategize));
+const MA: ActionCard = ActionCard::Event(EventCard::Normal(NormalEventCard::MasterOfWeaponry));
+const AB: ActionCard = ActionCard::Event(EventCard::Normal(NormalEventCard::AbyssalSummons));
+const HI: ActionCard = ActionCard::Event(EventCard::Resonance(ElementalResonanceCard::HighVoltage));
+const EN: ActionCard = ActionCard::Event(EventCard::Resonance(ElementalResonanceCard::EnduringRock));
+const PA: ActionCard = ActionCard::Support(SupportCard::Companion(CompanionCard::Paimon));
+const LI: ActionCard = ActionCard::Support(SupportCard::Companion(CompanionCard::Liben));
+const TI: ActionCard = ActionCard::Support(SupportCard::Companion(CompanionCard::Timaeus));
+const TU: ActionCard = ActionCard::Support(SupportCard::Companion(CompanionCard::Tubby));
+const NR: ActionCard = ActionCard::Support(SupportCard::Item(ItemCard::NRE));
+const JA: ActionCard = ActionCard::Support(SupportCard::Location(LocationCard::JadeChamber));
+const FA: ActionCard = ActionCard::Support(SupportCard::Location(LocationCard::FavoniusCathedral));
+const DA: ActionCard = ActionCard::Support(SupportCard::Location(LocationCard::DawnWinery));
+const WA: ActionCard = ActionCard::Support(SupportCard::Location(LocationCard::WangshuInn));
+
+fn deck(characters: [CharacterCard; 3], actions: [ActionCard; 30]) -> Deck {
+    Deck::from_exact(characters, actions).unwrap()
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).map(|arg| arg.parse::<usize>().expect("arguments must be numbers"));
+    let matches = args.next().unwrap_or(2);
+    let iterations = args.next().unwrap_or(100);
+
+    let resonance = [ST, PA, VI, HI, LU, WO, WH, NR, WA, JU, JA, WA, MI, WH, FA, LE, GA, LO, MA, AD, HI, TU, MI, TI, AQ, AB, TU, AB, AQ, DE];
+    let decks = [
+        ("Lawachurl/Collei/Noelle", deck([StonehideLawachurl, Collei, Noelle], [ST, PA, VI, DA, LU, WO, IG, NR, WA, JU, JA, WA, MI, WH, FA, LE, GA, LO, MA, AD, LI, TU, IG, TI, AQ, AB, TU, AB, EN, DE])),
+        ("Fischl/Noelle/Agent", deck([Fischl, Noelle, FatuiPyroAgent], [ST, PA, VI, DA, LU, WO, IG, NR, WA, JU, JA, WA, MI, WH, FA, LE, GA, LO, MA, AD, LI, TU, IG, TI, AQ, AB, TU, AB, AQ, DE])),
+        ("Razor/Fischl/Xingqiu", deck([Razor, Fischl, Xingqiu], resonance)),
+        ("Keqing/Razor/Fischl", deck([Keqing, Razor, Fischl], resonance)),
+    ];
+
+    let start = Instant::now();
+    let (mut wins, mut draws, mut total) = (0, 0, 0);
+
+    for (name, mcts_deck) in &decks {
+        for (other, random_deck) in &decks {
+            let mut pairing = (0, 0);
+
+            for seed in 0..matches as u64 {
+                let mut mcts = MctsPlayer::new(seed).iterations(iterations);
+                let mut random = RandomPlayer::new(seed);
+
+                // alternate seats, since the first player has an edge
+                let (winner, seat) = if seed % 2 == 0 {
+                    (Match::run(mcts_deck, random_deck, &mut mcts, &mut random, seed).winner(), PlayerId::First)
+                } else {
+                    (Match::run(random_deck, mcts_deck, &mut random, &mut mcts, seed).winner(), PlayerId::Second)
+                };
+
+                match winner {
+                    Some(player) if player == seat => pairing.0 += 1,
+                    None => pairing.1 += 1,
+                    _ => (),
+                }
+            }
+
+            println!("{name:>24} vs {other:<24} {}/{matches} wins, {} draws", pairing.0, pairing.1);
+
+            wins += pairing.0;
+            draws += pairing.1;
+            total += matches;
+        }
+    }
+
+    println!();
+    println!("MCTS ({iterations} iterations) won {wins} of {total} matches against random play, {draws} draws");
+    println!("{:.1}s elapsed", start.elapsed().as_secs_f64());
+}
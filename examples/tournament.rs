@@ -0,0 +1,43 @@
+//! Runs a round-robin between the decks of a file with a deck builder url per line, and prints
+//! the win rates as CSV, or JSON with `--json`
+//!
+//! ```sh
+//! cargo run --release --features deck-url --example tournament -- decks.txt [--json] [games] [iterations]
+//! ```
+//!
+//! Decks are played by MCTS players, or by random ones if `iterations` is 0.
+
+use genius_invokation::*;
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let json = args.iter().any(|arg| arg == "--json");
+    args.retain(|arg| arg != "--json");
+
+    let path = args.first().expect("usage: tournament <file> [--json] [games] [iterations]");
+    let number = |index: usize, default| args.get(index).map_or(default, |arg| arg.parse().expect("arguments must be numbers"));
+    let games = number(1, 10);
+    let iterations = number(2, 100);
+
+    let file = std::fs::read_to_string(path).expect("the file can't be read");
+    let decks = file.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|url| {
+            let deck = Deck::from_iter(deck_from_url(url).expect("invalid deck builder url")).expect("invalid deck");
+            let name: Vec<&str> = deck.characters().iter().map(|card| card.name()).collect();
+
+            (name.join(" / "), deck)
+        });
+
+    let results = Tournament::new(decks).games(games).run(|_, seed| match iterations {
+        0 => Box::new(RandomPlayer::new(seed)),
+        iterations => Box::new(MctsPlayer::new(seed).iterations(iterations)),
+    });
+
+    if json {
+        print!("{}", results.to_json());
+    } else {
+        print!("{}", results.to_csv());
+    }
+}
@@ -0,0 +1,97 @@
+use crate::*;
+
+/// Takes whichever action deals the most damage to the opponent right away, trying each
+/// legal action on a guess of the full match (see [`Observation::sample`])
+/// 
+/// Ties go to the earliest action in the list, so when no damage can be dealt it plays cards
+/// before giving up on the round.
+#[derive(Debug, Default, Clone)]
+pub struct GreedyPlayer {
+    opponent_deck: Option<Deck>,
+}
+
+impl GreedyPlayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Player for GreedyPlayer {
+    fn start(&mut self, _player: PlayerId, _own: &Deck, opponent: &Deck) {
+        self.opponent_deck = Some(opponent.clone());
+    }
+
+    fn choose(&mut self, observation: &Observation, actions: &[Action]) -> usize {
+        let deck = self.opponent_deck.as_ref().expect("the match must be started with Player::start");
+        let Some(game) = observation.sample(deck, 0) else { return 0 };
+        let mut best = (0, 0);
+
+        for (index, action) in actions.iter().enumerate() {
+            let damage = immediate_damage(&game, observation.viewer, *action);
+
+            if damage > best.1 {
+                best = (index, damage);
+            }
+        }
+
+        best.0
+    }
+}
+
+/// Damage the player deals to the opponent by taking the action, including anything it
+/// triggers (summons, reactions...)
+fn immediate_damage(game: &GameState, player: PlayerId, action: Action) -> u32 {
+    let mut after = game.clone();
+    let logged = after.events().len();
+
+    if after.apply(action).is_err() {
+        return 0
+    }
+
+    after.events()[logged..].iter()
+        .map(|event| match event {
+            Event::DamageDealt { info, amount, .. } if info.attacker == player => *amount as u32,
+            _ => 0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    #[test]
+    fn greedy_picks_the_strongest_skill() {
+        let mut game = duel(CharacterCard::Keqing);
+        game.player_mut(PlayerId::First).dice = [Die::Omni; 10].into_iter().collect();
+
+        let mut greedy = GreedyPlayer::new();
+        greedy.start(PlayerId::First, &deck([CharacterCard::Keqing, CharacterCard::Xiangling, CharacterCard::Sucrose]), &deck(OPPONENTS));
+
+        let actions = game.legal_actions();
+        let choice = greedy.choose(&game.observe(PlayerId::First), &actions);
+        assert_eq!(actions[choice], Action::UseSkill { skill: 1 });
+
+        charge(&mut game);
+        let actions = game.legal_actions();
+
+        let choice = greedy.choose(&game.observe(PlayerId::First), &actions);
+        assert_eq!(actions[choice], Action::UseSkill { skill: 2 });
+    }
+
+    #[test]
+    fn greedy_beats_random() {
+        let first = deck([CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle]);
+        let second = deck(OPPONENTS);
+
+        let wins = (0..10)
+            .filter(|seed| {
+                let played = Match::run(&first, &second, &mut GreedyPlayer::new(), &mut RandomPlayer::new(*seed), *seed);
+                played.winner() == Some(PlayerId::First)
+            })
+            .count();
+
+        assert!(wins >= 8, "greedy player only won {wins} matches out of 10");
+    }
+}
@@ -0,0 +1,215 @@
+use std::time::{Duration, Instant};
+use crate::*;
+use crate::game::Rng;
+
+/// Balance between trying rarely visited actions and sticking to the best ones (UCB1)
+const EXPLORATION: f64 = 0.7;
+
+/// Random actions played after leaving the tree before judging the match by HP
+const ROLLOUT_LIMIT: usize = 60;
+
+/// Searches for the best action with Information Set Monte Carlo Tree Search
+///
+/// The player can't see the opponent's hand and dice, the order of the draw piles, nor the
+/// dice that will be rolled later on. Each iteration of the search guesses them with
+/// [`Observation::sample`] (determinization). Iterations share a single tree, whose actions
+/// are only considered when they're legal in the current guess.
+///
+/// Matches that aren't over when a rollout ends are judged by the HP left on each side.
+///
+/// ## Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use genius_invokation::*;
+/// let player = MctsPlayer::new(7)
+///     .iterations(2000)
+///     .time_limit(Duration::from_millis(500));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MctsPlayer {
+    rng: Rng,
+    iterations: usize,
+    time_limit: Option<Duration>,
+    opponent_deck: Option<Deck>,
+}
+
+impl MctsPlayer {
+    /// A player running 1000 iterations per choice, with no time limit
+    pub fn new(seed: u64) -> Self {
+        Self { rng: Rng::new(seed), iterations: 1000, time_limit: None, opponent_deck: None }
+    }
+
+    /// Sets the amount of iterations to run for each choice
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations.max(1);
+        self
+    }
+
+    /// Stops searching once this much time has passed, even if there are iterations left
+    pub fn time_limit(mut self, limit: Duration) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+}
+
+impl Player for MctsPlayer {
+    fn start(&mut self, _player: PlayerId, _own: &Deck, opponent: &Deck) {
+        self.opponent_deck = Some(opponent.clone());
+    }
+
+    fn choose(&mut self, observation: &Observation, actions: &[Action]) -> usize {
+        if actions.len() == 1 {
+            return 0
+        }
+
+        let deck = self.opponent_deck.clone().expect("the match must be started with Player::start");
+        let start = Instant::now();
+        let mut tree = vec![Node::root(observation.viewer)];
+
+        for iteration in 0..self.iterations {
+            // always run at least one iteration, so there's something to choose from
+            if iteration > 0 && self.time_limit.is_some_and(|limit| start.elapsed() >= limit) {
+                break
+            }
+
+            let Some(guess) = observation.sample(&deck, self.rng.next_u64()) else { return 0 };
+            self.iterate(&mut tree, guess);
+        }
+
+        let best = tree[0].children.iter()
+            .max_by_key(|child| tree[**child].visits)
+            .and_then(|child| tree[*child].action);
+
+        best.and_then(|best| actions.iter().position(|action| *action == best)).unwrap_or(0)
+    }
+}
+
+impl MctsPlayer {
+    fn iterate(&mut self, tree: &mut Vec<Node>, mut game: GameState) {
+        let mut node = 0;
+
+        // selection, down to the first action never tried from a node
+        while !game.is_over() {
+            let legal = game.legal_actions();
+            let player = game.acting_player();
+            let mut untried = Vec::new();
+
+            for &action in &legal {
+                match tree[node].child(tree, action) {
+                    Some(child) => tree[child].available += 1,
+                    None => untried.push(action),
+                }
+            }
+
+            if !untried.is_empty() {
+                let action = untried[self.rng.below(untried.len())];
+                game.apply(action).expect("legal actions are always accepted");
+
+                tree.push(Node { action: Some(action), player, parent: Some(node), ..Node::root(player) });
+                let child = tree.len() - 1;
+                tree[node].children.push(child);
+                node = child;
+                break
+            }
+
+            let parent = &tree[node];
+            let best = parent.children.iter().copied()
+                .filter(|child| tree[*child].action.is_some_and(|action| legal.contains(&action)))
+                .max_by(|a, b| tree[*a].ucb().total_cmp(&tree[*b].ucb()))
+                .expect("every legal action was tried");
+
+            game.apply(tree[best].action.unwrap()).expect("legal actions are always accepted");
+            node = best;
+        }
+
+        // rollout
+        for _ in 0..ROLLOUT_LIMIT {
+            if game.is_over() {
+                break
+            }
+
+            let actions = game.legal_actions();
+            game.apply(actions[self.rng.below(actions.len())]).expect("legal actions are always accepted");
+        }
+
+        // backpropagation
+        let mut current = Some(node);
+
+        while let Some(index) = current {
+            let node = &mut tree[index];
+            node.visits += 1;
+            node.reward += value(&game, node.player);
+            current = node.parent;
+        }
+    }
+}
+
+struct Node {
+    action: Option<Action>,
+    /// The player who took `action`, whose point of view the rewards are from
+    player: PlayerId,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    visits: u32,
+    /// How many times the action was legal when its parent was visited
+    available: u32,
+    reward: f64,
+}
+
+impl Node {
+    fn root(player: PlayerId) -> Self {
+        Self { action: None, player, parent: None, children: Vec::new(), visits: 0, available: 0, reward: 0.0 }
+    }
+
+    fn child(&self, tree: &[Node], action: Action) -> Option<usize> {
+        self.children.iter().copied().find(|child| tree[*child].action == Some(action))
+    }
+
+    fn ucb(&self) -> f64 {
+        let visits = self.visits.max(1) as f64;
+        let available = self.available.max(1) as f64;
+
+        self.reward / visits + EXPLORATION * (available.ln() / visits).sqrt()
+    }
+}
+
+/// How good the match looks for the player: 1 for a win, 0 for a loss, otherwise their share
+/// of the HP left on the field
+fn value(game: &GameState, player: PlayerId) -> f64 {
+    match game.outcome() {
+        Some(Outcome::Winner(winner)) => if winner == player { 1.0 } else { 0.0 },
+        Some(Outcome::Draw) => 0.5,
+        None => {
+            let hp = |player| game.player(player).characters().iter().map(|ch| ch.hp() as f64).sum::<f64>();
+            let (own, other) = (hp(player), hp(player.opponent()));
+
+            if own + other == 0.0 { 0.5 } else { own / (own + other) }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    #[test]
+    fn finds_the_lethal_skill() {
+        let mut game = duel(CharacterCard::Keqing);
+        game.player_mut(PlayerId::First).dice = [Die::Omni; 10].into_iter().collect();
+
+        // the opponent's active character survives anything but the burst
+        let active = game.player(PlayerId::Second).active_index();
+        game.player_mut(PlayerId::Second).characters[active].hp = 4;
+        charge(&mut game);
+
+        let mut mcts = MctsPlayer::new(1).iterations(300);
+        mcts.start(PlayerId::First, &deck([CharacterCard::Keqing, CharacterCard::Xiangling, CharacterCard::Sucrose]), &deck(OPPONENTS));
+
+        let actions = game.legal_actions();
+        let choice = mcts.choose(&game.observe(PlayerId::First), &actions);
+
+        assert_eq!(actions[choice], Action::UseSkill { skill: 2 });
+    }
+}
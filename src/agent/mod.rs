@@ -0,0 +1,106 @@
+//! Automated players, and drivers to make them play against each other
+
+use crate::*;
+
+pub use random::RandomPlayer;
+mod random;
+
+pub use greedy::GreedyPlayer;
+mod greedy;
+
+pub use mcts::MctsPlayer;
+mod mcts;
+
+pub use tournament::{Tournament, TournamentResults, Matchup};
+mod tournament;
+
+/// Anything that can take part in a match: a bot, or a human behind some interface
+/// 
+/// Players are asked to choose every time they are the [acting player], whether it's their
+/// turn during the Action Phase or they have a [`Decision`] to make. They only get to see
+/// what the acting player would, see [`Observation`].
+/// 
+/// [acting player]: GameState::acting_player
+pub trait Player {
+    /// Called before the match starts with both decklists, which are public. Players that
+    /// search ahead need the opponent's deck to guess their hidden cards, see
+    /// [`Observation::sample`]
+    fn start(&mut self, _player: PlayerId, _own: &Deck, _opponent: &Deck) {}
+
+    /// Picks one of the legal `actions`, by position
+    /// 
+    /// `actions` is never empty.
+    fn choose(&mut self, observation: &Observation, actions: &[Action]) -> usize;
+}
+
+/// A finished match between two [`Player`]s
+#[derive(Debug, Clone)]
+pub struct Match {
+    decks: [Deck; 2],
+    seed: u64,
+    game: GameState,
+}
+
+impl Match {
+    /// Plays a whole match, `player_a` using `deck_a` as [`PlayerId::First`] and `player_b`
+    /// using `deck_b` as [`PlayerId::Second`]
+    /// 
+    /// ## Panics
+    /// 
+    /// If a player chooses a position outside of the legal actions.
+    pub fn run(
+        deck_a: &Deck,
+        deck_b: &Deck,
+        player_a: &mut dyn Player,
+        player_b: &mut dyn Player,
+        seed: u64,
+    ) -> Self {
+        let mut game = GameState::new(deck_a, deck_b, seed);
+
+        player_a.start(PlayerId::First, deck_a, deck_b);
+        player_b.start(PlayerId::Second, deck_b, deck_a);
+
+        while !game.is_over() {
+            let actions = game.legal_actions();
+            let acting = game.acting_player();
+            let player: &mut dyn Player = match acting {
+                PlayerId::First => player_a,
+                PlayerId::Second => player_b,
+            };
+
+            let choice = player.choose(&game.observe(acting), &actions);
+            let action = *actions.get(choice).expect("players must choose one of the legal actions");
+
+            game.apply(action).expect("legal actions are always accepted");
+        }
+
+        Self { decks: [deck_a.clone(), deck_b.clone()], seed, game }
+    }
+
+    pub fn outcome(&self) -> Outcome {
+        self.game.outcome().expect("matches are only returned once over")
+    }
+
+    /// The player who won, or `None` in case of a draw
+    pub fn winner(&self) -> Option<PlayerId> {
+        match self.outcome() {
+            Outcome::Winner(player) => Some(player),
+            Outcome::Draw => None,
+        }
+    }
+
+    /// Everything that happened during the match, see [`GameState::events`]
+    pub fn events(&self) -> &[Event] {
+        self.game.events()
+    }
+
+    /// The final state of the match
+    pub fn state(&self) -> &GameState {
+        &self.game
+    }
+
+    /// The match as a replay, which can be saved and played again
+    pub fn replay(&self) -> Replay {
+        Replay::record(&self.decks[0], &self.decks[1], self.seed, &self.game)
+    }
+}
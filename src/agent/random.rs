@@ -0,0 +1,43 @@
+use crate::*;
+use crate::game::Rng;
+
+/// Chooses uniformly among the legal actions
+/// 
+/// The choices only depend on the seed and the actions offered, so matches between random
+/// players can be reproduced.
+#[derive(Debug, Clone)]
+pub struct RandomPlayer {
+    rng: Rng,
+}
+
+impl RandomPlayer {
+    pub fn new(seed: u64) -> Self {
+        Self { rng: Rng::new(seed) }
+    }
+}
+
+impl Player for RandomPlayer {
+    fn choose(&mut self, _observation: &Observation, actions: &[Action]) -> usize {
+        self.rng.below(actions.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    #[test]
+    fn random_matches_are_reproducible() {
+        let first = deck([CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle]);
+        let second = deck(OPPONENTS);
+
+        let play = |seed| Match::run(&first, &second, &mut RandomPlayer::new(seed), &mut RandomPlayer::new(seed + 1), seed);
+        let a = play(3);
+        let b = play(3);
+
+        assert_eq!(a.events(), b.events());
+        assert_ne!(a.events(), play(4).events());
+        assert_eq!(a.replay().play().unwrap(), *a.state());
+    }
+}
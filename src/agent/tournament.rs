@@ -0,0 +1,357 @@
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use crate::*;
+use crate::game::Rng;
+use crate::export::{csv_field, json_string};
+
+/// Normal quantile for 95% confidence intervals
+const Z: f64 = 1.96;
+
+/// A round-robin between decks, where every pairing plays the same amount of matches
+///
+/// Matches are spread over several threads, and every one of them is seeded from the
+/// tournament's seed: running it again gives the same results, however many threads are used,
+/// as long as the players themselves are deterministic. Each deck plays half of the matches
+/// of a pairing as [`PlayerId::First`].
+///
+/// ## Example
+///
+/// ```
+/// # use genius_invokation::*;
+/// # let deck = |chars: [CharacterCard; 3]| Deck::from_iter(
+/// #     chars.into_iter().map(Card::from).chain(NormalEventCard::ALL.into_iter().cycle().take(30).map(Card::from))
+/// # ).unwrap();
+/// let results = Tournament::new([
+///     ("Keqing", deck([CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle])),
+///     ("Diluc", deck([CharacterCard::Diluc, CharacterCard::Xingqiu, CharacterCard::Sucrose])),
+///     ("Ganyu", deck([CharacterCard::Ganyu, CharacterCard::Bennett, CharacterCard::Collei])),
+/// ])
+/// .games(4)
+/// .run(|_, seed| Box::new(RandomPlayer::new(seed)));
+///
+/// assert_eq!(results.matchup(0, 1).games(), 4);
+/// println!("{}", results.to_csv());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tournament {
+    names: Vec<String>,
+    decks: Vec<Deck>,
+    games: usize,
+    seed: u64,
+    threads: usize,
+}
+
+impl Tournament {
+    /// A tournament between named decks, playing 10 matches per pairing on every available
+    /// thread
+    pub fn new<S: Into<String>>(decks: impl IntoIterator<Item=(S, Deck)>) -> Self {
+        let (names, decks) = decks.into_iter().map(|(name, deck)| (name.into(), deck)).unzip();
+        let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+
+        Self { names, decks, games: 10, seed: 0, threads }
+    }
+
+    /// Sets the amount of matches played by each pairing
+    pub fn games(mut self, games: usize) -> Self {
+        self.games = games;
+        self
+    }
+
+    /// Sets the seed every match and player seed is derived from
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the amount of threads to play on
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Plays every match, with players made by `player`
+    ///
+    /// `player` receives the position of the deck the player will use and a seed, and is called
+    /// once per deck and match: decks can be played by different kinds of players.
+    pub fn run<F>(&self, player: F) -> TournamentResults
+    where
+        F: Fn(usize, u64) -> Box<dyn Player> + Sync,
+    {
+        let jobs: Vec<(usize, usize, usize)> = (0..self.decks.len())
+            .flat_map(|a| (a + 1..self.decks.len()).map(move |b| (a, b)))
+            .flat_map(|(a, b)| (0..self.games).map(move |game| (a, b, game)))
+            .collect();
+
+        let size = self.decks.len();
+        let matchups = Mutex::new(vec![Matchup::default(); size * size]);
+        let next = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            for _ in 0..self.threads.min(jobs.len()) {
+                scope.spawn(|| {
+                    while let Some(&(a, b, game)) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let winner = self.play(a, b, game, &player);
+                        let mut matchups = matchups.lock().unwrap();
+
+                        matchups[a * size + b].add(winner.map(|winner| winner == a));
+                        matchups[b * size + a].add(winner.map(|winner| winner == b));
+                    }
+                });
+            }
+        });
+
+        TournamentResults {
+            names: self.names.clone(),
+            matchups: matchups.into_inner().unwrap(),
+        }
+    }
+
+    /// Plays a match between two decks, returning the position of the winning deck
+    fn play<F>(&self, a: usize, b: usize, game: usize, player: &F) -> Option<usize>
+    where
+        F: Fn(usize, u64) -> Box<dyn Player>,
+    {
+        let mut rng = Rng::new(self.seed ^ ((a as u64) << 48 | (b as u64) << 32 | game as u64));
+        let seed = rng.next_u64();
+
+        // alternate seats between matches
+        let (first, second) = if game % 2 == 0 { (a, b) } else { (b, a) };
+        let mut first_player = player(first, rng.next_u64());
+        let mut second_player = player(second, rng.next_u64());
+
+        let played = Match::run(
+            &self.decks[first],
+            &self.decks[second],
+            first_player.as_mut(),
+            second_player.as_mut(),
+            seed,
+        );
+
+        played.winner().map(|winner| match winner {
+            PlayerId::First => first,
+            PlayerId::Second => second,
+        })
+    }
+}
+
+/// How a deck fared against another in a [`Tournament`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matchup {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+impl Matchup {
+    fn add(&mut self, won: Option<bool>) {
+        match won {
+            Some(true) => self.wins += 1,
+            Some(false) => self.losses += 1,
+            None => self.draws += 1,
+        }
+    }
+
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+
+    /// The share of matches won, counting draws as half a win. `None` if no match was played
+    pub fn win_rate(&self) -> Option<f64> {
+        let games = self.games();
+        (games > 0).then(|| (self.wins as f64 + self.draws as f64 / 2.0) / games as f64)
+    }
+
+    /// The 95% confidence interval of the [win rate](Self::win_rate), as a Wilson score
+    /// interval. `None` if no match was played
+    pub fn confidence_interval(&self) -> Option<(f64, f64)> {
+        let rate = self.win_rate()?;
+        let games = self.games() as f64;
+
+        let center = rate + Z * Z / (2.0 * games);
+        let margin = Z * (rate * (1.0 - rate) / games + Z * Z / (4.0 * games * games)).sqrt();
+        let scale = 1.0 + Z * Z / games;
+
+        Some(((center - margin) / scale, (center + margin) / scale))
+    }
+}
+
+/// The outcome of a [`Tournament`], as a matrix of [`Matchup`]s between decks
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TournamentResults {
+    names: Vec<String>,
+    /// Row-major, from the point of view of the row's deck
+    matchups: Vec<Matchup>,
+}
+
+impl TournamentResults {
+    /// The names of the decks, in the order they were given
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// How the `deck` at this position fared against the `opponent`. A deck never plays
+    /// against itself
+    ///
+    /// ## Panics
+    ///
+    /// If either position is out of range.
+    pub fn matchup(&self, deck: usize, opponent: usize) -> Matchup {
+        assert!(deck < self.names.len() && opponent < self.names.len(), "deck position out of range");
+        self.matchups[deck * self.names.len() + opponent]
+    }
+
+    /// Every match a deck played, against any opponent
+    pub fn total(&self, deck: usize) -> Matchup {
+        (0..self.names.len()).map(|opponent| self.matchup(deck, opponent)).fold(Matchup::default(), |total, matchup| {
+            Matchup {
+                wins: total.wins + matchup.wins,
+                losses: total.losses + matchup.losses,
+                draws: total.draws + matchup.draws,
+            }
+        })
+    }
+
+    /// The results as CSV, one line per ordered pair of different decks
+    ///
+    /// The columns are `deck,opponent,games,wins,losses,draws,win_rate,ci_low,ci_high`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("deck,opponent,games,wins,losses,draws,win_rate,ci_low,ci_high\n");
+
+        for (deck, opponent, matchup) in self.pairs() {
+            let (low, high) = matchup.confidence_interval().map_or((String::new(), String::new()), |(low, high)| {
+                (format!("{low:.4}"), format!("{high:.4}"))
+            });
+            let rate = matchup.win_rate().map_or(String::new(), |rate| format!("{rate:.4}"));
+
+            writeln!(
+                csv,
+                "{},{},{},{},{},{},{rate},{low},{high}",
+                csv_field(&self.names[deck]),
+                csv_field(&self.names[opponent]),
+                matchup.games(),
+                matchup.wins,
+                matchup.losses,
+                matchup.draws,
+            ).unwrap();
+        }
+
+        csv
+    }
+
+    /// The results as JSON: the deck names, and the matrix of matchups with `null` along the
+    /// diagonal
+    ///
+    /// ```json
+    /// {
+    ///   "decks": ["Keqing", "Diluc"],
+    ///   "matchups": [
+    ///     [null, {"games": 10, "wins": 6, "losses": 4, "draws": 0, "win_rate": 0.6, "ci_low": 0.31, "ci_high": 0.83}],
+    ///     [{"games": 10, "wins": 4, "losses": 6, "draws": 0, "win_rate": 0.4, "ci_low": 0.17, "ci_high": 0.69}, null]
+    ///   ]
+    /// }
+    /// ```
+    pub fn to_json(&self) -> String {
+        let names: Vec<String> = self.names.iter().map(|name| json_string(name)).collect();
+        let rows: Vec<String> = (0..self.names.len()).map(|deck| {
+            let cells: Vec<String> = (0..self.names.len()).map(|opponent| {
+                let matchup = self.matchup(deck, opponent);
+
+                match (deck != opponent).then_some(matchup.confidence_interval()).flatten() {
+                    Some((low, high)) => format!(
+                        "{{\"games\": {}, \"wins\": {}, \"losses\": {}, \"draws\": {}, \"win_rate\": {:.4}, \"ci_low\": {low:.4}, \"ci_high\": {high:.4}}}",
+                        matchup.games(),
+                        matchup.wins,
+                        matchup.losses,
+                        matchup.draws,
+                        matchup.win_rate().unwrap(),
+                    ),
+                    None => "null".to_string(),
+                }
+            }).collect();
+
+            format!("    [{}]", cells.join(", "))
+        }).collect();
+
+        format!("{{\n  \"decks\": [{}],\n  \"matchups\": [\n{}\n  ]\n}}\n", names.join(", "), rows.join(",\n"))
+    }
+
+    fn pairs(&self) -> impl Iterator<Item=(usize, usize, Matchup)> + '_ {
+        let size = self.names.len();
+
+        (0..size)
+            .flat_map(move |deck| (0..size).map(move |opponent| (deck, opponent)))
+            .filter(|(deck, opponent)| deck != opponent)
+            .map(|(deck, opponent)| (deck, opponent, self.matchup(deck, opponent)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    fn tournament() -> Tournament {
+        Tournament::new([
+            ("Keqing", deck([CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle])),
+            ("Bennett, Xingqiu, Collei", deck(OPPONENTS)),
+            ("Diluc", deck([CharacterCard::Diluc, CharacterCard::Xiangling, CharacterCard::Sucrose])),
+        ])
+        .games(4)
+        .seed(3)
+    }
+
+    #[test]
+    fn every_pairing_plays() {
+        let results = tournament().run(|_, seed| Box::new(RandomPlayer::new(seed)));
+
+        for deck in 0..3 {
+            assert_eq!(results.matchup(deck, deck), Matchup::default());
+            assert_eq!(results.total(deck).games(), 8);
+
+            for opponent in (0..3).filter(|opponent| *opponent != deck) {
+                let (matchup, mirror) = (results.matchup(deck, opponent), results.matchup(opponent, deck));
+
+                assert_eq!(matchup.games(), 4);
+                assert_eq!((matchup.wins, matchup.draws), (mirror.losses, mirror.draws));
+            }
+        }
+    }
+
+    #[test]
+    fn results_dont_depend_on_threads() {
+        let player = |_, seed| Box::new(RandomPlayer::new(seed)) as Box<dyn Player>;
+        let results = tournament().threads(1).run(player);
+
+        assert_eq!(tournament().threads(4).run(player), results);
+        assert_ne!(tournament().seed(4).threads(4).run(player), results);
+    }
+
+    #[test]
+    fn confidence_intervals() {
+        let matchup = Matchup { wins: 7, losses: 2, draws: 2 };
+        let (low, high) = matchup.confidence_interval().unwrap();
+
+        assert_eq!(matchup.win_rate(), Some(8.0 / 11.0));
+        assert!(low < 8.0 / 11.0 && 8.0 / 11.0 < high);
+        assert!((low - 0.434).abs() < 0.001 && (high - 0.903).abs() < 0.001);
+
+        assert_eq!(Matchup::default().confidence_interval(), None);
+    }
+
+    #[test]
+    fn exports() {
+        let results = tournament().run(|_, seed| Box::new(RandomPlayer::new(seed)));
+        let csv = results.to_csv();
+
+        assert_eq!(csv.lines().count(), 1 + 6);
+        assert!(csv.lines().nth(3).unwrap().starts_with("\"Bennett, Xingqiu, Collei\",Keqing,4,"));
+
+        let json = results.to_json();
+        assert!(json.contains("\"decks\": [\"Keqing\", \"Bennett, Xingqiu, Collei\", \"Diluc\"]"));
+        assert!(json.contains("    [null, {\"games\": 4,"));
+    }
+}
@@ -0,0 +1,46 @@
+//! Hosts matches on localhost for clients speaking the JSON-lines protocol described in
+//! [`genius_invokation::Server`]
+
+use std::process::ExitCode;
+use genius_invokation::*;
+
+const USAGE: &str = "\
+usage: gitcg-server [--port <number>]
+
+Hosts matches on 127.0.0.1 (default port: 7878), see the crate documentation for the protocol.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let port = match args.as_slice() {
+        [] => 7878,
+        ["--port", port] => match port.parse::<u16>() {
+            Ok(port) => port,
+            Err(_) => return usage(),
+        },
+        ["help" | "--help" | "-h"] => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS
+        },
+        _ => return usage(),
+    };
+
+    let result = Server::bind(("127.0.0.1", port)).and_then(|server| {
+        println!("listening on {} (protocol version {PROTOCOL_VERSION})", server.local_addr()?);
+        server.run()
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("{USAGE}");
+    ExitCode::from(2)
+}
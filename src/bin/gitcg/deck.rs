@@ -0,0 +1,191 @@
+//! Reading and writing decks in every format the tool understands
+
+use std::path::Path;
+use genius_invokation::*;
+use serde_json::{json, Value};
+
+const URL_STARTER: &str = "https://genshin.hotgames.gg/tcg/deck-builder?deck=";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// A deck builder url
+    Url,
+    /// The `deck` parameter of a deck builder url
+    Code,
+    /// One card name per line, optionally prefixed by an amount
+    Text,
+    /// An object with the names of the `characters` and `actions`
+    Json,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "url" => Some(Self::Url),
+            "code" => Some(Self::Code),
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the cards of a deck without checking whether they make a valid deck. The input is
+/// either a deck builder url, a deck code, or a file with a deck in any format
+pub fn read(input: &str) -> Result<Vec<Card>, String> {
+    let path = Path::new(input);
+
+    if !input.contains(URL_STARTER) && path.is_file() {
+        let contents = std::fs::read_to_string(path).map_err(|error| format!("can't read `{input}`: {error}"))?;
+        return parse(&contents)
+    }
+
+    parse(input)
+}
+
+/// Like [`read`], but for a valid deck
+pub fn read_deck(input: &str) -> Result<Deck, String> {
+    let cards = read(input)?;
+
+    match Deck::validate(cards.iter().copied()).first() {
+        Some(error) => Err(format!("`{input}` isn't a valid deck: {error}")),
+        None => Ok(Deck::from_iter(cards).expect("validated decks are valid")),
+    }
+}
+
+/// Guesses the format of a deck and reads its cards
+pub fn parse(input: &str) -> Result<Vec<Card>, String> {
+    let trimmed = input.trim();
+
+    if trimmed.starts_with('{') {
+        from_json(trimmed)
+    } else if trimmed.contains("deck-builder?deck=") {
+        from_url(trimmed)
+    } else if !trimmed.contains(char::is_whitespace) && trimmed.contains('.') {
+        from_url(&format!("{URL_STARTER}{trimmed}&ver=1"))
+    } else {
+        from_text(trimmed)
+    }
+}
+
+fn from_url(url: &str) -> Result<Vec<Card>, String> {
+    match deck_from_url(url) {
+        Ok(cards) => Ok(cards.collect()),
+        Err(UrlDeckError::InvalidUrl(_)) => Err("invalid deck builder url".to_string()),
+        Err(UrlDeckError::UnknownVersion(version)) => Err(format!("unknown deck builder version `{version}`")),
+        Err(UrlDeckError::UnknownCard(card)) => Err(format!("unknown card code `{card}`")),
+    }
+}
+
+fn from_text(text: &str) -> Result<Vec<Card>, String> {
+    let mut cards = Vec::new();
+
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        let (amount, name) = match line.split_once(' ') {
+            Some((amount, name)) => match amount.trim_end_matches('x').parse::<usize>() {
+                Ok(amount) => (amount, name.trim()),
+                Err(_) => (1, line),
+            },
+            None => (1, line),
+        };
+
+        let card = card_named(name)?;
+        cards.extend(std::iter::repeat_n(card, amount));
+    }
+
+    Ok(cards)
+}
+
+fn from_json(json: &str) -> Result<Vec<Card>, String> {
+    let value: Value = serde_json::from_str(json).map_err(|error| format!("invalid JSON: {error}"))?;
+    let names = |key: &str| -> Result<Vec<Card>, String> {
+        value.get(key)
+            .and_then(Value::as_array)
+            .ok_or_else(|| format!("missing `{key}` list"))?
+            .iter()
+            .map(|name| name.as_str().ok_or_else(|| format!("`{key}` must only contain card names")).and_then(card_named))
+            .collect()
+    };
+
+    Ok(names("characters")?.into_iter().chain(names("actions")?).collect())
+}
+
+/// Looks up a card by name, ignoring case as a fallback
+fn card_named(name: &str) -> Result<Card, String> {
+    Card::from_name(name)
+        .or_else(|| Card::all().find(|card| card.name().eq_ignore_ascii_case(name)))
+        .ok_or_else(|| format!("unknown card `{name}`"))
+}
+
+pub fn write(deck: &Deck, format: Format) -> Result<String, String> {
+    let url = || deck_to_url(deck).map_err(|card| format!("the deck builder doesn't have `{}`", card.name()));
+
+    Ok(match format {
+        Format::Url => url()?,
+        Format::Code => {
+            let url = url()?;
+            let code = &url[URL_STARTER.len()..];
+            code.split('&').next().unwrap_or(code).to_string()
+        },
+        Format::Text => to_text(deck),
+        Format::Json => {
+            let characters: Vec<&str> = deck.character_slice().iter().map(|card| card.name()).collect();
+            let actions: Vec<&str> = deck.iter().map(|card| card.name()).collect();
+
+            serde_json::to_string_pretty(&json!({ "characters": characters, "actions": actions })).unwrap()
+        },
+    })
+}
+
+fn to_text(deck: &Deck) -> String {
+    let mut text = String::from("# Characters\n");
+
+    for card in deck.character_slice() {
+        text.push_str(card.name());
+        text.push('\n');
+    }
+
+    text.push_str("\n# Action cards\n");
+
+    for card in deck.iter_unique() {
+        text.push_str(&format!("{} {}\n", amount(deck, card), card.name()));
+    }
+
+    text
+}
+
+/// How many copies of the card the deck has
+pub fn amount(deck: &Deck, card: ActionCard) -> usize {
+    deck.iter().filter(|other| *other == card).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://genshin.hotgames.gg/tcg/deck-builder?deck=1.6.MC.MD.MF.MG.MH.MI.MJ.MK.ML.MM.MN.MO.MP.MV.MY.e.g8.gB.gD.gF.gb.ge.gh.gk.gt.gv.gx.gz.wj.wl.wm&ver=1&lang=en&author=DefaultDeck";
+
+    #[test]
+    fn every_format_round_trips() {
+        let deck = Deck::from_iter(parse(URL).unwrap()).unwrap();
+
+        for format in [Format::Url, Format::Code, Format::Text, Format::Json] {
+            let written = write(&deck, format).unwrap();
+            assert_eq!(Deck::from_iter(parse(&written).unwrap()), Ok(deck.clone()), "{format:?}");
+        }
+    }
+
+    #[test]
+    fn text_is_lenient() {
+        let cards = parse("Keqing\n# comment\n\n2x paimon\n1 Strategize").unwrap();
+
+        assert_eq!(cards, vec![
+            CharacterCard::Keqing.into(),
+            CompanionCard::Paimon.into(),
+            CompanionCard::Paimon.into(),
+            NormalEventCard::Strategize.into(),
+        ]);
+
+        assert_eq!(parse("Keqing's Lover"), Err("unknown card `Keqing's Lover`".to_string()));
+    }
+}
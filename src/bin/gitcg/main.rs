@@ -0,0 +1,345 @@
+//! Command-line tool for working with Genius Invokation TCG decks
+//!
+//! Decks can be given as a deck builder url, a deck code (the `deck` parameter of such a url),
+//! or a file with a deck in any format `convert` writes.
+
+use std::process::ExitCode;
+use genius_invokation::*;
+
+mod deck;
+use deck::Format;
+
+mod play;
+
+const USAGE: &str = "\
+usage: gitcg <command> [arguments]
+
+commands:
+    validate <deck>                          lists every reason why the deck is invalid
+    show <deck>                              prints the deck grouped by card type
+    convert <deck> <url|code|text|json>      writes the deck in another format
+    stats <deck>                             prints cost curves and card type counts
+    diff <deck> <deck>                       lists the cards added and removed between decks
+    play <deck> <deck> [options]             plays a match, see `gitcg play --help`
+    catalog <json|csv>                       exports every card in the game
+
+<deck> is a deck builder url, a deck code, or a file with a deck in any format.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["validate", input] => validate(input),
+        ["show", input] => deck::read_deck(input).map(|deck| {
+            print!("{}", show(&deck));
+            ExitCode::SUCCESS
+        }),
+        ["convert", input, format] => match Format::parse(format) {
+            Some(format) => deck::read_deck(input).and_then(|deck| deck::write(&deck, format)).map(|written| {
+                println!("{}", written.trim_end());
+                ExitCode::SUCCESS
+            }),
+            None => Err(format!("unknown format `{format}`, expected url, code, text or json")),
+        },
+        ["stats", input] => deck::read_deck(input).map(|deck| {
+            print!("{}", stats(&deck));
+            ExitCode::SUCCESS
+        }),
+        ["diff", old, new] => deck::read_deck(old).and_then(|old| deck::read_deck(new).map(|new| {
+            let changes = diff(&old, &new);
+            print!("{changes}");
+
+            if changes.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+        })),
+        ["catalog", "json"] => {
+            print!("{}", catalog_json());
+            Ok(ExitCode::SUCCESS)
+        },
+        ["catalog", "csv"] => {
+            print!("{}", catalog_csv());
+            Ok(ExitCode::SUCCESS)
+        },
+        ["catalog", format] => Err(format!("unknown format `{format}`, expected json or csv")),
+        ["play", "help" | "--help" | "-h"] => {
+            println!("{}", play::USAGE);
+            Ok(ExitCode::SUCCESS)
+        },
+        ["play", args @ ..] => play::run(args).map(|()| ExitCode::SUCCESS),
+        ["help" | "--help" | "-h"] => {
+            println!("{USAGE}");
+            Ok(ExitCode::SUCCESS)
+        },
+        _ => Err(USAGE.to_string()),
+    };
+
+    result.unwrap_or_else(|error| {
+        eprintln!("{error}");
+        ExitCode::from(2)
+    })
+}
+
+/// Prints every deck error, failing if there's any
+fn validate(input: &str) -> Result<ExitCode, String> {
+    let errors = Deck::validate(deck::read(input)?);
+
+    if errors.is_empty() {
+        println!("valid deck");
+        return Ok(ExitCode::SUCCESS)
+    }
+
+    for error in errors {
+        println!("{error}");
+    }
+
+    Ok(ExitCode::FAILURE)
+}
+
+/// The group and subgroup of an action card, in deck order
+fn category(card: ActionCard) -> (&'static str, &'static str) {
+    match card {
+        ActionCard::Equipment(EquipmentCard::Talent(_))   => ("Equipment", "Talent"),
+        ActionCard::Equipment(EquipmentCard::Weapon(_))   => ("Equipment", "Weapon"),
+        ActionCard::Equipment(EquipmentCard::Artifact(_)) => ("Equipment", "Artifact"),
+        ActionCard::Support(SupportCard::Location(_))     => ("Support", "Location"),
+        ActionCard::Support(SupportCard::Companion(_))    => ("Support", "Companion"),
+        ActionCard::Support(SupportCard::Item(_))         => ("Support", "Item"),
+        ActionCard::Event(EventCard::Resonance(_))        => ("Event", "Elemental Resonance"),
+        ActionCard::Event(EventCard::Normal(_))           => ("Event", "Normal"),
+        ActionCard::Event(EventCard::Food(_))             => ("Event", "Food"),
+        ActionCard::Event(EventCard::Arcane(_))           => ("Event", "Arcane Legend"),
+        #[cfg(feature = "custom-cards")]
+        ActionCard::Custom(card)                          => ("Custom", match card.category() {
+            CustomCategory::Weapon(_) => "Weapon",
+            CustomCategory::Artifact  => "Artifact",
+            CustomCategory::Location  => "Location",
+            CustomCategory::Companion => "Companion",
+            CustomCategory::Item      => "Item",
+            CustomCategory::Event     => "Event",
+            CustomCategory::Food      => "Food",
+        }),
+    }
+}
+
+fn cost(cost: CardCost) -> String {
+    let mut parts = Vec::new();
+
+    if let Some((element, amount)) = cost.exact() {
+        parts.push(format!("{amount} {element:?}"));
+    }
+
+    if cost.matching() > 0 {
+        parts.push(format!("{} Matching", cost.matching()));
+    }
+
+    if cost.unaligned() > 0 {
+        parts.push(format!("{} Unaligned", cost.unaligned()));
+    }
+
+    if parts.is_empty() {
+        parts.push("free".to_string());
+    }
+
+    if cost.energy() > 0 {
+        parts.push(format!("{} Energy", cost.energy()));
+    }
+
+    if cost.discard() > 0 {
+        parts.push(format!("discard {}", cost.discard()));
+    }
+
+    parts.join(" + ")
+}
+
+fn show(deck: &Deck) -> String {
+    let mut text = String::from("Characters\n");
+
+    for card in deck.character_slice() {
+        let weapon = card.weapon().map_or("Other Weapons".to_string(), |weapon| format!("{weapon:?}"));
+        text.push_str(&format!("    {:<24} {:<8} {:<14} {:?}\n", card.name(), format!("{:?}", card.element()), weapon, card.faction()));
+    }
+
+    let mut group = "";
+    let mut subgroup = "";
+
+    for card in deck.iter_unique() {
+        let (new_group, new_subgroup) = category(card);
+
+        if new_group != group {
+            group = new_group;
+            text.push_str(&format!("\n{group}\n"));
+        }
+
+        if new_subgroup != subgroup {
+            subgroup = new_subgroup;
+            text.push_str(&format!("    {subgroup}\n"));
+        }
+
+        text.push_str(&format!("        {} {:<44} {}\n", deck::amount(deck, card), card.name(), cost(card.cost())));
+    }
+
+    text
+}
+
+fn stats(deck: &Deck) -> String {
+    let mut text = String::from("Cost curve\n");
+    let max = deck.iter().map(|card| card.cost().amount()).max().unwrap_or(0);
+
+    for amount in 0..=max {
+        let count = deck.iter().filter(|card| card.cost().amount() == amount).count();
+        text.push_str(&format!("    {amount} dice  {count:>2}  {}\n", "#".repeat(count)));
+    }
+
+    text.push_str("\nDice type\n");
+
+    let dice_types = ["Unaligned", "Matching"].into_iter()
+        .chain(Element::ALL.map(element_name))
+        .chain(["Mixed"]);
+
+    for name in dice_types {
+        let count = deck.iter().filter(|card| dice_type(card.cost()) == Some(name)).count();
+
+        if count > 0 {
+            text.push_str(&format!("    {name:<10} {count:>2}\n"));
+        }
+    }
+
+    let free = deck.iter().filter(|card| card.cost().amount() == 0).count();
+    let energy = deck.iter().filter(|card| card.cost().energy() > 0).count();
+    text.push_str(&format!("    {:<10} {free:>2}\n    {:<10} {energy:>2}\n", "Free", "Energy"));
+
+    text.push_str("\nCard types\n");
+
+    let mut categories: Vec<((&str, &str), usize)> = Vec::new();
+
+    for card in deck.iter() {
+        match categories.iter_mut().find(|(category, _)| *category == self::category(card)) {
+            Some((_, count)) => *count += 1,
+            None => categories.push((category(card), 1)),
+        }
+    }
+
+    for group in ["Equipment", "Support", "Event"] {
+        let subgroups: Vec<String> = categories.iter()
+            .filter(|((name, _), _)| *name == group)
+            .map(|((_, subgroup), count)| format!("{subgroup} {count}"))
+            .collect();
+        let total: usize = categories.iter().filter(|((name, _), _)| *name == group).map(|(_, count)| count).sum();
+
+        text.push_str(&format!("    {group:<10} {total:>2}"));
+
+        if !subgroups.is_empty() {
+            text.push_str(&format!("  ({})", subgroups.join(", ")));
+        }
+
+        text.push('\n');
+    }
+
+    let prices: Vec<u16> = deck.iter().filter_map(|card| card.shop_price()).collect();
+    text.push_str(&format!(
+        "\nShop price\n    {} Lucky Coins for {} cards, {} cards aren't sold\n",
+        prices.iter().map(|price| *price as u32).sum::<u32>(),
+        prices.len(),
+        deck.iter().count() - prices.len(),
+    ));
+
+    text
+}
+
+/// The kind of dice a card costs, with costs that need several kinds counted as mixed
+fn dice_type(cost: CardCost) -> Option<&'static str> {
+    if cost.amount() == 0 {
+        None
+    } else if cost.is_mixed() {
+        Some("Mixed")
+    } else if let Some((element, _)) = cost.exact() {
+        Some(element_name(element))
+    } else if cost.matching() > 0 {
+        Some("Matching")
+    } else {
+        Some("Unaligned")
+    }
+}
+
+fn element_name(element: Element) -> &'static str {
+    match element {
+        Element::Cryo => "Cryo",
+        Element::Hydro => "Hydro",
+        Element::Pyro => "Pyro",
+        Element::Electro => "Electro",
+        Element::Anemo => "Anemo",
+        Element::Geo => "Geo",
+        Element::Dendro => "Dendro",
+    }
+}
+
+/// The cards removed from `old` and added to `new`, empty if both decks are the same
+fn diff(old: &Deck, new: &Deck) -> String {
+    let mut text = String::new();
+
+    for card in old.character_slice().iter().filter(|card| !new.has_character(**card)) {
+        text.push_str(&format!("- {}\n", card.name()));
+    }
+
+    for card in new.character_slice().iter().filter(|card| !old.has_character(**card)) {
+        text.push_str(&format!("+ {}\n", card.name()));
+    }
+
+    let mut cards: Vec<ActionCard> = old.iter_unique().collect();
+    cards.extend(new.iter_unique().filter(|card| !old.contains(*card)));
+
+    for card in cards {
+        let change = deck::amount(new, card) as i32 - deck::amount(old, card) as i32;
+
+        if change != 0 {
+            text.push_str(&format!("{change:+} {}\n", card.name()));
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://genshin.hotgames.gg/tcg/deck-builder?deck=1.6.MC.MD.MF.MG.MH.MI.MJ.MK.ML.MM.MN.MO.MP.MV.MY.e.g8.gB.gD.gF.gb.ge.gh.gk.gt.gv.gx.gz.wj.wl.wm&ver=1&lang=en&author=DefaultDeck";
+
+    #[test]
+    fn diffs() {
+        let old = deck::read_deck(URL).unwrap();
+        let new = deck::read_deck(&URL.replace("1.6.", "1.4.").replace(".MC.", ".MF.")).unwrap();
+
+        assert_eq!(diff(&old, &old), "");
+        assert_eq!(diff(&old, &new), "- Diluc\n+ Xingqiu\n+1 Strategize\n-1 The Bestest Travel Companion!\n");
+    }
+
+    #[test]
+    fn stats_add_up() {
+        let stats = stats(&deck::read_deck(URL).unwrap());
+
+        assert!(stats.contains("Equipment  12  (Weapon 5, Artifact 7)"), "{stats}");
+        assert!(stats.contains("Event      15  (Normal 13, Food 2)"), "{stats}");
+        assert!(stats.contains("2 dice  17"), "{stats}");
+    }
+
+    #[test]
+    fn stats_follow_the_deck_rules() {
+        let rules = DeckRules::standard().with_characters(2).with_actions(4);
+        let cards = [CharacterCard::Keqing, CharacterCard::Fischl].map(Card::from).into_iter()
+            .chain([NormalEventCard::Strategize, NormalEventCard::Strategize].map(Card::from))
+            .chain([Card::from(CompanionCard::Paimon), Card::from(FoodCard::SweetMadame)]);
+        let stats = stats(&Deck::from_iter_with(&rules, cards).unwrap());
+
+        assert!(stats.contains("for 3 cards, 1 cards aren't sold"), "{stats}");
+        assert!(stats.contains("Matching    3"), "{stats}");
+    }
+
+    #[test]
+    fn mixed_costs_have_their_own_dice_type() {
+        assert_eq!(dice_type(CardCost::ZERO.with_exact(Element::Cryo, 1).with_unaligned(2)), Some("Mixed"));
+        assert_eq!(dice_type(CardCost::ZERO.with_exact(Element::Cryo, 1)), Some("Cryo"));
+        assert_eq!(dice_type(CardCost::ZERO.with_unaligned(2)), Some("Unaligned"));
+        assert_eq!(dice_type(CardCost::ZERO), None);
+    }
+}
@@ -0,0 +1,394 @@
+//! Playing a match from the terminal, against a bot or another player on the same terminal
+
+use std::io::{BufRead, Write};
+use genius_invokation::*;
+
+use crate::deck;
+
+pub const USAGE: &str = "\
+usage: gitcg play <deck> <opponent deck> [options]
+
+options:
+    --opponent <mcts|greedy|random|human>    who plays the opponent's deck (default: mcts)
+    --second                                 play second instead of first
+    --seed <number>                          seed of the match (default: random)
+    --replay <file>                          where to save the replay (default: replay-<seed>.txt)";
+
+/// Who takes the decisions for one side of the match
+pub enum Seat {
+    Human,
+    Bot(Box<dyn Player>),
+}
+
+pub fn run(args: &[&str]) -> Result<(), String> {
+    let mut decks = Vec::new();
+    let mut opponent = "mcts";
+    let mut second = false;
+    let mut seed = None;
+    let mut replay = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match *arg {
+            "--opponent" => opponent = args.next().ok_or(USAGE)?,
+            "--second" => second = true,
+            "--seed" => seed = Some(args.next().and_then(|seed| seed.parse().ok()).ok_or(USAGE)?),
+            "--replay" => replay = Some(args.next().ok_or(USAGE)?.to_string()),
+            input => decks.push(deck::read_deck(input)?),
+        }
+    }
+
+    let [own, other]: [Deck; 2] = decks.try_into().map_err(|_| USAGE.to_string())?;
+    let seed = seed.unwrap_or_else(random_seed);
+
+    let bot = match opponent {
+        "mcts" => Seat::Bot(Box::new(MctsPlayer::new(seed).iterations(300))),
+        "greedy" => Seat::Bot(Box::new(GreedyPlayer::new())),
+        "random" => Seat::Bot(Box::new(RandomPlayer::new(seed))),
+        "human" => Seat::Human,
+        _ => return Err(format!("unknown opponent `{opponent}`, expected mcts, greedy, random or human")),
+    };
+
+    let (decks, seats) = match second {
+        false => ([own, other], [Seat::Human, bot]),
+        true => ([other, own], [bot, Seat::Human]),
+    };
+
+    let stdin = std::io::stdin();
+    let game = play(&decks, seats, seed, &mut stdin.lock(), &mut std::io::stdout())?;
+
+    let path = replay.unwrap_or_else(|| format!("replay-{seed}.txt"));
+    let replay = Replay::record(&decks[0], &decks[1], seed, &game);
+    std::fs::write(&path, replay.to_string()).map_err(|error| format!("can't save the replay: {error}"))?;
+    println!("Replay saved to {path}");
+
+    Ok(())
+}
+
+fn random_seed() -> u64 {
+    use std::hash::{BuildHasher, RandomState};
+    RandomState::new().hash_one(std::time::SystemTime::now())
+}
+
+/// Plays a whole match, asking humans for their choices through `input`
+pub fn play(
+    decks: &[Deck; 2],
+    mut seats: [Seat; 2],
+    seed: u64,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<GameState, String> {
+    let mut game = GameState::new(&decks[0], &decks[1], seed);
+    let humans = seats.iter().filter(|seat| matches!(seat, Seat::Human)).count();
+    let mut shown = 0;
+
+    for (index, seat) in seats.iter_mut().enumerate() {
+        if let Seat::Bot(bot) = seat {
+            bot.start(player_id(index), &decks[index], &decks[1 - index]);
+        }
+    }
+
+    let mut terminal = Terminal { input, output };
+
+    while !game.is_over() {
+        let acting = game.acting_player();
+        let actions = game.legal_actions();
+
+        terminal.events(&game.events()[shown..])?;
+        shown = game.events().len();
+
+        let choice = match &mut seats[acting.index()] {
+            Seat::Bot(bot) => bot.choose(&game.observe(acting), &actions),
+            Seat::Human => {
+                if humans > 1 {
+                    terminal.line(&format!("\n===== {acting:?} player's turn ====="))?;
+                }
+
+                terminal.choose(&game.observe(acting), &actions)?
+            },
+        };
+
+        let action = *actions.get(choice).ok_or("players must choose one of the legal actions")?;
+        game.apply(action).map_err(|error| format!("{error:?}"))?;
+    }
+
+    terminal.events(&game.events()[shown..])?;
+    Ok(game)
+}
+
+fn player_id(index: usize) -> PlayerId {
+    if index == 0 { PlayerId::First } else { PlayerId::Second }
+}
+
+struct Terminal<'t, R, W> {
+    input: &'t mut R,
+    output: &'t mut W,
+}
+
+impl<R: BufRead, W: Write> Terminal<'_, R, W> {
+    fn line(&mut self, line: &str) -> Result<(), String> {
+        writeln!(self.output, "{line}").map_err(|error| error.to_string())
+    }
+
+    fn read(&mut self, prompt: &str) -> Result<String, String> {
+        write!(self.output, "{prompt}").and_then(|_| self.output.flush()).map_err(|error| error.to_string())?;
+
+        let mut line = String::new();
+
+        match self.input.read_line(&mut line) {
+            Ok(0) => Err("the match was abandoned".to_string()),
+            Ok(_) => Ok(line.trim().to_string()),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    /// Shows what happened since the last choice. Taken actions are left out, since the
+    /// events they cause already describe them
+    fn events(&mut self, events: &[Event]) -> Result<(), String> {
+        for event in events {
+            match event {
+                Event::Action { player, action: Action::EndRound } => self.line(&format!("* {player:?} ends the round"))?,
+                Event::Action { .. } => (),
+                event => self.line(&format!("* {event}"))?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn choose(&mut self, observation: &Observation, actions: &[Action]) -> Result<usize, String> {
+        self.line(&board(observation))?;
+
+        match observation.decision {
+            Some(Decision::Mulligan { .. }) => self.mulligan(observation, actions),
+            Some(Decision::Reroll { .. }) => self.reroll(observation, actions),
+            _ => self.pick(observation, actions),
+        }
+    }
+
+    fn pick(&mut self, observation: &Observation, actions: &[Action]) -> Result<usize, String> {
+        for (index, action) in actions.iter().enumerate() {
+            self.line(&format!("{:>3}. {}", index + 1, describe(observation, action)))?;
+        }
+
+        loop {
+            match self.read("> ")?.as_str() {
+                "board" => self.line(&board(observation))?,
+                "help" => self.line("type the number of an action, or `board` to see the board again")?,
+                answer => match answer.parse::<usize>() {
+                    Ok(choice) if (1..=actions.len()).contains(&choice) => return Ok(choice - 1),
+                    _ => self.line(&format!("type a number from 1 to {}", actions.len()))?,
+                },
+            }
+        }
+    }
+
+    fn mulligan(&mut self, observation: &Observation, actions: &[Action]) -> Result<usize, String> {
+        let hand = observation.own().hand.clone().unwrap_or_default();
+
+        loop {
+            let answer = self.read(&format!("Cards to swap, by position from 1 to {} (empty to keep all): ", hand.len()))?;
+            let positions: Option<Vec<usize>> = answer.split_whitespace().map(|position| position.parse().ok()).collect();
+
+            let cards = positions.and_then(|positions| positions.iter().try_fold(0u16, |mask, position| {
+                (1..=hand.len()).contains(position).then_some(mask | 1 << (position - 1))
+            }));
+
+            match cards.and_then(|cards| actions.iter().position(|action| *action == Action::SwapCards { cards })) {
+                Some(choice) => return Ok(choice),
+                None => self.line("type positions separated by spaces, such as `1 3`")?,
+            }
+        }
+    }
+
+    fn reroll(&mut self, observation: &Observation, actions: &[Action]) -> Result<usize, String> {
+        let dice: Vec<Die> = observation.own().dice.iter()
+            .flat_map(|dice| dice.iter().flat_map(|(die, amount)| std::iter::repeat_n(die, amount as usize)))
+            .collect();
+
+        for (index, die) in dice.iter().enumerate() {
+            self.line(&format!("{:>3}. {}", index + 1, die_name(*die)))?;
+        }
+
+        loop {
+            let answer = self.read("Dice to reroll, by position (empty to keep all): ")?;
+            let positions: Option<Vec<usize>> = answer.split_whitespace().map(|position| position.parse().ok()).collect();
+
+            let reroll: Option<Dice> = positions
+                .filter(|positions| {
+                    let mut unique = positions.clone();
+                    unique.sort();
+                    unique.dedup();
+                    unique.len() == positions.len() && positions.iter().all(|position| (1..=dice.len()).contains(position))
+                })
+                .map(|positions| positions.iter().map(|position| dice[position - 1]).collect());
+
+            match reroll.and_then(|dice| actions.iter().position(|action| *action == Action::Reroll { dice })) {
+                Some(choice) => return Ok(choice),
+                None => self.line("type different positions separated by spaces, such as `1 3`")?,
+            }
+        }
+    }
+}
+
+fn die_name(die: Die) -> String {
+    match die {
+        Die::Omni => "Omni".to_string(),
+        Die::Element(element) => format!("{element:?}"),
+    }
+}
+
+fn entity(entity: &Entity) -> String {
+    let mut text = entity.name().to_string();
+
+    if let Some(usages) = entity.usages() {
+        text.push_str(&format!(" ({usages})"));
+    }
+
+    if let Some(rounds) = entity.duration() {
+        text.push_str(&format!(" [{rounds} round{}]", if rounds == 1 { "" } else { "s" }));
+    }
+
+    text
+}
+
+fn entities(label: &str, list: &[Entity]) -> Option<String> {
+    let names: Vec<String> = list.iter().map(entity).collect();
+    (!names.is_empty()).then(|| format!("  {label}: {}", names.join(", ")))
+}
+
+/// The board as the viewer sees it, opponent first
+pub fn board(observation: &Observation) -> String {
+    let mut lines = vec![String::new()];
+    let turn = if observation.turn == observation.viewer { "your turn".to_string() } else { "opponent's turn".to_string() };
+    lines.push(format!("Round {}, {:?}, {turn}", observation.round, observation.phase));
+
+    for (label, view) in [("Opponent", observation.opponent()), ("You", observation.own())] {
+        let mut header = format!("{label}: hand {}, pile {}, dice {}", view.hand_size, view.pile_size, view.dice_count);
+
+        if view.ended_round {
+            header.push_str(", ended the round");
+        }
+
+        lines.push(header);
+
+        for (index, character) in view.characters.iter().enumerate() {
+            let marker = if index == view.active { ">" } else { " " };
+            let mut line = format!(
+                "  {marker} {:<20} {:>2}/{} HP  {}/{} energy",
+                character.card().name(),
+                character.hp(),
+                character.max_hp(),
+                character.energy(),
+                character.max_energy(),
+            );
+
+            if !character.is_alive() {
+                line.push_str("  defeated");
+            }
+
+            let aura: Vec<String> = character.aura().elements().map(|element| format!("{element:?}")).collect();
+
+            if !aura.is_empty() {
+                line.push_str(&format!("  aura: {}", aura.join(" + ")));
+            }
+
+            let attached: Vec<String> = character.equipment().iter().chain(character.statuses()).map(entity).collect();
+
+            if !attached.is_empty() {
+                line.push_str(&format!("  [{}]", attached.join(", ")));
+            }
+
+            lines.push(line);
+        }
+
+        lines.extend(entities("Combat statuses", &view.combat_statuses));
+        lines.extend(entities("Summons", &view.summons));
+        lines.extend(entities("Supports", &view.supports));
+
+        if let Some(dice) = view.dice {
+            let dice: Vec<String> = dice.iter().map(|(die, amount)| format!("{amount} {}", die_name(die))).collect();
+            lines.push(format!("  Dice: {}", if dice.is_empty() { "none".to_string() } else { dice.join(", ") }));
+        }
+
+        if let Some(hand) = &view.hand {
+            let hand: Vec<&str> = hand.iter().map(|card| card.name()).collect();
+            lines.push(format!("  Hand: {}", hand.join(", ")));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// A description of an action for the acting player
+pub fn describe(observation: &Observation, action: &Action) -> String {
+    let own = observation.own();
+    let character = |index: usize| own.characters.get(index).map_or("?", |character| character.card().name());
+
+    match *action {
+        Action::PlayCard { card, target } => {
+            let target = match target {
+                None => String::new(),
+                Some(Target::Character(index)) => format!(" on {}", character(index)),
+                Some(Target::Summon(player, index)) => {
+                    let side = &observation.players[player.index()];
+                    format!(" on {}", side.summons.get(index).map_or("?", Entity::name))
+                },
+                Some(Target::Support(index)) => format!(" replacing {}", own.supports.get(index).map_or("?", Entity::name)),
+                Some(Target::Transfer(from, to)) => format!(" from {} to {}", character(from), character(to)),
+            };
+
+            format!("Play {}{target}", card.name())
+        },
+        Action::ElementalTuning { card, die } => {
+            let element = own.characters.get(own.active).map(|character| character.card().element());
+            format!("Tune {} ({} die into {})", card.name(), die_name(die), element.map_or("?".to_string(), |element| format!("{element:?}")))
+        },
+        Action::UseSkill { skill } => {
+            let active = own.characters.get(own.active).map(|character| character.card());
+            let name = active.and_then(|card| card.skills().get(skill)).map_or("?", |skill| skill.name());
+            format!("Use {name}")
+        },
+        Action::SwitchCharacter { to } => format!("Switch to {}", character(to)),
+        Action::EndRound => "End the round".to_string(),
+        Action::ChooseActive { character: index } => format!("Choose {} as the active character", character(index)),
+        Action::SwapCards { cards } => format!("Swap cards {cards:#b}"),
+        Action::Reroll { dice } => {
+            let dice: Vec<String> = dice.iter().map(|(die, amount)| format!("{amount} {}", die_name(die))).collect();
+            format!("Reroll {}", if dice.is_empty() { "nothing".to_string() } else { dice.join(", ") })
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://genshin.hotgames.gg/tcg/deck-builder?deck=1.6.MC.MD.MF.MG.MH.MI.MJ.MK.ML.MM.MN.MO.MP.MV.MY.e.g8.gB.gD.gF.gb.ge.gh.gk.gt.gv.gx.gz.wj.wl.wm&ver=1&lang=en&author=DefaultDeck";
+
+    #[test]
+    fn scripted_match() {
+        let deck = deck::read_deck(URL).unwrap();
+        let decks = [deck.clone(), deck];
+
+        // swaps the first card, rerolls the first die, then always takes the first action
+        let mut input = "1\n1\n".to_string() + &"1\n".repeat(5000);
+        let mut output = Vec::new();
+
+        let seats = [Seat::Human, Seat::Bot(Box::new(RandomPlayer::new(1)))];
+        let game = play(&decks, seats, 7, &mut input.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(game.is_over());
+        assert!(output.contains("You: hand 5, pile 25, dice 0"), "{output}");
+        assert!(output.contains(". End the round"));
+
+        let replay = Replay::record(&decks[0], &decks[1], 7, &game);
+        assert_eq!(replay.play().unwrap().outcome(), game.outcome());
+
+        // running out of input gives up on the match
+        input.truncate(4);
+        let seats = [Seat::Human, Seat::Human];
+        assert!(play(&decks, seats, 7, &mut input.as_bytes(), &mut Vec::new()).is_err());
+    }
+}
@@ -29,6 +29,11 @@ pub trait PlayingCard: sealed::Trait {
     /// [Paimon]: crate::CompanionCard::Paimon
     fn shop_price(&self) -> Option<Price>;
 
+    /// The game version that added this card
+    /// 
+    /// Custom cards don't belong to any version, so they return [`None`]
+    fn version(&self) -> Option<GameVersion>;
+
     impl_method!(
         EquipmentCard: an equipment,
         EventCard: an event,
@@ -41,12 +46,17 @@ pub trait PlayingCard: sealed::Trait {
         ItemCard: an item,
         LocationCard: a location,
         ElementalResonanceCard: a resonance,
+        ArcaneLegendCard: an arcane,
     );
 
     /// Attempts to parse `self` as a normal event card (non-food and non-resonance).
     /// Returns None if this card is of another type/subtype
     fn normal_event(&self) -> Option<NormalEventCard> { None }
 
+    /// Attempts to parse `self` as a custom card. Returns None if this card is a built-in one
+    #[cfg(feature = "custom-cards")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "custom-cards")))]
+    fn custom(&self) -> Option<CustomCardId> { None }
 }
 
 mod sealed {
@@ -63,7 +73,10 @@ mod sealed {
 
     impl_trait!(ActionCard
         EquipmentCard ArtifactCard TalentCard WeaponCard
-        EventCard FoodCard NormalEventCard ElementalResonanceCard
+        EventCard FoodCard NormalEventCard ElementalResonanceCard ArcaneLegendCard
         SupportCard CompanionCard ItemCard LocationCard
     );
+
+    #[cfg(feature = "custom-cards")]
+    impl Trait for CustomCardId {}
 }
\ No newline at end of file
@@ -1,10 +1,27 @@
 use crate::Element;
 
+/// What it takes to play a card: dice, energy, and sometimes other cards from the hand
+///
+/// Most cards only need one kind of dice, described by [`dice_type`](Self::dice_type) and
+/// [`amount`](Self::amount). Costs can also mix several components, like the "1 Cryo + 2
+/// unaligned" of a Cryo character's Normal Attack, which can be built from [`CardCost::ZERO`]:
+///
+/// ```
+/// use genius_invokation::{CardCost, Element};
+///
+/// let cost = CardCost::ZERO.with_exact(Element::Cryo, 1).with_unaligned(2);
+///
+/// assert_eq!(cost.exact(), Some((Element::Cryo, 1)));
+/// assert_eq!(cost.amount(), 3);
+/// ```
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
 pub struct CardCost {
-    dice: DiceCost,
-    amount: u8,
+    /// Dice of a given element, kept with its element even once discounted down to zero
+    exact: Option<(Element, u8)>,
+    matching: u8,
+    unaligned: u8,
     energy: u8,
+    discard: u8,
 }
 
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
@@ -19,39 +36,162 @@ pub enum DiceCost {
 
 impl CardCost {
     /// For cards that cost nothing
-    pub(crate) const ZERO: Self = Self { dice: DiceCost::Same, amount: 0, energy: 0 };
+    pub const ZERO: Self = Self { exact: None, matching: 0, unaligned: 0, energy: 0, discard: 0 };
 
     /// For cards that cost one die of any element
-    pub(crate) const ONE: Self = Self { dice: DiceCost::Same, amount: 1, energy: 0 };
+    pub(crate) const ONE: Self = Self::new(DiceCost::Same, 1, 0);
 
     /// For cards that cost any two dice of any type
-    pub(crate) const ANY2: Self = Self { dice: DiceCost::Any, amount: 2, energy: 0 };
+    pub(crate) const ANY2: Self = Self::new(DiceCost::Any, 2, 0);
 
     /// For cards that cost two dice of the same type
-    pub(crate) const MATCH2: Self = Self { dice: DiceCost::Same, amount: 2, energy: 0 };
+    pub(crate) const MATCH2: Self = Self::new(DiceCost::Same, 2, 0);
 
     /// For cards that cost three dice of the same type
-    pub(crate) const MATCH3: Self = Self { dice: DiceCost::Same, amount: 3, energy: 0 };
+    pub(crate) const MATCH3: Self = Self::new(DiceCost::Same, 3, 0);
 
     pub(crate) const fn new(dice: DiceCost, amount: u8, energy: u8) -> Self {
-        Self { dice, amount, energy }
+        let cost = Self::ZERO.with_energy(energy);
+
+        match dice {
+            DiceCost::Any => cost.with_unaligned(amount),
+            DiceCost::Same => cost.with_matching(amount),
+            DiceCost::Exact(element) => cost.with_exact(element, amount),
+        }
+    }
+
+    /// The same cost, requiring `amount` dice of `element` instead of any it required
+    pub const fn with_exact(self, element: Element, amount: u8) -> Self {
+        Self { exact: Some((element, amount)), ..self }
+    }
+
+    /// The same cost, requiring `amount` dice of the same element instead of any it required
+    pub const fn with_matching(self, amount: u8) -> Self {
+        Self { matching: amount, ..self }
+    }
+
+    /// The same cost, requiring `amount` dice of any element instead of any it required
+    pub const fn with_unaligned(self, amount: u8) -> Self {
+        Self { unaligned: amount, ..self }
+    }
+
+    /// The same cost, requiring `amount` energy instead of any it required
+    pub const fn with_energy(self, amount: u8) -> Self {
+        Self { energy: amount, ..self }
+    }
+
+    /// The same cost, requiring `amount` other cards to be discarded from the hand
+    pub const fn with_discard(self, amount: u8) -> Self {
+        Self { discard: amount, ..self }
+    }
+
+    /// Reduces the amount of dice required, unaligned dice first, then matching ones and
+    /// finally dice of an exact element
+    pub(crate) fn discount(&mut self, amount: u8) {
+        let mut left = amount;
+
+        for dice in [&mut self.unaligned, &mut self.matching] {
+            let used = (*dice).min(left);
+            *dice -= used;
+            left -= used;
+        }
+
+        if let Some((_, dice)) = &mut self.exact {
+            *dice = dice.saturating_sub(left);
+        }
+    }
+
+    /// Raises the amount of dice required, such as when an effect taxes switching. Costs of
+    /// a single kind of dice keep it, others get more unaligned dice
+    pub(crate) fn increase(&mut self, amount: u8) {
+        match (self.exact, self.matching, self.unaligned) {
+            (Some((element, dice)), 0, 0) => self.exact = Some((element, dice.saturating_add(amount))),
+            (None, matching, 0) if matching > 0 => self.matching = matching.saturating_add(amount),
+            _ => self.unaligned = self.unaligned.saturating_add(amount),
+        }
     }
 
     /// Retrieves which type of dice is required to play the given card
+    ///
+    /// For costs that mix several kinds of dice, this is the most restrictive one: an exact
+    /// element over matching dice, and matching dice over unaligned ones. Free cards count as
+    /// requiring matching dice.
     pub fn dice_type(&self) -> DiceCost {
-        self.dice
+        match self.exact {
+            Some((element, _)) => DiceCost::Exact(element),
+            None if self.unaligned > 0 && self.matching == 0 => DiceCost::Any,
+            None => DiceCost::Same,
+        }
     }
 
-    /// The amount of dice required to play the given card
+    /// The amount of dice required to play the given card, of every kind
     pub fn amount(&self) -> u8 {
-        self.amount
+        self.exact.map_or(0, |(_, dice)| dice) + self.matching + self.unaligned
+    }
+
+    /// The element and amount of dice required of an exact element, if any
+    pub fn exact(&self) -> Option<(Element, u8)> {
+        self.exact.filter(|(_, dice)| *dice > 0)
+    }
+
+    /// The amount of dice that have to share the same element
+    pub fn matching(&self) -> u8 {
+        self.matching
+    }
+
+    /// The amount of dice of any element
+    pub fn unaligned(&self) -> u8 {
+        self.unaligned
     }
 
     /// The amount of character energy required to play this card
-    /// 
+    ///
     /// Most cards don't require energy, which means zero
     pub fn energy(&self) -> u8 {
         self.energy
     }
+
+    /// The amount of other cards that have to be discarded from the hand to play this card
+    ///
+    /// [`GameState`](crate::GameState) has no way to choose them yet, so cards with such a
+    /// cost can't be played in a match, failing with
+    /// [`ActionError::UnsupportedCost`](crate::ActionError::UnsupportedCost).
+    pub fn discard(&self) -> u8 {
+        self.discard
+    }
+
+    /// Whether the cost has more than one kind of dice
+    pub fn is_mixed(&self) -> bool {
+        [self.exact().is_some(), self.matching > 0, self.unaligned > 0].into_iter().filter(|kind| *kind).count() > 1
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Element::*;
+
+    #[test]
+    fn single_component_costs_keep_their_type() {
+        let mut cost = CardCost::new(DiceCost::Exact(Cryo), 1, 0);
+        cost.discount(1);
+        assert_eq!((cost.dice_type(), cost.amount(), cost.exact()), (DiceCost::Exact(Cryo), 0, None));
+
+        let mut cost = CardCost::new(DiceCost::Any, 1, 0);
+        cost.increase(1);
+        assert_eq!((cost.dice_type(), cost.amount()), (DiceCost::Any, 2));
+
+        assert_eq!(CardCost::ZERO.dice_type(), DiceCost::Same);
+        assert!(!CardCost::MATCH3.is_mixed());
+    }
+
+    #[test]
+    fn mixed_costs_discount_unaligned_dice_first() {
+        let mut cost = CardCost::ZERO.with_exact(Pyro, 1).with_unaligned(2).with_energy(1);
+        assert!(cost.is_mixed());
+        assert_eq!((cost.dice_type(), cost.amount(), cost.energy()), (DiceCost::Exact(Pyro), 3, 1));
+
+        cost.discount(2);
+        assert_eq!((cost.exact(), cost.unaligned()), (Some((Pyro, 1)), 0));
+    }
+}
@@ -0,0 +1,408 @@
+use std::{fmt, sync::{PoisonError, RwLock, RwLockReadGuard}};
+use crate::*;
+
+use super::Price;
+
+/// Every registered custom card. Entries are never removed, which lets them hand out
+/// `'static` references like built-in cards do
+static REGISTRY: RwLock<Vec<&'static Registered>> = RwLock::new(Vec::new());
+
+/// The registry for reading. Since it only ever grows, a panic while registering can't leave
+/// it half-updated, so a poisoned lock is still safe to read
+fn registry() -> RwLockReadGuard<'static, Vec<&'static Registered>> {
+    REGISTRY.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+struct Registered {
+    name: &'static str,
+    category: CustomCategory,
+    cost: CardCost,
+    code: &'static str,
+    requirements: Vec<DeckRequirement>,
+    effect: &'static dyn CardEffect,
+}
+
+/// A fan-made action card, for formats that play with more than the official cards
+///
+/// Custom cards are added to the game with [`register`](Self::register), after which they can
+/// be found with [`Card::from_name`], put in a [`Deck`] and played like any other card.
+///
+/// ## Example
+///
+/// ```
+/// use genius_invokation::*;
+///
+/// let blade = CustomCard::new("Homebrew Blade", CustomCategory::Weapon(WeaponType::Sword), CardCost::ZERO.with_matching(2))
+///     .with_requirement(DeckRequirement::Character(CharacterCard::Keqing))
+///     .register()
+///     .unwrap();
+///
+/// assert_eq!(Card::from_name("Homebrew Blade"), Some(Card::from(blade)));
+/// assert_eq!(blade.code(), "HomebrewBlade");
+/// ```
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "custom-cards")))]
+pub struct CustomCard {
+    name: String,
+    category: CustomCategory,
+    cost: CardCost,
+    code: Option<String>,
+    requirements: Vec<DeckRequirement>,
+    effect: Option<&'static dyn CardEffect>,
+}
+
+impl CustomCard {
+    pub fn new(name: impl Into<String>, category: CustomCategory, cost: CardCost) -> Self {
+        Self { name: name.into(), category, cost, code: None, requirements: Vec::new(), effect: None }
+    }
+
+    /// The code of this card in deck builder urls, which otherwise is its name without spaces
+    /// or punctuation
+    ///
+    /// Codes have to be at least three letters or digits long, so that they never clash with
+    /// the codes of built-in cards.
+    pub fn with_code(self, code: impl Into<String>) -> Self {
+        Self { code: Some(code.into()), ..self }
+    }
+
+    /// Adds a condition the deck has to meet to include this card
+    pub fn with_requirement(mut self, requirement: DeckRequirement) -> Self {
+        self.requirements.push(requirement);
+        self
+    }
+
+    /// What the card does when played, and afterwards if it stays on the field
+    ///
+    /// This replaces the default behaviour of its [category](CustomCategory) entirely, so an
+    /// equipment or support effect has to place the card itself, with [`GameState::equip`] or
+    /// [`GameState::add_support`].
+    pub fn with_effect(self, effect: &'static dyn CardEffect) -> Self {
+        Self { effect: Some(effect), ..self }
+    }
+
+    /// Adds the card to the game for the rest of the program, failing if its name or code is
+    /// already taken
+    pub fn register(self) -> Result<CustomCardId, CustomCardError> {
+        if self.name.is_empty() || self.name.contains([';', '\n', '\r']) {
+            return Err(CustomCardError::InvalidName(self.name))
+        }
+
+        let code = self.code.unwrap_or_else(|| self.name.chars().filter(char::is_ascii_alphanumeric).collect());
+        if code.len() < 3 || !code.chars().all(|char| char.is_ascii_alphanumeric()) {
+            return Err(CustomCardError::InvalidCode(code))
+        }
+
+        if Card::all().any(|card| card.name() == self.name) {
+            return Err(CustomCardError::NameTaken(self.name))
+        }
+
+        let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+
+        if registry.iter().any(|card| card.name == self.name) {
+            return Err(CustomCardError::NameTaken(self.name))
+        }
+        if registry.iter().any(|card| card.code == code) {
+            return Err(CustomCardError::CodeTaken(code))
+        }
+
+        let id = CustomCardId(registry.len() as u32);
+        registry.push(Box::leak(Box::new(Registered {
+            name: self.name.leak(),
+            category: self.category,
+            cost: self.cost,
+            code: code.leak(),
+            requirements: self.requirements,
+            effect: self.effect.unwrap_or_else(|| crate::game::custom_placement(id)),
+        })));
+
+        Ok(id)
+    }
+}
+
+/// A registered [`CustomCard`]
+///
+/// Ids are handed out in registration order, so they can differ between runs of a program.
+/// [Serde](https://serde.rs) writes custom cards by name instead.
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "custom-cards")))]
+pub struct CustomCardId(u32);
+
+impl CustomCardId {
+    /// Every registered custom card, in registration order
+    pub fn all() -> Vec<Self> {
+        (0..registry().len() as u32).map(Self).collect()
+    }
+
+    /// Looks up a registered custom card by name
+    pub fn from_name(name: &str) -> Option<Self> {
+        registry().iter().position(|card| card.name == name).map(|index| Self(index as u32))
+    }
+
+    /// Looks up a registered custom card by its code in deck builder urls
+    pub fn from_code(code: &str) -> Option<Self> {
+        registry().iter().position(|card| card.code == code).map(|index| Self(index as u32))
+    }
+
+    pub fn category(&self) -> CustomCategory {
+        self.registered().category
+    }
+
+    /// Conditions the deck has to meet to include this card
+    pub fn requirements(&self) -> &'static [DeckRequirement] {
+        &self.registered().requirements
+    }
+
+    /// The code of this card in deck builder urls
+    pub fn code(&self) -> &'static str {
+        self.registered().code
+    }
+
+    pub(crate) fn effect(&self) -> &'static dyn CardEffect {
+        self.registered().effect
+    }
+
+    fn registered(&self) -> &'static Registered {
+        registry()[self.0 as usize]
+    }
+}
+
+impl super::PlayingCard for CustomCardId {
+    fn name(&self) -> &'static str {
+        self.registered().name
+    }
+
+    fn shop_price(&self) -> Option<Price> {
+        None
+    }
+
+    fn cost(&self) -> CardCost {
+        self.registered().cost
+    }
+
+    fn version(&self) -> Option<GameVersion> {
+        None
+    }
+
+    fn custom(&self) -> Option<CustomCardId> {
+        Some(*self)
+    }
+}
+
+impl super::CardOrd for CustomCardId {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CustomCardId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CustomCardId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+
+        Self::from_name(&name).ok_or_else(|| serde::de::Error::custom(format!("unknown custom card `{name}`")))
+    }
+}
+
+impl_from!(Custom: CustomCardId => crate::ActionCard => crate::Card);
+
+/// What kind of action card a [`CustomCard`] is, which decides how it's played when it has
+/// no effect of its own
+///
+/// Built-in cards that look for a kind of card, such as discounts on weapons or food, only
+/// know about built-in cards.
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "custom-cards")))]
+pub enum CustomCategory {
+    /// Equipped to a character wielding this type of weapon, replacing its weapon
+    Weapon(WeaponType),
+    /// Equipped to any character, replacing its artifact
+    Artifact,
+    Location,
+    Companion,
+    Item,
+    /// Does nothing unless given an effect
+    Event,
+    /// Satiates a character that isn't satiated yet
+    Food,
+}
+
+/// A condition a deck has to meet to include a [`CustomCard`]
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "custom-cards")))]
+pub enum DeckRequirement {
+    /// The deck has to include this character, like talent cards
+    Character(CharacterCard),
+    /// The deck has to include at least this many characters of an element, like Elemental
+    /// Resonance cards
+    Element(Element, u8),
+    /// The deck has to include at least this many characters of a faction
+    Faction(Faction, u8),
+    /// The deck can only hold one copy of the card, like Arcane Legend cards
+    SingleCopy,
+}
+
+impl DeckRequirement {
+    /// Whether a deck with these characters and `copies` of the card meets the requirement
+    pub(crate) fn is_met(&self, characters: &[CharacterCard], copies: usize) -> bool {
+        match *self {
+            Self::Character(character) => characters.contains(&character),
+            Self::Element(element, amount) => characters.iter().filter(|card| card.element() == element).count() >= amount as usize,
+            Self::Faction(faction, amount) => characters.iter().filter(|card| card.faction() == faction).count() >= amount as usize,
+            Self::SingleCopy => copies <= 1,
+        }
+    }
+}
+
+impl fmt::Display for DeckRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Character(card) => write!(f, "`{}` to be present in the deck", card.name()),
+            Self::Element(element, amount) => write!(f, "at least {amount} {element:?} characters in the deck"),
+            Self::Faction(faction, amount) => write!(f, "at least {amount} {faction:?} characters in the deck"),
+            Self::SingleCopy => write!(f, "the deck to hold a single copy"),
+        }
+    }
+}
+
+/// Why a [`CustomCard`] couldn't be registered
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "custom-cards")))]
+pub enum CustomCardError {
+    /// Names can't be empty, nor contain `;` or line breaks, which replays use as separators
+    InvalidName(String),
+    /// Another card, built-in or custom, already has this name
+    NameTaken(String),
+    /// Codes have to be at least three letters or digits long
+    InvalidCode(String),
+    /// Another custom card already has this code
+    CodeTaken(String),
+}
+
+impl fmt::Display for CustomCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(name) => write!(f, "`{name}` isn't a valid card name"),
+            Self::NameTaken(name) => write!(f, "there already is a card named `{name}`"),
+            Self::InvalidCode(code) => write!(f, "`{code}` isn't a valid card code, which needs at least three letters or digits"),
+            Self::CodeTaken(code) => write!(f, "there already is a custom card with the code `{code}`"),
+        }
+    }
+}
+
+impl std::error::Error for CustomCardError {}
+
+#[cfg(test)]
+mod tests {
+    // The registry is global and shared by tests running in parallel, so every test has to
+    // register cards under its own names and codes, and can't rely on ids or on how many
+    // custom cards exist
+    use crate::*;
+    use crate::game::testing::*;
+
+    /// Custom cards filled up to a full deck with normal events the deck builder has
+    fn cards(characters: [CharacterCard; 3], custom: &[CustomCardId]) -> Vec<Card> {
+        let events = NormalEventCard::ALL.into_iter()
+            .filter(|card| card.version() == Some(GameVersion::LAUNCH))
+            .cycle().take(30 - custom.len()).map(Card::from);
+
+        characters.into_iter().map(Card::from).chain(custom.iter().map(|card| Card::from(*card))).chain(events).collect()
+    }
+
+    #[test]
+    fn names_and_codes_are_unique() {
+        let lantern = CustomCard::new("Test Lantern", CustomCategory::Item, CardCost::ZERO).register().unwrap();
+
+        assert_eq!(Card::from_name("Test Lantern"), Some(lantern.into()));
+        assert_eq!(CustomCardId::from_code("TestLantern"), Some(lantern));
+
+        let register = |name: &str, code: &str| CustomCard::new(name, CustomCategory::Event, CardCost::ZERO).with_code(code).register();
+        assert_eq!(register("Test Lantern", "TestLantern2"), Err(CustomCardError::NameTaken("Test Lantern".into())));
+        assert_eq!(register("Keqing", "TestKeqing"), Err(CustomCardError::NameTaken("Keqing".into())));
+        assert_eq!(register("Test Lamp", "TestLantern"), Err(CustomCardError::CodeTaken("TestLantern".into())));
+        assert_eq!(register("Test Lamp", "MZ"), Err(CustomCardError::InvalidCode("MZ".into())));
+        assert_eq!(register("Test; Lamp", "TestLamp"), Err(CustomCardError::InvalidName("Test; Lamp".into())));
+    }
+
+    #[test]
+    fn lookups_survive_a_poisoned_registry() {
+        let bell = CustomCard::new("Test Bell", CustomCategory::Item, CardCost::ZERO).register().unwrap();
+
+        let _ = std::thread::spawn(|| {
+            let _registry = super::REGISTRY.write().unwrap_or_else(std::sync::PoisonError::into_inner);
+            panic!("poisoning the registry");
+        }).join();
+
+        assert_eq!(bell.name(), "Test Bell");
+        assert_eq!(Card::from_name("Test Bell"), Some(bell.into()));
+        assert!(CustomCard::new("Test Chime", CustomCategory::Item, CardCost::ZERO).register().is_ok());
+    }
+
+    #[test]
+    fn decks_check_requirements() {
+        let talisman = CustomCard::new("Test Talisman", CustomCategory::Artifact, CardCost::ZERO.with_matching(1))
+            .with_requirement(DeckRequirement::Character(CharacterCard::Keqing))
+            .with_requirement(DeckRequirement::SingleCopy)
+            .register()
+            .unwrap();
+
+        let errors = Deck::validate(cards([CharacterCard::Fischl, CharacterCard::Noelle, CharacterCard::Ganyu], &[talisman, talisman]));
+        assert_eq!(errors, vec![
+            DeckError::CustomRequirementNotMet(talisman, DeckRequirement::Character(CharacterCard::Keqing)),
+        ]);
+
+        let error = Deck::from_iter(cards([CharacterCard::Keqing, CharacterCard::Noelle, CharacterCard::Ganyu], &[talisman, talisman]));
+        assert_eq!(error, Err(DeckError::CustomRequirementNotMet(talisman, DeckRequirement::SingleCopy)));
+
+        let deck = Deck::from_iter(cards([CharacterCard::Keqing, CharacterCard::Noelle, CharacterCard::Ganyu], &[talisman])).unwrap();
+        assert!(deck.contains(talisman.into()));
+    }
+
+    #[test]
+    fn weapons_without_an_effect_are_equipped() {
+        let sword = CustomCard::new("Test Sword", CustomCategory::Weapon(WeaponType::Sword), CardCost::ZERO.with_matching(1))
+            .register()
+            .unwrap();
+
+        let mut game = duel(CharacterCard::Keqing);
+        game.turn = PlayerId::First;
+        game.player_mut(PlayerId::First).hand.push(sword.into());
+
+        assert_eq!(sword.effect().targets(&game, PlayerId::First), vec![Some(Target::Character(0))]);
+        game.apply(Action::PlayCard { card: sword.into(), target: Some(Target::Character(0)) }).unwrap();
+
+        let equipment = |game: &GameState| game.player(PlayerId::First).character(0).equipment().iter().map(Entity::kind).collect::<Vec<_>>();
+        assert_eq!(equipment(&game), vec![EntityKind::Card(sword.into())]);
+
+        game.equip(PlayerId::First, 0, WeaponCard::TravelersHandySword.into());
+        assert_eq!(equipment(&game), vec![EntityKind::Card(WeaponCard::TravelersHandySword.into())]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_writes_names() {
+        let cake = CustomCard::new("Test Cake", CustomCategory::Food, CardCost::ZERO).register().unwrap();
+        let json = serde_json::to_string(&ActionCard::from(cake)).unwrap();
+
+        assert_eq!(json, r#"{"Custom":"Test Cake"}"#);
+        assert_eq!(serde_json::from_str::<ActionCard>(&json).unwrap(), cake.into());
+        assert!(serde_json::from_str::<ActionCard>(r#"{"Custom":"Test Pie"}"#).is_err());
+    }
+
+    #[cfg(feature = "deck-url")]
+    #[test]
+    fn deck_urls_use_codes() {
+        let inn = CustomCard::new("Test Inn", CustomCategory::Location, CardCost::ZERO).with_code("TInn").register().unwrap();
+        let deck = Deck::from_iter(cards([CharacterCard::Keqing, CharacterCard::Noelle, CharacterCard::Ganyu], &[inn, inn])).unwrap();
+        let url = deck_to_url(&deck).unwrap();
+
+        assert!(url.contains(".TInn-2&ver=1"));
+        assert_eq!(Deck::from_iter(deck_from_url(&url).unwrap()).unwrap(), deck);
+    }
+}
@@ -7,10 +7,11 @@ mod weapon;
 pub use talent::TalentCard;
 mod talent;
 
-use crate::CardCost;
+use crate::{CardCost, GameVersion};
 use super::{Price, PlayingCard};
 
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EquipmentCard {
     Talent(TalentCard),
     Weapon(WeaponCard),
@@ -42,6 +43,14 @@ impl PlayingCard for EquipmentCard {
         }
     }
 
+    fn version(&self) -> Option<GameVersion> {
+        match self {
+            Self::Talent(card)   => card.version(),
+            Self::Weapon(card)   => card.version(),
+            Self::Artifact(card) => card.version(),
+        }
+    }
+
     fn equipment(&self) -> Option<EquipmentCard> {
         Some(*self)
     }
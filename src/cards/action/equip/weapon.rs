@@ -1,43 +1,6 @@
-use crate::{CardCost, WeaponType::{self, *}};
-use super::Price;
+use crate::WeaponType;
 
-#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
-#[non_exhaustive]
-pub enum WeaponCard {
-    MagicGuide,
-    SacrificialFragments,
-    SkywardAtlas,
-    RavenBow,
-    SacrificialBow,
-    SkywardHarp,
-    WhiteIronGreatsword,
-    SacrificialGreatsword,
-    WolfsGravestone,
-    WhiteTassel,
-    LithicSpear,
-    SkywardSpine,
-    TravelersHandySword,
-    SacrificialSword,
-    AquilaFavonia,
-}
-
-impl super::PlayingCard for WeaponCard {
-    fn name(&self) -> &'static str {
-        self.info_dump().0
-    }
-
-    fn shop_price(&self) -> Option<Price> {
-        Some(self.info_dump().1)
-    }
-
-    fn cost(&self) -> CardCost {
-        self.info_dump().2
-    }
-
-    fn weapon(&self) -> Option<WeaponCard> {
-        Some(*self)
-    }
-}
+include!(concat!(env!("OUT_DIR"), "/cards/weapons.rs"));
 
 impl WeaponCard {
     /// Retrieves the weapon type for this card
@@ -48,32 +11,4 @@ impl WeaponCard {
     pub fn subtype(&self) -> Option<WeaponType> {
         Some(self.info_dump().3)
     }
-
-    fn info_dump(&self) -> (&'static str, Price, CardCost, WeaponType) {
-        match self {
-            Self::MagicGuide            => ("Magic Guide",             500, CardCost::MATCH2, Catalyst),
-            Self::SacrificialFragments  => ("Sacrificial Fragments",   700, CardCost::MATCH3, Catalyst),
-            Self::SkywardAtlas          => ("Skyward Atlas",          1000, CardCost::MATCH3, Catalyst),
-            Self::RavenBow              => ("Raven Bow",               500, CardCost::MATCH2, Bow),
-            Self::SacrificialBow        => ("Sacrificial Bow",         700, CardCost::MATCH3, Bow),
-            Self::SkywardHarp           => ("Skyward Harp",           1000, CardCost::MATCH3, Bow),
-            Self::WhiteIronGreatsword   => ("White Iron Greatsword",   500, CardCost::MATCH2, Claymore),
-            Self::SacrificialGreatsword => ("Sacrificial Greatsword",  700, CardCost::MATCH3, Claymore),
-            Self::WolfsGravestone       => ("Wolf's Gravestone",      1000, CardCost::MATCH3, Claymore),
-            Self::WhiteTassel           => ("White Tassel",            500, CardCost::MATCH2, Polearm),
-            Self::LithicSpear           => ("Lithic Spear",            700, CardCost::MATCH3, Polearm),
-            Self::SkywardSpine          => ("Skyward Spine",          1000, CardCost::MATCH3, Polearm),
-            Self::TravelersHandySword   => ("Traveler's Handy Sword",  500, CardCost::MATCH2, Sword),
-            Self::SacrificialSword      => ("Sacrificial Sword",       700, CardCost::MATCH3, Sword),
-            Self::AquilaFavonia         => ("Aquila Favonia",         1000, CardCost::MATCH3, Sword),
-        }
-    }
 }
-
-impl super::CardOrd for WeaponCard {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        (*self as u32).cmp(&(*other as u32))
-    }
-}
-
-impl_from!(Weapon: WeaponCard => crate::EquipmentCard => crate::ActionCard => crate::Card);
\ No newline at end of file
@@ -0,0 +1 @@
+include!(concat!(env!("OUT_DIR"), "/cards/arcane_legends.rs"));
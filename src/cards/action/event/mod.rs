@@ -1,4 +1,4 @@
-use crate::CardCost;
+use crate::{CardCost, GameVersion};
 
 pub use normal::NormalEventCard;
 mod normal;
@@ -9,9 +9,13 @@ mod resonance;
 pub use food::FoodCard;
 mod food;
 
+pub use arcane::ArcaneLegendCard;
+mod arcane;
+
 use super::{Price, PlayingCard};
 
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventCard {
     /// Normal types of event cards, with no subtypes, might rename this variant and subtype later
     Normal(NormalEventCard),
@@ -19,6 +23,8 @@ pub enum EventCard {
     Resonance(ElementalResonanceCard),
     /// Food buff cards
     Food(FoodCard),
+    /// Arcane Legend cards, limited to one copy per deck
+    Arcane(ArcaneLegendCard),
 }
 
 impl PlayingCard for EventCard {
@@ -27,6 +33,7 @@ impl PlayingCard for EventCard {
             Self::Normal(card)    => card.name(),
             Self::Resonance(card) => card.name(),
             Self::Food(card)      => card.name(),
+            Self::Arcane(card)    => card.name(),
         }
     }
 
@@ -35,6 +42,7 @@ impl PlayingCard for EventCard {
             Self::Normal(card)    => card.shop_price(),
             Self::Resonance(card) => card.shop_price(),
             Self::Food(card)      => card.shop_price(),
+            Self::Arcane(card)    => card.shop_price(),
         }
     }
 
@@ -43,6 +51,16 @@ impl PlayingCard for EventCard {
             Self::Normal(card)    => card.cost(),
             Self::Resonance(card) => card.cost(),
             Self::Food(card)      => card.cost(),
+            Self::Arcane(card)    => card.cost(),
+        }
+    }
+
+    fn version(&self) -> Option<GameVersion> {
+        match self {
+            Self::Normal(card)    => card.version(),
+            Self::Resonance(card) => card.version(),
+            Self::Food(card)      => card.version(),
+            Self::Arcane(card)    => card.version(),
         }
     }
 
@@ -54,6 +72,7 @@ impl PlayingCard for EventCard {
         normal_event -> NormalEventCard { card: Self::Normal(card) },
         resonance    -> ElementalResonanceCard { card: Self::Resonance(card) },
         food         -> FoodCard { card: Self::Food(card) },
+        arcane       -> ArcaneLegendCard { card: Self::Arcane(card) },
     );
 }
 
@@ -61,11 +80,14 @@ use std::cmp::Ordering;
 use super::CardOrd;
 impl CardOrd for EventCard {
     fn cmp(&self, other: &Self) -> Ordering {
-        // Resonance < Normal < Food
+        // Arcane < Resonance < Normal < Food
         match (self, other) {
+            (Self::Arcane(x), Self::Arcane(y))       => x.cmp(y),
             (Self::Resonance(x), Self::Resonance(y)) => x.cmp(y),
             (Self::Normal(x), Self::Normal(y))       => x.cmp(y),
             (Self::Food(x), Self::Food(y))           => x.cmp(y),
+            (Self::Arcane(_), _)    => Ordering::Less,
+            (_, Self::Arcane(_))    => Ordering::Greater,
             (Self::Resonance(_), _) => Ordering::Less,
             (_, Self::Resonance(_)) => Ordering::Greater,
             (Self::Normal(_), _)    => Ordering::Less,
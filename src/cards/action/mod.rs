@@ -51,13 +51,25 @@ mod cost;
 pub use card_trait::PlayingCard;
 mod card_trait;
 
+#[cfg(feature = "custom-cards")]
+pub use custom::{CustomCard, CustomCardId, CustomCategory, DeckRequirement, CustomCardError};
+#[cfg(feature = "custom-cards")]
+mod custom;
+
+use super::GameVersion;
+
 type Price = u16;
 
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ActionCard {
     Equipment(EquipmentCard),
     Support(SupportCard),
     Event(EventCard),
+    /// A card registered at runtime, see [`CustomCard`]
+    #[cfg(feature = "custom-cards")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "custom-cards")))]
+    Custom(CustomCardId),
 }
 
 impl PlayingCard for ActionCard {
@@ -66,6 +78,8 @@ impl PlayingCard for ActionCard {
             Self::Equipment(card) => card.name(),
             Self::Support(card)   => card.name(),
             Self::Event(card)     => card.name(),
+            #[cfg(feature = "custom-cards")]
+            Self::Custom(card)    => card.name(),
         }
     }
 
@@ -74,6 +88,8 @@ impl PlayingCard for ActionCard {
             Self::Equipment(card) => card.shop_price(),
             Self::Support(card)   => card.shop_price(),
             Self::Event(card)     => card.shop_price(),
+            #[cfg(feature = "custom-cards")]
+            Self::Custom(card)    => card.shop_price(),
         }
     }
 
@@ -82,6 +98,18 @@ impl PlayingCard for ActionCard {
             Self::Equipment(card) => card.cost(),
             Self::Support(card)   => card.cost(),
             Self::Event(card)     => card.cost(),
+            #[cfg(feature = "custom-cards")]
+            Self::Custom(card)    => card.cost(),
+        }
+    }
+
+    fn version(&self) -> Option<GameVersion> {
+        match self {
+            Self::Equipment(card) => card.version(),
+            Self::Support(card)   => card.version(),
+            Self::Event(card)     => card.version(),
+            #[cfg(feature = "custom-cards")]
+            Self::Custom(card)    => card.version(),
         }
     }
 
@@ -98,14 +126,20 @@ impl PlayingCard for ActionCard {
         food         -> FoodCard { card: Self::Event(EventCard::Food(card)) },
         normal_event -> NormalEventCard { card: Self::Event(EventCard::Normal(card)) },
         resonance    -> ElementalResonanceCard { card: Self::Event(EventCard::Resonance(card)) },
+        arcane       -> ArcaneLegendCard { card: Self::Event(EventCard::Arcane(card)) },
     );
+
+    #[cfg(feature = "custom-cards")]
+    fn custom(&self) -> Option<CustomCardId> {
+        if let Self::Custom(card) = self { Some(*card) } else { None }
+    }
 }
 
 use std::cmp::Ordering;
 use super::CardOrd;
 impl CardOrd for ActionCard {
     fn cmp(&self, other: &Self) -> Ordering {
-        // Equipment < Support < Event
+        // Equipment < Support < Event < Custom
         match (self, other) {
             (Self::Equipment(x), Self::Equipment(y)) => x.cmp(y),
             (Self::Support(x), Self::Support(y))     => x.cmp(y),
@@ -114,6 +148,12 @@ impl CardOrd for ActionCard {
             (_, Self::Equipment(_)) => Ordering::Greater,
             (Self::Support(_), _)   => Ordering::Less,
             (_, Self::Support(_))   => Ordering::Greater,
+            #[cfg(feature = "custom-cards")]
+            (Self::Custom(x), Self::Custom(y))       => x.cmp(y),
+            #[cfg(feature = "custom-cards")]
+            (Self::Custom(_), _)    => Ordering::Greater,
+            #[cfg(feature = "custom-cards")]
+            (_, Self::Custom(_))    => Ordering::Less,
         }
     }
 }
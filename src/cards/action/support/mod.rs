@@ -1,4 +1,4 @@
-use crate::CardCost;
+use crate::{CardCost, GameVersion};
 
 pub use companion::CompanionCard;
 mod companion;
@@ -12,6 +12,7 @@ mod item;
 use super::{Price, PlayingCard};
 
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SupportCard {
     Companion(CompanionCard),
     Location(LocationCard),
@@ -43,6 +44,14 @@ impl PlayingCard for SupportCard {
         }
     }
 
+    fn version(&self) -> Option<GameVersion> {
+        match self {
+            Self::Companion(card) => card.version(),
+            Self::Location(card)  => card.version(),
+            Self::Item(card)      => card.version(),
+        }
+    }
+
     fn support(&self) -> Option<SupportCard> {
         Some(*self)
     }
@@ -0,0 +1,217 @@
+use crate::*;
+use crate::export::{csv_field, json_string};
+
+/// The columns of [`catalog_csv`], which are also the keys of every object in
+/// [`catalog_json`], in order
+///
+/// Columns are only ever added at the end, so that existing consumers keep working.
+pub const CATALOG_COLUMNS: [&str; 18] = [
+    "kind", "path", "name",
+    "dice_type", "dice_amount", "energy", "shop_price",
+    "talent_character", "resonance_element", "weapon_subtype",
+    "element", "weapon", "faction", "health",
+    "exact_dice", "matching_dice", "unaligned_dice", "discard",
+];
+
+/// Everything known about a card, as exported by [`catalog_json`] and [`catalog_csv`]
+///
+/// Fields that don't apply to the card, such as the cost of a character card, are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogEntry {
+    pub card: Card,
+    /// The card written as a Rust expression, like
+    /// `ActionCard::Equipment(EquipmentCard::Weapon(WeaponCard::RavenBow))`
+    pub path: String,
+    pub name: &'static str,
+    pub cost: Option<CardCost>,
+    pub shop_price: Option<u16>,
+    /// The character a talent card belongs to
+    pub talent_character: Option<CharacterCard>,
+    /// The element of an Elemental Resonance card
+    pub resonance_element: Option<Element>,
+    /// The type of a weapon card
+    pub weapon_subtype: Option<WeaponType>,
+    /// The element of a character card
+    pub element: Option<Element>,
+    /// The weapon of a character card, also `None` for characters listed as "Other Weapons"
+    pub weapon: Option<WeaponType>,
+    pub faction: Option<Faction>,
+    pub health: Option<u8>,
+}
+
+impl CatalogEntry {
+    pub fn new(card: Card) -> Self {
+        let mut entry = Self {
+            card,
+            path: path(card),
+            name: card.name(),
+            cost: None,
+            shop_price: None,
+            talent_character: None,
+            resonance_element: None,
+            weapon_subtype: None,
+            element: None,
+            weapon: None,
+            faction: None,
+            health: None,
+        };
+
+        match card {
+            Card::Character(card) => {
+                entry.element = Some(card.element());
+                entry.weapon = card.weapon();
+                entry.faction = Some(card.faction());
+                entry.health = Some(card.health());
+            },
+            Card::Action(card) => {
+                entry.cost = Some(card.cost());
+                entry.shop_price = card.shop_price();
+                entry.talent_character = card.talent().map(|talent| talent.character());
+                entry.resonance_element = card.resonance().map(|resonance| resonance.element());
+                entry.weapon_subtype = card.weapon().and_then(|weapon| weapon.subtype());
+            },
+        }
+
+        entry
+    }
+
+    /// The value of every column in [`CATALOG_COLUMNS`], `None` where empty
+    fn values(&self) -> [Option<Value>; 18] {
+        let text = |text: &str| Some(Value::Text(text.to_string()));
+        let debug = |value: &dyn std::fmt::Debug| Some(Value::Text(format!("{value:?}")));
+        let number = |number: u16| Some(Value::Number(number));
+
+        [
+            text(match self.card { Card::Character(_) => "character", Card::Action(_) => "action" }),
+            text(&self.path),
+            text(self.name),
+            self.cost.and_then(|cost| match cost.dice_type() {
+                DiceCost::Any => text("Any"),
+                DiceCost::Same => text("Same"),
+                DiceCost::Exact(element) => debug(&element),
+            }),
+            self.cost.and_then(|cost| number(cost.amount() as u16)),
+            self.cost.and_then(|cost| number(cost.energy() as u16)),
+            self.shop_price.and_then(number),
+            self.talent_character.and_then(|card| text(card.name())),
+            self.resonance_element.and_then(|element| debug(&element)),
+            self.weapon_subtype.and_then(|weapon| debug(&weapon)),
+            self.element.and_then(|element| debug(&element)),
+            self.weapon.and_then(|weapon| debug(&weapon)),
+            self.faction.and_then(|faction| debug(&faction)),
+            self.health.and_then(|health| number(health as u16)),
+            self.cost.and_then(|cost| number(cost.exact().map_or(0, |(_, dice)| dice) as u16)),
+            self.cost.and_then(|cost| number(cost.matching() as u16)),
+            self.cost.and_then(|cost| number(cost.unaligned() as u16)),
+            self.cost.and_then(|cost| number(cost.discard() as u16)),
+        ]
+    }
+}
+
+enum Value {
+    Text(String),
+    Number(u16),
+}
+
+/// Every card in the game, in the order of [`Card::all`]
+pub fn catalog() -> Vec<CatalogEntry> {
+    Card::all().map(CatalogEntry::new).collect()
+}
+
+/// The [`catalog`] as a JSON array with one object per card, with the keys of
+/// [`CATALOG_COLUMNS`]. Missing values are `null`
+pub fn catalog_json() -> String {
+    let cards: Vec<String> = catalog().iter().map(|entry| {
+        let fields: Vec<String> = CATALOG_COLUMNS.iter().zip(entry.values()).map(|(column, value)| {
+            let value = match value {
+                Some(Value::Text(text)) => json_string(&text),
+                Some(Value::Number(number)) => number.to_string(),
+                None => "null".to_string(),
+            };
+
+            format!("{}: {value}", json_string(column))
+        }).collect();
+
+        format!("  {{{}}}", fields.join(", "))
+    }).collect();
+
+    format!("[\n{}\n]\n", cards.join(",\n"))
+}
+
+/// The [`catalog`] as CSV with a header row of [`CATALOG_COLUMNS`]. Missing values are empty
+pub fn catalog_csv() -> String {
+    let mut csv = CATALOG_COLUMNS.join(",");
+    csv.push('\n');
+
+    for entry in catalog() {
+        let fields: Vec<String> = entry.values().into_iter().map(|value| match value {
+            Some(Value::Text(text)) => csv_field(&text),
+            Some(Value::Number(number)) => number.to_string(),
+            None => String::new(),
+        }).collect();
+
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+fn path(card: Card) -> String {
+    let card = match card {
+        Card::Character(card) => return format!("CharacterCard::{card:?}"),
+        Card::Action(card) => card,
+    };
+
+    let (parent, child, leaf, name) = match card {
+        ActionCard::Equipment(EquipmentCard::Talent(card))   => ("Equipment", "EquipmentCard::Talent", "TalentCard", format!("{card:?}")),
+        ActionCard::Equipment(EquipmentCard::Weapon(card))   => ("Equipment", "EquipmentCard::Weapon", "WeaponCard", format!("{card:?}")),
+        ActionCard::Equipment(EquipmentCard::Artifact(card)) => ("Equipment", "EquipmentCard::Artifact", "ArtifactCard", format!("{card:?}")),
+        ActionCard::Support(SupportCard::Location(card))     => ("Support", "SupportCard::Location", "LocationCard", format!("{card:?}")),
+        ActionCard::Support(SupportCard::Companion(card))    => ("Support", "SupportCard::Companion", "CompanionCard", format!("{card:?}")),
+        ActionCard::Support(SupportCard::Item(card))         => ("Support", "SupportCard::Item", "ItemCard", format!("{card:?}")),
+        ActionCard::Event(EventCard::Resonance(card))        => ("Event", "EventCard::Resonance", "ElementalResonanceCard", format!("{card:?}")),
+        ActionCard::Event(EventCard::Normal(card))           => ("Event", "EventCard::Normal", "NormalEventCard", format!("{card:?}")),
+        ActionCard::Event(EventCard::Food(card))             => ("Event", "EventCard::Food", "FoodCard", format!("{card:?}")),
+        ActionCard::Event(EventCard::Arcane(card))           => ("Event", "EventCard::Arcane", "ArcaneLegendCard", format!("{card:?}")),
+        #[cfg(feature = "custom-cards")]
+        ActionCard::Custom(card)                             => return format!("ActionCard::Custom({:?})", card.name()),
+    };
+
+    format!("ActionCard::{parent}({child}({leaf}::{name}))")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exports_every_card() {
+        let csv = catalog_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], CATALOG_COLUMNS.join(","));
+        assert_eq!(lines.len(), Card::all().count() + 1);
+        assert!(lines.iter().filter(|line| !line.contains('"')).all(|line| line.split(',').count() == CATALOG_COLUMNS.len()));
+
+        assert!(lines.contains(&"character,CharacterCard::Keqing,Keqing,,,,,,,,Electro,Sword,Liyue,10,,,,"));
+        assert!(lines.contains(&"action,ActionCard::Equipment(EquipmentCard::Weapon(WeaponCard::RavenBow)),Raven Bow,Same,2,0,500,,,Bow,,,,,0,2,0,0"));
+        assert!(lines.contains(&"action,ActionCard::Equipment(EquipmentCard::Talent(TalentCard::ThunderingPenance)),Thundering Penance,Electro,3,0,,Keqing,,,,,,,3,0,0,0"));
+    }
+
+    #[test]
+    fn json_matches_csv() {
+        let json = catalog_json();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let cards = value.as_array().unwrap();
+
+        assert_eq!(cards.len(), Card::all().count());
+        assert!(cards.iter().all(|card| card.as_object().unwrap().len() == CATALOG_COLUMNS.len()));
+
+        let resonance = cards.iter().find(|card| card["name"] == "Enduring Rock").unwrap();
+        assert_eq!(resonance["path"], "ActionCard::Event(EventCard::Resonance(ElementalResonanceCard::EnduringRock))");
+        assert_eq!(resonance["resonance_element"], "Geo");
+        assert_eq!(resonance["shop_price"], 500);
+        assert_eq!(resonance["weapon"], serde_json::Value::Null);
+    }
+}
@@ -5,6 +5,11 @@ pub enum Faction {
     Liyue,
     Inazuma,
     Sumeru,
+    Fontaine,
+    Natlan,
+    Snezhnaya,
     Fatui,
+    Eremite,
+    Hilichurl,
     Monster
-}
\ No newline at end of file
+}
@@ -1,4 +1,4 @@
-use crate::{Card, Element::{self, *}};
+use crate::{Card, Element, GameVersion};
 
 pub use weapon::WeaponType;
 mod weapon;
@@ -6,40 +6,7 @@ mod weapon;
 pub use faction::Faction;
 mod faction;
 
-#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
-#[non_exhaustive]
-pub enum CharacterCard {
-    KamisatoAyaka,
-    Chongyun,
-    Diona,
-    Ganyu,
-    Kaeya,
-    Barbara,
-    Mona,
-    Xingqiu,
-    Cyno,
-    Fischl,
-    Keqing,
-    Razor,
-    Ningguang,
-    Noelle,
-    Collei,
-    Jean,
-    Sucrose,
-    Bennett,
-    Diluc,
-    Xiangling,
-    Yoimiya,
-    MirrorMaiden,
-    RhodeiaOfLoch,
-    StonehideLawachurl,
-    JadeplumeTerrorshroom,
-    MaguuKenki,
-    FatuiPyroAgent,
-}
-
-use WeaponType::*;
-use Faction::*;
+include!(concat!(env!("OUT_DIR"), "/cards/characters.rs"));
 
 impl CharacterCard {
     pub fn name(&self) -> &'static str {
@@ -61,37 +28,21 @@ impl CharacterCard {
         self.info_dump().3  
     }
 
-    fn info_dump(&self) -> (&'static str, Element, Option<WeaponType>, Faction) {
-        match self {
-            Self::KamisatoAyaka => ("Kamisato Ayaka", Cryo,    Some(Sword),    Inazuma),
-            Self::Chongyun =>      ("Chongyun",       Cryo,    Some(Claymore), Liyue),
-            Self::Diona =>         ("Diona",          Cryo,    Some(Bow),      Mondstadt),
-            Self::Ganyu =>         ("Ganyu",          Cryo,    Some(Bow),      Liyue),
-            Self::Kaeya =>         ("Kaeya",          Cryo,    Some(Sword),    Mondstadt),
-            Self::Barbara =>       ("Barbara",        Hydro,   Some(Catalyst), Mondstadt),
-            Self::Mona =>          ("Mona",           Hydro,   Some(Catalyst), Mondstadt),
-            Self::Xingqiu =>       ("Xingqiu",        Hydro,   Some(Sword),    Liyue),
-            Self::Cyno =>          ("Cyno",           Electro, Some(Polearm),  Sumeru),
-            Self::Fischl =>        ("Fischl",         Electro, Some(Bow),      Mondstadt),
-            Self::Keqing =>        ("Keqing",         Electro, Some(Sword),    Liyue),
-            Self::Razor =>         ("Razor",          Electro, Some(Claymore), Mondstadt),
-            Self::Ningguang =>     ("Ningguang",      Geo,     Some(Catalyst), Liyue),
-            Self::Noelle =>        ("Noelle",         Geo,     Some(Claymore), Mondstadt),
-            Self::Collei =>        ("Collei",         Dendro,  Some(Bow),      Sumeru),
-            Self::Jean =>          ("Jean",           Anemo,   Some(Sword),    Mondstadt),
-            Self::Sucrose =>       ("Sucrose",        Anemo,   Some(Catalyst), Mondstadt),
-            Self::Bennett =>       ("Bennett",        Pyro,    Some(Sword),    Mondstadt),
-            Self::Diluc =>         ("Diluc",          Pyro,    Some(Claymore), Mondstadt),
-            Self::Xiangling =>     ("Xiangling",      Pyro,    Some(Polearm),  Liyue),
-            Self::Yoimiya =>       ("Yoimiya",        Pyro,    Some(Bow),      Inazuma),
-            Self::MirrorMaiden =>          ("Mirror Maiden",          Hydro,  None, Fatui),
-            Self::RhodeiaOfLoch =>         ("Rhodeia of Loch",        Hydro,  None, Monster),
-            Self::StonehideLawachurl =>    ("Stonehide Lawachurl",    Geo,    None, Monster),
-            Self::JadeplumeTerrorshroom => ("Jadeplume Terrorshroom", Dendro, None, Monster),
-            Self::MaguuKenki =>            ("Maguu Kenki",            Anemo,  None, Monster),
-            Self::FatuiPyroAgent =>        ("Fatui Pyro Agent",       Pyro,   None, Fatui),
-        }
+    /// The maximum (and starting) HP of this character
+    pub fn health(&self) -> u8 {
+        self.info_dump().4
     }
+
+    /// How much energy this character needs to use its elemental burst
+    pub fn max_energy(&self) -> u8 {
+        self.info_dump().5
+    }
+
+    /// The game version that added this character
+    pub fn version(&self) -> GameVersion {
+        self.info_dump().6
+    }
+
 }
 
 impl From<CharacterCard> for Card {
@@ -99,9 +50,3 @@ impl From<CharacterCard> for Card {
         Card::Character(card)
     }
 }
-
-impl super::CardOrd for CharacterCard {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        (*self as u32).cmp(&(*other as u32))
-    }
-}
\ No newline at end of file
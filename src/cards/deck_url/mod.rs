@@ -1,13 +1,17 @@
 use std::{fmt, iter};
 use crate::*;
 
-const URL_STARTER: &'static str = "genshin.hotgames.gg/tcg/deck-builder?deck=";
+const URL_STARTER: &str = "genshin.hotgames.gg/tcg/deck-builder?deck=";
 
 #[derive(Debug)]
 #[cfg_attr(docsrs, doc(cfg(feature = "deck-url")))]
 pub enum UrlDeckError<'s> {
+    /// The string isn't a deck builder url
     InvalidUrl(&'s str),
+    /// The url uses a deck builder version other than 1
     UnknownVersion(&'s str),
+    /// A card code that isn't in this crate, which includes the post-launch characters and talents
+    /// until the deck builder's codes for them are added
     UnknownCard(&'s str),
 }
 
@@ -28,12 +32,12 @@ pub enum UrlDeckError<'s> {
 /// [deck builder url]: https://genshin.hotgames.gg/tcg/deck-builder
 #[cfg_attr(docsrs, doc(cfg(feature = "deck-url")))]
 pub fn deck_from_url<'s>(url: &'s str) -> Result<impl Iterator<Item=Card> + 's, UrlDeckError<'s>> {
-    let index = url.find(URL_STARTER).ok_or_else(|| UrlDeckError::InvalidUrl(url))?;
+    let index = url.find(URL_STARTER).ok_or(UrlDeckError::InvalidUrl(url))?;
     let start = index + URL_STARTER.len();
 
     let mut split = url[start..].split('&');
-    let deck  = split.next().ok_or_else(|| UrlDeckError::InvalidUrl(url))?;
-    let version = get_version(split.next()).ok_or_else(|| UrlDeckError::InvalidUrl(url))?;
+    let deck  = split.next().ok_or(UrlDeckError::InvalidUrl(url))?;
+    let version = get_version(split.next()).ok_or(UrlDeckError::InvalidUrl(url))?;
 
     if version == "1" {
         decode(deck)
@@ -42,10 +46,51 @@ pub fn deck_from_url<'s>(url: &'s str) -> Result<impl Iterator<Item=Card> + 's,
     }
 }
 
+/// Creates a [deck builder url] for a deck, which [`deck_from_url`] reads back
+/// 
+/// Fails with the first card that has no deck builder code in this crate. For now, those are the
+/// cards released after the launch, Arcane Legends included.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use genius_invokation::{Deck, deck_from_url, deck_to_url};
+/// 
+/// let url = "https://genshin.hotgames.gg/tcg/deck-builder?deck=1.6.MC.MD.MF.MG.MH.MI.MJ.MK.ML.MM.MN.MO.MP.MV.MY.e.g8.gB.gD.gF.gb.ge.gh.gk.gt.gv.gx.gz.wj.wl.wm&ver=1&lang=en&author=DefaultDeck";
+/// let deck = Deck::from_iter(deck_from_url(url).unwrap()).unwrap();
+/// let encoded = deck_to_url(&deck).unwrap();
+/// 
+/// assert_eq!(Deck::from_iter(deck_from_url(&encoded).unwrap()).unwrap(), deck);
+/// ```
+/// 
+/// [deck builder url]: https://genshin.hotgames.gg/tcg/deck-builder
+#[cfg_attr(docsrs, doc(cfg(feature = "deck-url")))]
+pub fn deck_to_url(deck: &Deck) -> Result<String, Card> {
+    let characters = deck.character_slice().iter().map(|card| {
+        encode_character_card(*card).map(str::to_string).ok_or(Card::from(*card))
+    });
+    let actions = deck.iter_unique().map(|card| {
+        let code = encode_action_card(card).ok_or(Card::from(card))?;
+
+        Ok(match deck.iter().filter(|other| *other == card).count() {
+            1 => code.to_string(),
+            amount => format!("{code}-{amount}"),
+        })
+    });
+    let cards: Vec<String> = characters.chain(actions).collect::<Result<_, _>>()?;
+
+    Ok(format!("https://{URL_STARTER}{}&ver=1", cards.join(".")))
+}
+
+// Generated from the `code` of every card in cards.json. Cards released after the launch have no
+// code until the deck builder's own are added (see the README), and custom cards use codes of
+// three or more characters
+include!(concat!(env!("OUT_DIR"), "/cards/deck_url.rs"));
+
 fn decode<'s>(deck: &'s str) -> Result<impl Iterator<Item=Card> + 's, UrlDeckError<'s>> {
     verify_iterator(deck.split('.').map(|card| decode_card(card)))?;
 
-    Ok(deck.split('.').map(|card| decode_card(card).unwrap()).flatten())
+    Ok(deck.split('.').flat_map(|card| decode_card(card).unwrap()))
 }
 
 fn decode_card<'s>(card: &'s str) -> Result<impl Iterator<Item=Card> + fmt::Debug + 's, &'s str> {
@@ -55,14 +100,17 @@ fn decode_card<'s>(card: &'s str) -> Result<impl Iterator<Item=Card> + fmt::Debu
     let card = match card.len() {
         1 => decode_character_card(card),
         2 => decode_action_card(card),
+        #[cfg(feature = "custom-cards")]
+        _ => CustomCardId::from_code(card).map(Card::from).ok_or(card),
+        #[cfg(not(feature = "custom-cards"))]
         _ => Err(card)
     }?;
 
-    Ok(iter::repeat(card).take(amount))
+    Ok(iter::repeat_n(card, amount))
 }
 
-fn get_version<'s>(version: Option<&'s str>) -> Option<&'s str> {
-    version.map(|str| str.strip_prefix("ver=")).flatten()
+fn get_version(version: Option<&str>) -> Option<&str> {
+    version.and_then(|str| str.strip_prefix("ver="))
 }
 
 fn verify_iterator<'s, I>(
@@ -77,190 +125,89 @@ fn verify_iterator<'s, I>(
     }
 }
 
-fn decode_character_card<'s>(card: &'s str) -> Result<Card, &'s str> {
-    match card {
-        "0" => Ok(Card::Character(CharacterCard::Ganyu)),
-        "1" => Ok(Card::Character(CharacterCard::Kaeya)),
-        "2" => Ok(Card::Character(CharacterCard::Chongyun)),
-        "3" => Ok(Card::Character(CharacterCard::KamisatoAyaka)),
-        "4" => Ok(Card::Character(CharacterCard::Xingqiu)),
-        "5" => Ok(Card::Character(CharacterCard::Mona)),
-        "6" => Ok(Card::Character(CharacterCard::Diluc)),
-        "7" => Ok(Card::Character(CharacterCard::Xiangling)),
-        "8" => Ok(Card::Character(CharacterCard::Bennett)),
-        "a" => Ok(Card::Character(CharacterCard::Yoimiya)),
-        "b" => Ok(Card::Character(CharacterCard::Fischl)),
-        "c" => Ok(Card::Character(CharacterCard::Razor)),
-        "d" => Ok(Card::Character(CharacterCard::Keqing)),
-        "e" => Ok(Card::Character(CharacterCard::Sucrose)),
-        "f" => Ok(Card::Character(CharacterCard::Jean)),
-        "g" => Ok(Card::Character(CharacterCard::Ningguang)),
-        "h" => Ok(Card::Character(CharacterCard::Noelle)),
-        "i" => Ok(Card::Character(CharacterCard::Collei)),
-        "j" => Ok(Card::Character(CharacterCard::RhodeiaOfLoch)),
-        "k" => Ok(Card::Character(CharacterCard::FatuiPyroAgent)),
-        "l" => Ok(Card::Character(CharacterCard::MaguuKenki)),
-        "m" => Ok(Card::Character(CharacterCard::StonehideLawachurl)),
-        "n" => Ok(Card::Character(CharacterCard::Diona)),
-        "o" => Ok(Card::Character(CharacterCard::Cyno)),
-        "p" => Ok(Card::Character(CharacterCard::Barbara)),
-        "q" => Ok(Card::Character(CharacterCard::MirrorMaiden)),
-        "r" => Ok(Card::Character(CharacterCard::JadeplumeTerrorshroom)),
-        _ => Err(card)
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn default_deck() {
+        let url = "https://genshin.hotgames.gg/tcg/deck-builder?deck=1.6.MC.MD.MF.MG.MH.MI.MJ.MK.ML.MM.MN.MO.MP.MV.MY.e.g8.gB.gD.gF.gb.ge.gh.gk.gt.gv.gx.gz.wj.wl.wm&ver=1&lang=en&author=DefaultDeck";
+
+        let cards: Vec<_> = super::deck_from_url(url).unwrap().map(|card| card.name()).collect();
+
+        // the deck builder's default deck, in the url's order
+        assert_eq!(cards, [
+            "Kaeya", "Diluc", "The Bestest Travel Companion!", "Changing Shifts", "Strategize",
+            "I haven't Lost Yet!", "Leave It To Me!", "When The Crane Returned", "Starsigns",
+            "Calx's Arts", "Master of Weaponry", "Blessing of the Divine Relic's Installation",
+            "Quick Knit", "Send Off", "Guardian's Oath", "Sweet Madame", "Minty Meat Rolls",
+            "Sucrose", "Magic Guide", "Viridescent Venerer's Diadem", "Mask of Solitude Basalt",
+            "Laurel Coronet", "Raven Bow", "White Iron Greatsword", "White Tassel",
+            "Traveler's Handy Sword", "Broken Rime's Echo", "Wine-Stained Tricorne",
+            "Witch's Scorching Hat", "Thunder Summoner's Crown", "Dawn Winery", "Favonius Cathedral",
+            "Paimon",
+        ]);
     }
-}
 
-fn decode_action_card<'s>(card: &'s str) -> Result<Card, &'s str> {
-    if let Some(decoded) = decode_equip_card(card) { return Ok(Card::Action(decoded)) }
-    if let Some(decoded) = decode_event_card(card) { return Ok(Card::Action(decoded)) }
-    if let Some(decoded) = decode_support_card(card) { return Ok(Card::Action(decoded)) }
+    #[test]
+    fn later_cards_have_no_code() {
+        use crate::*;
 
-    Err(card)
-}
+        let deck = Deck::from_iter([CharacterCard::Shenhe, CharacterCard::Ganyu, CharacterCard::Keqing].map(Card::from).into_iter()
+            .chain([TalentCard::MysticalAbandon.into()])
+            .chain(NormalEventCard::ALL.into_iter().cycle().take(29).map(Card::from))
+        ).unwrap();
 
-fn decode_equip_card(card: &str) -> Option<ActionCard> {
-    match card {
-        "84" => Some(EquipmentCard::Talent(TalentCard::UndividedHeart)),
-        "85" => Some(EquipmentCard::Talent(TalentCard::ColdBloodedStrike)),
-        "86" => Some(EquipmentCard::Talent(TalentCard::SteadyBreathing)),
-        "87" => Some(EquipmentCard::Talent(TalentCard::KantenSenmyouBlessing)),
-        "88" => Some(EquipmentCard::Talent(TalentCard::TheScentRemained)),
-        "89" => Some(EquipmentCard::Talent(TalentCard::ProphecyOfSubmersion)),
-        "8a" => Some(EquipmentCard::Talent(TalentCard::FlowingFlame)),
-        "8b" => Some(EquipmentCard::Talent(TalentCard::Crossfire)),
-        "8c" => Some(EquipmentCard::Talent(TalentCard::GrandExpectation)),
-        "8e" => Some(EquipmentCard::Talent(TalentCard::NaganoharaMeteorSwarm)),
-        "8f" => Some(EquipmentCard::Talent(TalentCard::StellarPredator)),
-        "8g" => Some(EquipmentCard::Talent(TalentCard::Awakening)),
-        "8h" => Some(EquipmentCard::Talent(TalentCard::ThunderingPenance)),
-        "8i" => Some(EquipmentCard::Talent(TalentCard::ChaoticEntropy)),
-        "8j" => Some(EquipmentCard::Talent(TalentCard::LandsOfDandelion)),
-        "8k" => Some(EquipmentCard::Talent(TalentCard::StrategicReserve)),
-        "8l" => Some(EquipmentCard::Talent(TalentCard::IGotYourBack)),
-        "8m" => Some(EquipmentCard::Talent(TalentCard::FloralSidewinder)),
-        "8n" => Some(EquipmentCard::Talent(TalentCard::StreamingSurge)),
-        "8o" => Some(EquipmentCard::Talent(TalentCard::PaidInFull)),
-        "8p" => Some(EquipmentCard::Talent(TalentCard::TranscendentAutomaton)),
-        "8q" => Some(EquipmentCard::Talent(TalentCard::StonehideReforged)),
-        "8r" => Some(EquipmentCard::Talent(TalentCard::ShakenNotPurred)),
-        "8s" => Some(EquipmentCard::Talent(TalentCard::FeatherfallJudgment)),
-        "8t" => Some(EquipmentCard::Talent(TalentCard::GloriousSeason)),
-        "8u" => Some(EquipmentCard::Talent(TalentCard::MirrorCage)),
-        "8v" => Some(EquipmentCard::Talent(TalentCard::ProliferatingSpores)),
-        "g8" => Some(EquipmentCard::Weapon(WeaponCard::MagicGuide)),
-        "g9" => Some(EquipmentCard::Weapon(WeaponCard::SacrificialFragments)),
-        "ga" => Some(EquipmentCard::Weapon(WeaponCard::SkywardAtlas)),
-        "gb" => Some(EquipmentCard::Weapon(WeaponCard::RavenBow)),
-        "gc" => Some(EquipmentCard::Weapon(WeaponCard::SacrificialBow)),
-        "gd" => Some(EquipmentCard::Weapon(WeaponCard::SkywardHarp)),
-        "ge" => Some(EquipmentCard::Weapon(WeaponCard::WhiteIronGreatsword)),
-        "gf" => Some(EquipmentCard::Weapon(WeaponCard::SacrificialGreatsword)),
-        "gg" => Some(EquipmentCard::Weapon(WeaponCard::WolfsGravestone)),
-        "gh" => Some(EquipmentCard::Weapon(WeaponCard::WhiteTassel)),
-        "gi" => Some(EquipmentCard::Weapon(WeaponCard::LithicSpear)),
-        "gj" => Some(EquipmentCard::Weapon(WeaponCard::SkywardSpine)),
-        "gk" => Some(EquipmentCard::Weapon(WeaponCard::TravelersHandySword)),
-        "gl" => Some(EquipmentCard::Weapon(WeaponCard::SacrificialSword)),
-        "gm" => Some(EquipmentCard::Weapon(WeaponCard::AquilaFavonia)),
-        "gn" => Some(EquipmentCard::Artifact(ArtifactCard::AdventurersBandana)),
-        "go" => Some(EquipmentCard::Artifact(ArtifactCard::LuckyDogsSilverCirclet)),
-        "gp" => Some(EquipmentCard::Artifact(ArtifactCard::TravelingDoctorsHandkerchief)),
-        "gq" => Some(EquipmentCard::Artifact(ArtifactCard::GamblersEarrings)),
-        "gr" => Some(EquipmentCard::Artifact(ArtifactCard::InstructorsCap)),
-        "gs" => Some(EquipmentCard::Artifact(ArtifactCard::ExilesCirclet)),
-        "gt" => Some(EquipmentCard::Artifact(ArtifactCard::BrokenRimesEcho)),
-        "gu" => Some(EquipmentCard::Artifact(ArtifactCard::BlizzardStrayer)),
-        "gv" => Some(EquipmentCard::Artifact(ArtifactCard::WineStainedTricorne)),
-        "gw" => Some(EquipmentCard::Artifact(ArtifactCard::HeartOfDepth)),
-        "gx" => Some(EquipmentCard::Artifact(ArtifactCard::WitchsScorchingHat)),
-        "gy" => Some(EquipmentCard::Artifact(ArtifactCard::CrimsonWitchOfFlames)),
-        "gz" => Some(EquipmentCard::Artifact(ArtifactCard::ThunderSummonersCrown)),
-        "gA" => Some(EquipmentCard::Artifact(ArtifactCard::ThunderingFury)),
-        "gB" => Some(EquipmentCard::Artifact(ArtifactCard::ViridescentVenerersDiadem)),
-        "gC" => Some(EquipmentCard::Artifact(ArtifactCard::ViridescentVenerer)),
-        "gD" => Some(EquipmentCard::Artifact(ArtifactCard::MaskOfSolitudeBasalt)),
-        "gE" => Some(EquipmentCard::Artifact(ArtifactCard::ArchaicPetra)),
-        "gF" => Some(EquipmentCard::Artifact(ArtifactCard::LaurelCoronet)),
-        "gG" => Some(EquipmentCard::Artifact(ArtifactCard::DeepwoodMemories)),
-        _ => None
-    }.map(|card| ActionCard::Equipment(card))
-}
+        assert_eq!(super::deck_to_url(&deck), Err(CharacterCard::Shenhe.into()));
+        assert!(super::deck_from_url("https://genshin.hotgames.gg/tcg/deck-builder?deck=s.0.d.8w&ver=1").is_err());
 
-fn decode_event_card(card: &str) -> Option<ActionCard> {
-    match card {
-        "Mo" => Some(EventCard::Resonance(ElementalResonanceCard::WovenIce)),
-        "Mp" => Some(EventCard::Resonance(ElementalResonanceCard::ShatteringIce)),
-        "Mq" => Some(EventCard::Resonance(ElementalResonanceCard::WovenWaters)),
-        "Mr" => Some(EventCard::Resonance(ElementalResonanceCard::SoothingWater)),
-        "Ms" => Some(EventCard::Resonance(ElementalResonanceCard::WovenFlames)),
-        "Mt" => Some(EventCard::Resonance(ElementalResonanceCard::FerventFlames)),
-        "Mu" => Some(EventCard::Resonance(ElementalResonanceCard::WovenThunder)),
-        "Mv" => Some(EventCard::Resonance(ElementalResonanceCard::HighVoltage)),
-        "Mw" => Some(EventCard::Resonance(ElementalResonanceCard::WovenWinds)),
-        "Mx" => Some(EventCard::Resonance(ElementalResonanceCard::ImpetuousWinds)),
-        "My" => Some(EventCard::Resonance(ElementalResonanceCard::WovenStone)),
-        "Mz" => Some(EventCard::Resonance(ElementalResonanceCard::EnduringRock)),
-        "MA" => Some(EventCard::Resonance(ElementalResonanceCard::WovenWeeds)),
-        "MB" => Some(EventCard::Resonance(ElementalResonanceCard::SprawlingGreenery)),
-        "MC" => Some(EventCard::Normal(NormalEventCard::TheBestestTravelCompanion)),
-        "MD" => Some(EventCard::Normal(NormalEventCard::ChangingShifts)),
-        "ME" => Some(EventCard::Normal(NormalEventCard::TossUp)),
-        "MF" => Some(EventCard::Normal(NormalEventCard::Strategize)),
-        "MG" => Some(EventCard::Normal(NormalEventCard::IHaventLostYet)),
-        "MH" => Some(EventCard::Normal(NormalEventCard::LeaveItToMe)),
-        "MI" => Some(EventCard::Normal(NormalEventCard::WhenTheCraneReturned)),
-        "MJ" => Some(EventCard::Normal(NormalEventCard::Starsigns)),
-        "MK" => Some(EventCard::Normal(NormalEventCard::CalxsArts)),
-        "ML" => Some(EventCard::Normal(NormalEventCard::MasterOfWeaponry)),
-        "MM" => Some(EventCard::Normal(NormalEventCard::BlessingOfTheDivineRelicsInstallation)),
-        "MN" => Some(EventCard::Normal(NormalEventCard::QuickKnit)),
-        "MO" => Some(EventCard::Normal(NormalEventCard::SendOff)),
-        "MP" => Some(EventCard::Normal(NormalEventCard::GuardiansOath)),
-        "MQ" => Some(EventCard::Normal(NormalEventCard::AbyssalSummons)),
-        "MR" => Some(EventCard::Food(FoodCard::JueyunGuoba)),
-        "MS" => Some(EventCard::Food(FoodCard::AdeptusTemptation)),
-        "MT" => Some(EventCard::Food(FoodCard::LotusFlowerCrisp)),
-        "MU" => Some(EventCard::Food(FoodCard::NorthernSmokedChicken)),
-        "MV" => Some(EventCard::Food(FoodCard::SweetMadame)),
-        "MW" => Some(EventCard::Food(FoodCard::MondstadtHashBrown)),
-        "MX" => Some(EventCard::Food(FoodCard::MushroomPizza)),
-        "MY" => Some(EventCard::Food(FoodCard::MintyMeatRolls)),
-        _ => None
-    }.map(|card| ActionCard::Event(card))
-}
+        let deck = Deck::from_iter([CharacterCard::Noelle, CharacterCard::Ganyu, CharacterCard::Keqing].map(Card::from).into_iter()
+            .chain([WeaponCard::KingsSquire.into(), FoodCard::SashimiPlatter.into()])
+            .chain(NormalEventCard::ALL.into_iter().cycle().take(28).map(Card::from))
+        ).unwrap();
 
-fn decode_support_card(card: &str) -> Option<ActionCard> {
-    match card {
-        "wg" => Some(SupportCard::Location(LocationCard::LiyueHarborWharf)),
-        "wh" => Some(SupportCard::Location(LocationCard::KnightsOfFavoniusLibrary)),
-        "wi" => Some(SupportCard::Location(LocationCard::JadeChamber)),
-        "wj" => Some(SupportCard::Location(LocationCard::DawnWinery)),
-        "wk" => Some(SupportCard::Location(LocationCard::WangshuInn)),
-        "wl" => Some(SupportCard::Location(LocationCard::FavoniusCathedral)),
-        "wm" => Some(SupportCard::Companion(CompanionCard::Paimon)),
-        "wn" => Some(SupportCard::Companion(CompanionCard::Katheryne)),
-        "wo" => Some(SupportCard::Companion(CompanionCard::Timaeus)),
-        "wp" => Some(SupportCard::Companion(CompanionCard::Wagner)),
-        "wq" => Some(SupportCard::Companion(CompanionCard::ChefMao)),
-        "wr" => Some(SupportCard::Companion(CompanionCard::Tubby)),
-        "ws" => Some(SupportCard::Companion(CompanionCard::Timmie)),
-        "wt" => Some(SupportCard::Companion(CompanionCard::Liben)),
-        "wu" => Some(SupportCard::Companion(CompanionCard::ChangTheNinth)),
-        "wv" => Some(SupportCard::Companion(CompanionCard::Ellin)),
-        "ww" => Some(SupportCard::Companion(CompanionCard::IronTongueTian)),
-        "wx" => Some(SupportCard::Companion(CompanionCard::LiuSu)),
-        "wy" => Some(SupportCard::Item(ItemCard::ParametricTransformer)),
-        "wz" => Some(SupportCard::Item(ItemCard::NRE)),
-        _ => None
-    }.map(|card| ActionCard::Support(card))
-}
+        assert_eq!(super::deck_to_url(&deck), Err(WeaponCard::KingsSquire.into()));
+        assert!(super::deck_from_url("https://genshin.hotgames.gg/tcg/deck-builder?deck=h.0.d.gI.M4&ver=1").is_err());
+
+        let deck = Deck::from_iter([CharacterCard::Noelle, CharacterCard::Ganyu, CharacterCard::Keqing].map(Card::from).into_iter()
+            .chain([ArcaneLegendCard::AncientCourtyard.into()])
+            .chain(NormalEventCard::ALL.into_iter().filter(|card| card.version() == Some(GameVersion::LAUNCH)).cycle().take(29).map(Card::from))
+        ).unwrap();
+
+        assert_eq!(super::deck_to_url(&deck), Err(ArcaneLegendCard::AncientCourtyard.into()));
+    }
 
-#[cfg(test)]
-mod tests {
     #[test]
-    fn default_deck() {
-        let url = "https://genshin.hotgames.gg/tcg/deck-builder?deck=1.6.MC.MD.MF.MG.MH.MI.MJ.MK.ML.MM.MN.MO.MP.MV.MY.e.g8.gB.gD.gF.gb.ge.gh.gk.gt.gv.gx.gz.wj.wl.wm&ver=1&lang=en&author=DefaultDeck";
+    fn codes_match_the_deck_builder() {
+        use super::*;
 
-        assert!(super::deck_from_url(url).is_ok());
+        // the deck builder's code of every card it has, as hand-written before cards.json
+        for line in include_str!("codes.txt").lines() {
+            let (code, name) = line.split_once('\t').unwrap();
+            let card = Card::from_name(name).unwrap();
+
+            let (encoded, decoded) = match card {
+                Card::Character(character) => (encode_character_card(character), decode_character_card(code)),
+                Card::Action(action) => (encode_action_card(action), decode_action_card(code)),
+            };
+
+            assert_eq!((encoded, decoded), (Some(code), Ok(card)), "{name}");
+        }
+    }
+
+    #[test]
+    fn every_card_with_a_code_round_trips() {
+        use super::*;
+
+        for card in Card::all() {
+            let decoded = match card {
+                Card::Character(character) => encode_character_card(character).map(decode_character_card),
+                Card::Action(action) => encode_action_card(action).map(decode_action_card),
+            };
+
+            match decoded {
+                Some(decoded) => assert_eq!(decoded, Ok(card)),
+                // the deck builder has every card from the game's launch
+                None => assert_ne!(card.version(), Some(GameVersion::LAUNCH), "{card:?}"),
+            }
+        }
     }
 }
\ No newline at end of file
@@ -4,14 +4,21 @@ mod character;
 pub use action::*;
 mod action;
 
+pub use version::GameVersion;
+mod version;
+
+pub use catalog::{catalog, catalog_json, catalog_csv, CatalogEntry, CATALOG_COLUMNS};
+mod catalog;
+
 #[cfg(feature = "deck-url")]
-pub use deck_url::{deck_from_url, UrlDeckError};
+pub use deck_url::{deck_from_url, deck_to_url, UrlDeckError};
 
 #[cfg(feature = "deck-url")]
 mod deck_url;
 
 /// Represents any card in Genius Invokation TCG
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Card {
     Character(CharacterCard),
     Action(ActionCard),
@@ -24,6 +31,53 @@ impl Card {
             Self::Action(card)    => card.name(),
         }
     }
+
+    /// The game version that added this card, `None` for custom cards
+    pub fn version(&self) -> Option<GameVersion> {
+        match self {
+            Self::Character(card) => Some(card.version()),
+            Self::Action(card)    => card.version(),
+        }
+    }
+
+    /// Every card in the game, characters first and then action cards in [`Deck`] order
+    /// 
+    /// [`Deck`]: crate::Deck
+    pub fn all() -> impl Iterator<Item=Card> {
+        let characters = CharacterCard::ALL.into_iter().map(Card::from);
+        let equipment = TalentCard::ALL.into_iter().map(Card::from)
+            .chain(WeaponCard::ALL.into_iter().map(Card::from))
+            .chain(ArtifactCard::ALL.into_iter().map(Card::from));
+        let supports = LocationCard::ALL.into_iter().map(Card::from)
+            .chain(CompanionCard::ALL.into_iter().map(Card::from))
+            .chain(ItemCard::ALL.into_iter().map(Card::from));
+        let events = ArcaneLegendCard::ALL.into_iter().map(Card::from)
+            .chain(ElementalResonanceCard::ALL.into_iter().map(Card::from))
+            .chain(NormalEventCard::ALL.into_iter().map(Card::from))
+            .chain(FoodCard::ALL.into_iter().map(Card::from));
+
+        characters.chain(equipment).chain(supports).chain(events)
+    }
+
+    /// Looks up a card by its ingame name, as returned by [`Card::name`], including registered
+    /// custom cards
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use genius_invokation::{Card, CharacterCard};
+    /// 
+    /// assert_eq!(Card::from_name("Keqing"), Some(Card::Character(CharacterCard::Keqing)));
+    /// assert_eq!(Card::from_name("Keqing's Lover"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Card> {
+        let card = Self::all().find(|card| card.name() == name);
+
+        #[cfg(feature = "custom-cards")]
+        let card = card.or_else(|| CustomCardId::from_name(name).map(Card::from));
+
+        card
+    }
 }
 
 /// This is so that decks that include the same cards are also considered to be equal to each other
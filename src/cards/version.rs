@@ -0,0 +1,66 @@
+use std::{fmt, str::FromStr};
+
+/// A version of Genshin Impact, such as the one that added a card to the game
+///
+/// ## Example
+///
+/// ```
+/// use genius_invokation::{GameVersion, PlayingCard, WeaponCard};
+///
+/// let version: GameVersion = "3.7".parse().unwrap();
+///
+/// assert_eq!(WeaponCard::KingsSquire.version(), Some(version));
+/// assert!(GameVersion::LAUNCH < version);
+/// ```
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GameVersion {
+    major: u8,
+    minor: u8,
+}
+
+impl GameVersion {
+    /// The version Genius Invokation TCG came out with, 3.3
+    pub const LAUNCH: Self = Self::new(3, 3);
+
+    pub const fn new(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    pub fn major(&self) -> u8 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u8 {
+        self.minor
+    }
+}
+
+impl fmt::Display for GameVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl FromStr for GameVersion {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        text.split_once('.')
+            .and_then(|(major, minor)| Some(Self::new(major.parse().ok()?, minor.parse().ok()?)))
+            .ok_or_else(|| format!("invalid game version `{text}`"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for GameVersion {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GameVersion {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -1,30 +1,39 @@
 use crate::{
-    PlayingCard, CharacterCard, ActionCard, Card, Element,
+    PlayingCard, CharacterCard, ActionCard, Card, GameVersion,
     EquipmentCard, TalentCard, 
-    EventCard, ElementalResonanceCard,
+    EventCard, ElementalResonanceCard, ArcaneLegendCard,
 };
+#[cfg(feature = "custom-cards")]
+use crate::{CustomCardId, DeckRequirement};
 use super::cards::CardOrd;
 
 /// A deck for Genius Invokation TCG
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Deck {
-    characters: [CharacterCard; 3],
-    actions: [ActionCard; 30],
+    characters: Vec<CharacterCard>,
+    actions: Vec<ActionCard>,
 }
 
 impl Deck {
-    pub fn has_character(&self, card: CharacterCard) -> bool {
-        let x = self.characters;
+    /// The three character cards of a deck following the [standard](DeckRules::standard) rules
+    /// 
+    /// Panics if the deck was built with rules for another amount of characters, which can use
+    /// [`character_slice`](Deck::character_slice) instead.
+    pub fn characters(&self) -> &[CharacterCard; 3] {
+        self.characters.as_slice().try_into().expect("the deck doesn't have three characters")
+    }
 
-        card == x[0] || card == x[1] || card == x[2]
+    /// The character cards of a deck, however many its rules asked for
+    pub fn character_slice(&self) -> &[CharacterCard] {
+        &self.characters
     }
 
-    pub fn contains(&self, card: ActionCard) -> bool {
-        for i in 0..30 {
-            if card == self.actions[i] { return true }
-        }
+    pub fn has_character(&self, card: CharacterCard) -> bool {
+        self.characters.contains(&card)
+    }
 
-        false
+    pub fn contains(&self, card: ActionCard) -> bool {
+        self.actions.contains(&card)
     }
 
     pub fn iter(&self) -> IterAction<'_> {
@@ -35,118 +44,349 @@ impl Deck {
         IterAction { array: &self.actions, index: 0, unique: true }
     }
 
+    /// Builds a deck following the [standard](DeckRules::standard) rules
+    #[allow(clippy::should_implement_trait)]
     pub fn from_iter(iter: impl IntoIterator<Item=Card>) -> Result<Self, DeckError> {
-        let mut cards = iter.into_iter();
-
-        let mut char_vec   = Vec::with_capacity(3);
-        let mut action_vec = Vec::with_capacity(30);
+        Deck::from_iter_with(&DeckRules::standard(), iter)
+    }
 
-        while let Some(card) = cards.next() {
+    /// Same as [`from_iter`](Deck::from_iter), for a format with other rules
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use genius_invokation::{Card, CharacterCard::*, Deck, DeckRules, NormalEventCard::*};
+    /// 
+    /// let practice = DeckRules::standard().with_characters(2).with_actions(4);
+    /// let cards = [Keqing, Fischl].map(Card::from).into_iter()
+    ///     .chain([Strategize, Strategize, LeaveItToMe, LeaveItToMe].map(Card::from));
+    /// 
+    /// assert!(Deck::from_iter_with(&practice, cards.clone()).is_ok());
+    /// assert!(Deck::from_iter(cards).is_err());
+    /// ```
+    pub fn from_iter_with(rules: &DeckRules, iter: impl IntoIterator<Item=Card>) -> Result<Self, DeckError> {
+        let cards = iter.into_iter();
+
+        let mut characters = Vec::with_capacity(rules.characters as usize);
+        let mut actions    = Vec::with_capacity(rules.actions as usize);
+
+        for card in cards {
             match card {
                 Card::Character(character) => {
-                    if char_vec.len() == 3 {
+                    if characters.len() == rules.characters as usize {
                         return Err(DeckError::TooManyCharacterCards)
                     } else {
-                        char_vec.push(character)
+                        characters.push(character)
                     }
                 },
                 Card::Action(action) => {
-                    if action_vec.len() == 30 {
+                    if actions.len() == rules.actions as usize {
                         return Err(DeckError::TooManyActionCards)
                     } else {
-                        action_vec.push(action)
+                        actions.push(action)
                     }
                 }
             }
         }
 
-        if char_vec.len() != 3 { return   Err(DeckError::NotEnoughCharacterCards(char_vec.len() as u8)) }
-        if action_vec.len() != 30 { return Err(DeckError::NotEnoughActionCards(action_vec.len() as u8)) }
+        characters.sort_by(|a, b| a.cmp(b));
+        actions.sort_by(|a, b| a.cmp(b));
 
-        char_vec.sort_by(|a, b| a.cmp(b));
-        action_vec.sort_by(|a, b| a.cmp(b));
+        match Deck::check(rules, &characters, &actions).into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(Self { characters, actions }),
+        }
+    }
 
-        let characters = <[CharacterCard; 3]>::try_from(char_vec).unwrap();
-        let actions    = <[ActionCard; 30]>::try_from(action_vec).unwrap();
+    /// Builds a deck from exactly as many cards as the [standard](DeckRules::standard) rules
+    /// require
+    pub fn from_exact(characters: [CharacterCard; 3], actions: [ActionCard; 30]) -> Result<Self, DeckError> {
+        let mut characters = characters.to_vec();
+        let mut actions = actions.to_vec();
 
-        Deck::verify(&characters, &actions)?;
+        characters.sort_by(|a, b| a.cmp(b));
+        actions.sort_by(|a, b| a.cmp(b));
 
-        Ok(Self { characters, actions })
+        match Deck::check(&DeckRules::standard(), &characters, &actions).into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(Self { characters, actions }),
+        }
     }
 
-    pub fn from_exact(mut characters: [CharacterCard; 3], mut actions: [ActionCard; 30]) -> Result<Self, DeckError> {
+    /// Rebuilds a deck that was already checked against its own rules, such as one saved in a
+    /// replay, without knowing which rules those were
+    pub(crate) fn from_checked(iter: impl IntoIterator<Item=Card>) -> Self {
+        let mut characters = Vec::new();
+        let mut actions = Vec::new();
+
+        for card in iter {
+            match card {
+                Card::Character(character) => characters.push(character),
+                Card::Action(action) => actions.push(action),
+            }
+        }
+
         characters.sort_by(|a, b| a.cmp(b));
         actions.sort_by(|a, b| a.cmp(b));
 
-        Deck::verify(&characters, &actions)?;
+        Self { characters, actions }
+    }
+
+    /// Every reason why these cards don't make a valid deck, unlike [`from_iter`] which stops
+    /// at the first one. An empty list means [`from_iter`] would succeed
+    /// 
+    /// ## Example
+    /// 
+    /// ```
+    /// use genius_invokation::{Card, CharacterCard::*, Deck, DeckError, TalentCard};
+    /// 
+    /// let cards = [Keqing, Keqing].map(Card::from).into_iter().chain([TalentCard::Crossfire.into()]);
+    /// 
+    /// assert_eq!(Deck::validate(cards), vec![
+    ///     DeckError::NotEnoughCharacterCards(2),
+    ///     DeckError::NotEnoughActionCards(1),
+    ///     DeckError::CharacterAppearsMoreThanOnce(Keqing),
+    ///     DeckError::TalentRequiresCharacter(TalentCard::Crossfire),
+    /// ]);
+    /// ```
+    /// 
+    /// [`from_iter`]: Deck::from_iter
+    pub fn validate(iter: impl IntoIterator<Item=Card>) -> Vec<DeckError> {
+        Deck::validate_with(&DeckRules::standard(), iter)
+    }
+
+    /// Same as [`validate`](Deck::validate), for a format with other rules
+    pub fn validate_with(rules: &DeckRules, iter: impl IntoIterator<Item=Card>) -> Vec<DeckError> {
+        let mut characters = Vec::new();
+        let mut actions = Vec::new();
+
+        for card in iter {
+            match card {
+                Card::Character(character) => characters.push(character),
+                Card::Action(action) => actions.push(action),
+            }
+        }
+
+        characters.sort_by(|a, b| a.cmp(b));
+        actions.sort_by(|a, b| a.cmp(b));
 
-        Ok(Self { characters, actions })
+        Deck::check(rules, &characters, &actions)
     }
 
-    fn verify(characters: &[CharacterCard; 3], actions: &[ActionCard; 30]) -> Result<(), DeckError> {
-        if characters[0] == characters[1] || characters[1] == characters[2] {
-            // we don't have to verify if characters[0] == characters[2] because the array
-            // is assumed to be sorted already
-            return Err(DeckError::CharacterAppearsMoreThanOnce(characters[1]))
+    /// Every rule these cards break, which have to be sorted already
+    fn check(rules: &DeckRules, characters: &[CharacterCard], actions: &[ActionCard]) -> Vec<DeckError> {
+        let mut errors = Vec::new();
+        let mut push = |error| if !errors.contains(&error) { errors.push(error) };
+
+        match characters.len() {
+            amount if amount < rules.characters as usize => push(DeckError::NotEnoughCharacterCards(amount as u8)),
+            amount if amount > rules.characters as usize => push(DeckError::TooManyCharacterCards),
+            _ => (),
+        }
+
+        match actions.len() {
+            amount if amount < rules.actions as usize => push(DeckError::NotEnoughActionCards(amount as u8)),
+            amount if amount > rules.actions as usize => push(DeckError::TooManyActionCards),
+            _ => (),
+        }
+
+        for pair in characters.windows(2).filter(|pair| pair[0] == pair[1]) {
+            push(DeckError::CharacterAppearsMoreThanOnce(pair[0]));
         }
 
-        for i in 0..28 {
-            // similarly, since actions is also sorted, we can check for triplicates by just
-            // checking for the same card 3 times in a row (duplicates are allowed!)
-            if actions[i] == actions[i+1] && actions[i+1] == actions[i+2] {
-                return Err(DeckError::ActionCardAppearsMoreThanTwice(actions[i]))
+        // the errors from before DeckRules existed only describe the standard limits
+        let standard = *rules == DeckRules::standard();
+
+        // since actions are sorted, copies of the same card are always next to each other
+        for copies in actions.chunk_by(|a, b| a == b) {
+            let card = copies[0];
+            let limit = rules.limit(card);
+            let overridden = rules.limits.iter().any(|(other, _)| *other == card);
+
+            if copies.len() > limit as usize {
+                push(match card.arcane() {
+                    Some(arcane) if limit == 1 && !overridden => DeckError::ArcaneLegendAppearsMoreThanOnce(arcane),
+                    _ if standard => DeckError::ActionCardAppearsMoreThanTwice(card),
+                    _ => DeckError::TooManyCopies(card, limit),
+                });
             }
         }
 
-        // Verifies if there's elemental resonance within the characters
-        // That is, if there are two or three characters of same element
-        let resonance: Option<Element> = {
-            let el1 = characters[0].element();
-            let el2 = characters[1].element();
-            let el3 = characters[2].element();
-
-            if el1 == el2 {
-                Some(el1)
-            } else if el2 == el3 {
-                Some(el2)
-            } else if el3 == el1 {
-                Some(el3)
-            } else {
-                None
+        let cards = characters.iter().map(|card| Card::from(*card))
+            .chain(actions.iter().map(|card| Card::from(*card)));
+
+        for card in cards {
+            if rules.is_banned(card) {
+                push(DeckError::BannedCard(card));
             }
-        };
 
-        for i in 0..30 {
-            if let ActionCard::Equipment(EquipmentCard::Talent(talent)) = actions[i] {
-                let required = talent.character();
+            if let (Some(allowed), Some(version)) = (rules.version, card.version()) {
+                if version > allowed {
+                    push(DeckError::NotInVersion(card, allowed));
+                }
+            }
+        }
 
-                if !characters.contains(&required) {
-                    return Err(DeckError::TalentRequiresCharacter(talent))
+        for &action in actions {
+            if let ActionCard::Equipment(EquipmentCard::Talent(talent)) = action {
+                if !characters.contains(&talent.character()) {
+                    push(DeckError::TalentRequiresCharacter(talent));
                 }
             }
 
-            if let ActionCard::Event(EventCard::Resonance(elemental)) = actions[i] {
-                let required = elemental.element();
+            if let ActionCard::Event(EventCard::Resonance(elemental)) = action {
+                let element = elemental.element();
 
-                if resonance != Some(required) {
-                    return Err(DeckError::ResonanceRequiresAtLeastTwo(elemental))
+                if characters.iter().filter(|character| character.element() == element).count() < 2 {
+                    push(DeckError::ResonanceRequiresAtLeastTwo(elemental));
                 }
             }
+
+            #[cfg(feature = "custom-cards")]
+            if let Some(error) = Deck::custom_requirement(characters, actions, action) {
+                push(error);
+            }
         }
 
-        Ok(())
+        errors
+    }
+
+    /// The first requirement of a custom card that the deck doesn't meet
+    #[cfg(feature = "custom-cards")]
+    fn custom_requirement(characters: &[CharacterCard], actions: &[ActionCard], action: ActionCard) -> Option<DeckError> {
+        let ActionCard::Custom(card) = action else { return None };
+        let copies = actions.iter().filter(|other| **other == action).count();
+
+        card.requirements().iter()
+            .find(|requirement| !requirement.is_met(characters, copies))
+            .map(|requirement| DeckError::CustomRequirementNotMet(card, *requirement))
     }
 }
 
-#[derive(PartialEq, Eq)]
+/// How many cards of each kind a deck has to hold, and which cards it may hold
+/// 
+/// Event formats and some game modes play with other numbers than the usual 3 characters and
+/// 30 action cards, which [`standard`](Self::standard) describes.
+/// 
+/// ## Example
+/// 
+/// ```
+/// use genius_invokation::{DeckRules, GameVersion, NormalEventCard};
+/// 
+/// let rules = DeckRules::standard()
+///     .with_limit(NormalEventCard::Strategize, 1)
+///     .with_banned(NormalEventCard::TossUp)
+///     .with_version(GameVersion::new(3, 5));
+/// 
+/// assert_eq!(rules.limit(NormalEventCard::Strategize.into()), 1);
+/// assert_eq!(rules.limit(NormalEventCard::LeaveItToMe.into()), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeckRules {
+    characters: u8,
+    actions: u8,
+    copies: u8,
+    limits: Vec<(ActionCard, u8)>,
+    banned: Vec<Card>,
+    version: Option<GameVersion>,
+}
+
+impl DeckRules {
+    /// The rules of ranked play: 3 different characters, 30 action cards, at most two copies of
+    /// each and one of each Arcane Legend card, with every card allowed
+    pub fn standard() -> Self {
+        Self { characters: 3, actions: 30, copies: 2, limits: Vec::new(), banned: Vec::new(), version: None }
+    }
+
+    /// How many character cards a deck has to hold
+    pub fn with_characters(mut self, amount: u8) -> Self {
+        self.characters = amount;
+        self
+    }
+
+    /// How many action cards a deck has to hold
+    pub fn with_actions(mut self, amount: u8) -> Self {
+        self.actions = amount;
+        self
+    }
+
+    /// How many copies of the same action card a deck can hold, unless it has its own
+    /// [limit](Self::with_limit). Arcane Legend cards stay limited to one
+    pub fn with_copies(mut self, copies: u8) -> Self {
+        self.copies = copies;
+        self
+    }
+
+    /// How many copies of this card a deck can hold, replacing the usual limit
+    pub fn with_limit(mut self, card: impl Into<ActionCard>, copies: u8) -> Self {
+        let card = card.into();
+
+        self.limits.retain(|(other, _)| *other != card);
+        self.limits.push((card, copies));
+        self
+    }
+
+    /// Forbids this card from being in a deck
+    pub fn with_banned(mut self, card: impl Into<Card>) -> Self {
+        let card = card.into();
+
+        if !self.banned.contains(&card) {
+            self.banned.push(card);
+        }
+
+        self
+    }
+
+    /// Only allows cards that were already in the game in this version
+    /// 
+    /// Custom cards don't belong to any version, so they're always allowed.
+    pub fn with_version(mut self, version: GameVersion) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    pub fn characters(&self) -> u8 {
+        self.characters
+    }
+
+    pub fn actions(&self) -> u8 {
+        self.actions
+    }
+
+    /// How many copies of this card a deck can hold
+    pub fn limit(&self, card: ActionCard) -> u8 {
+        match self.limits.iter().find(|(other, _)| *other == card) {
+            Some((_, copies)) => *copies,
+            None if card.arcane().is_some() => self.copies.min(1),
+            None => self.copies,
+        }
+    }
+
+    pub fn is_banned(&self, card: Card) -> bool {
+        self.banned.contains(&card)
+    }
+
+    /// The latest version cards can come from, if limited
+    pub fn version(&self) -> Option<GameVersion> {
+        self.version
+    }
+}
+
+impl Default for DeckRules {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
 pub enum DeckError {
-    /// Character cards > 3
+    /// More character cards than the rules allow (3 in standard decks)
     TooManyCharacterCards,
-    /// Action cards > 30
+    /// More action cards than the rules allow (30 in standard decks)
     TooManyActionCards,
-    /// Character cards < 3 (u8 represents current amount)
+    /// Fewer character cards than the rules require (u8 represents current amount)
     NotEnoughCharacterCards(u8),
-    /// Action cards < 30 (u8 represents current amount)
+    /// Fewer action cards than the rules require (u8 represents current amount)
     NotEnoughActionCards(u8),
     /// Talent card is present, but its CharacterCard requirement is not
     TalentRequiresCharacter(TalentCard),
@@ -156,6 +396,19 @@ pub enum DeckError {
     CharacterAppearsMoreThanOnce(CharacterCard),
     /// Only one or two of the same action card allowed for a deck
     ActionCardAppearsMoreThanTwice(ActionCard),
+    /// Only one of each Arcane Legend card allowed for a deck
+    ArcaneLegendAppearsMoreThanOnce(ArcaneLegendCard),
+    /// More copies of an action card than the rules allow, when the limit isn't the usual one
+    /// (u8 represents the limit)
+    TooManyCopies(ActionCard, u8),
+    /// Card is banned by the rules
+    BannedCard(Card),
+    /// Card came out after the latest version allowed by the rules
+    NotInVersion(Card, GameVersion),
+    /// Custom card is present, but the deck doesn't meet one of its requirements
+    #[cfg(feature = "custom-cards")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "custom-cards")))]
+    CustomRequirementNotMet(CustomCardId, DeckRequirement),
 }
 
 use std::fmt;
@@ -185,13 +438,39 @@ impl fmt::Display for DeckError {
 
                 format!("deck contains more than two `{card_name}`")
             },
-            Self::TooManyCharacterCards => "deck has more than three character cards".into(),
-            Self::TooManyActionCards => "deck has more than 30 action cards".into(),
+            Self::ArcaneLegendAppearsMoreThanOnce(card) => {
+                let card_name = card.name();
+
+                format!("deck contains more than one `{card_name}`")
+            },
+            Self::TooManyCopies(card, limit) => {
+                let card_name = card.name();
+
+                format!("deck contains more than {limit} `{card_name}`")
+            },
+            Self::BannedCard(card) => {
+                let card_name = card.name();
+
+                format!("`{card_name}` is banned")
+            },
+            Self::NotInVersion(card, version) => {
+                let card_name = card.name();
+
+                format!("`{card_name}` came out after version {version}")
+            },
+            #[cfg(feature = "custom-cards")]
+            Self::CustomRequirementNotMet(card, requirement) => {
+                let card_name = card.name();
+
+                format!("`{card_name}` requires {requirement}")
+            },
+            Self::TooManyCharacterCards => "deck has too many character cards".into(),
+            Self::TooManyActionCards => "deck has too many action cards".into(),
             Self::NotEnoughCharacterCards(x) => {
-                format!("decks require 3 character cards, only retrieved {x}")
+                format!("deck doesn't have enough character cards, only retrieved {x}")
             },
             Self::NotEnoughActionCards(x) => {
-                format!("decks require 30 action cards, only retrieved {x}")
+                format!("deck doesn't have enough action cards, only retrieved {x}")
             },
         };
         
@@ -214,7 +493,7 @@ impl std::error::Error for DeckError {}
 /// [`iter`]: Deck::iter
 /// [`iter_unique`]: Deck::iter_unique
 pub struct IterAction<'d> {
-    array: &'d [ActionCard; 30],
+    array: &'d [ActionCard],
     index: usize,
     unique: bool,
 }
@@ -223,23 +502,17 @@ impl Iterator for IterAction<'_> {
     type Item = ActionCard;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index < 30 {
-            let card = self.array[self.index];
-
-            if self.unique && self.index < 29 {
-                if card == self.array[self.index + 1] {
-                    self.index += 2; // Assumes you can only have at most 2 cards
-                } else {
-                    self.index += 1;
-                }
-            } else {
+        let card = *self.array.get(self.index)?;
+        self.index += 1;
+
+        if self.unique {
+            // copies are next to each other, since the cards are sorted
+            while self.array.get(self.index) == Some(&card) {
                 self.index += 1;
             }
-
-            Some(card)
-        } else {
-            None
         }
+
+        Some(card)
     }
 }
 
@@ -282,6 +555,8 @@ mod tests {
     const AB: ActionCard = ActionCard::Event(EventCard::Normal(NormalEventCard::AbyssalSummons));
     const HI: ActionCard = ActionCard::Event(EventCard::Resonance(ElementalResonanceCard::HighVoltage));
     const EN: ActionCard = ActionCard::Event(EventCard::Resonance(ElementalResonanceCard::EnduringRock));
+    const AN: ActionCard = ActionCard::Event(EventCard::Arcane(ArcaneLegendCard::AncientCourtyard));
+    const CV: ActionCard = ActionCard::Event(EventCard::Arcane(ArcaneLegendCard::CovenantOfRock));
     const PA: ActionCard = ActionCard::Support(SupportCard::Companion(CompanionCard::Paimon));
     const LI: ActionCard = ActionCard::Support(SupportCard::Companion(CompanionCard::Liben));
     const TI: ActionCard = ActionCard::Support(SupportCard::Companion(CompanionCard::Timaeus));
@@ -414,6 +689,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn arcane_legends_are_unique() {
+        let single = iter(
+            &[LA, CO, NO],
+            &[ST, PA, VI, AN, LU, WO, WH, NR, WA, JU, JA, WA, MI, WH, FA, LE, GA, LO, MA, AD, CV, TU, MI, TI, AQ, AB, TU, AB, AQ, DE]
+        );
+
+        assert!(Deck::from_iter(single).is_ok());
+
+        let duplicate = iter(
+            &[LA, CO, NO],
+            &[ST, PA, VI, AN, LU, WO, WH, NR, WA, JU, JA, WA, MI, WH, FA, LE, GA, LO, MA, AD, AN, TU, MI, TI, AQ, AB, TU, AB, AQ, DE]
+        );
+
+        assert_eq!(
+            Deck::from_iter(duplicate.clone()),
+            Err(DeckError::ArcaneLegendAppearsMoreThanOnce(ArcaneLegendCard::AncientCourtyard)),
+        );
+        assert_eq!(Deck::validate(duplicate), vec![
+            DeckError::ArcaneLegendAppearsMoreThanOnce(ArcaneLegendCard::AncientCourtyard),
+        ]);
+    }
+
+    #[test]
+    fn validate_finds_every_error() {
+        let broken = iter(
+            &[YO, AG, YO, FI],
+            &[ST, PA, VI, TU, LU, WO, IG, NR, WA, JU, JA, WA, MI, WH, FA, LE, GA, LO, MA, AD, HI, TU, MI, TI, AQ, AB, TU, AB, AQ, DE, IG]
+        );
+
+        assert_eq!(Deck::validate(broken), vec![
+            DeckError::TooManyCharacterCards,
+            DeckError::TooManyActionCards,
+            DeckError::CharacterAppearsMoreThanOnce(YO),
+            DeckError::ActionCardAppearsMoreThanTwice(TU),
+            DeckError::TalentRequiresCharacter(TalentCard::IGotYourBack),
+            DeckError::ResonanceRequiresAtLeastTwo(ElementalResonanceCard::HighVoltage),
+        ]);
+
+        let valid = iter(
+            &[FI, NO, AG],
+            &[ST, PA, VI, DA, LU, WO, IG, NR, WA, JU, JA, WA, MI, WH, FA, LE, GA, LO, MA, AD, LI, TU, IG, TI, AQ, AB, TU, AB, AQ, DE]
+        );
+
+        assert_eq!(Deck::validate(valid), Vec::new());
+    }
+
+    #[test]
+    fn rules_change_amounts_and_copies() {
+        let practice = DeckRules::standard().with_characters(2).with_actions(5).with_copies(3);
+        let deck = Deck::from_iter_with(&practice, iter(&[KE, FI], &[ST, ST, ST, AN, HI])).unwrap();
+
+        assert_eq!(deck.character_slice(), &[FI, KE]);
+        assert_eq!(deck.iter_unique().collect::<Vec<_>>(), vec![AN, HI, ST]);
+
+        assert_eq!(Deck::from_iter_with(&practice, iter(&[KE, FI, RA], &[ST, ST, ST, AN, HI])), Err(DeckError::TooManyCharacterCards));
+        assert_eq!(Deck::from_iter_with(&practice, iter(&[KE, FI], &[ST, ST, ST, ST, HI])), Err(DeckError::TooManyCopies(ST, 3)));
+        assert_eq!(
+            Deck::from_iter_with(&practice, iter(&[KE, FI], &[ST, ST, AN, AN, HI])),
+            Err(DeckError::ArcaneLegendAppearsMoreThanOnce(ArcaneLegendCard::AncientCourtyard)),
+        );
+
+        let limited = practice.with_limit(NormalEventCard::Strategize, 1);
+        assert_eq!(limited.limit(ST), 1);
+        assert_eq!(Deck::from_iter_with(&limited, iter(&[KE, FI], &[ST, ST, LE, LE, HI])), Err(DeckError::TooManyCopies(ST, 1)));
+    }
+
+    #[test]
+    fn other_rules_report_their_own_limits() {
+        // a limit of two only names the standard rule when the rules are the standard ones
+        let practice = DeckRules::standard().with_characters(2).with_actions(5);
+        assert_eq!(Deck::from_iter_with(&practice, iter(&[KE, FI], &[ST, ST, ST, LE, HI])), Err(DeckError::TooManyCopies(ST, 2)));
+
+        let overridden = DeckRules::standard().with_characters(2).with_actions(5).with_copies(4).with_limit(ST, 2);
+        assert_eq!(Deck::from_iter_with(&overridden, iter(&[KE, FI], &[ST, ST, ST, LE, HI])), Err(DeckError::TooManyCopies(ST, 2)));
+
+        let overridden = overridden.with_limit(AN, 1);
+        assert_eq!(Deck::from_iter_with(&overridden, iter(&[KE, FI], &[AN, AN, ST, LE, HI])), Err(DeckError::TooManyCopies(AN, 1)));
+    }
+
+    #[test]
+    fn rules_ban_cards_and_versions() {
+        let actions = [ST, PA, VI, DA, LU, WO, IG, NR, WA, JU, JA, WA, MI, WH, FA, LE, GA, LO, MA, AD, LI, TU, IG, TI, AQ, AB, TU, AB, AN, DE];
+        let deck = iter(&[LA, CO, NO], &actions);
+
+        let rules = DeckRules::standard()
+            .with_banned(CompanionCard::Paimon)
+            .with_banned(CO)
+            .with_version(GameVersion::new(4, 2));
+
+        assert_eq!(Deck::validate_with(&rules, deck.clone()), vec![
+            DeckError::BannedCard(CO.into()),
+            DeckError::BannedCard(PA.into()),
+            DeckError::NotInVersion(AN.into(), GameVersion::new(4, 2)),
+        ]);
+        assert!(Deck::from_iter_with(&rules.with_version(GameVersion::new(4, 3)), deck.clone()).is_err());
+        assert!(Deck::from_iter_with(&DeckRules::standard().with_version(GameVersion::new(4, 3)), deck).is_ok());
+    }
+
     #[test]
     fn from_exact_works() {
         let characters = [LA, CO, NO];
@@ -2,6 +2,7 @@ use crate::Element;
 
 /// An elemental octet die 
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Die {
     Omni,
     Element(Element)
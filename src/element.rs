@@ -7,6 +7,7 @@
 /// assert_eq!(CharacterCard::Fischl.element(), Element::Electro);
 /// ```
 #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Element {
     Cryo,
     Hydro,
@@ -15,4 +16,12 @@ pub enum Element {
     Dendro,
     Anemo,
     Pyro,
-}
\ No newline at end of file
+}
+
+impl Element {
+    /// Every element, in the same order as the elemental dice are displayed ingame
+    pub const ALL: [Element; 7] = [
+        Element::Cryo, Element::Hydro, Element::Pyro, Element::Electro,
+        Element::Anemo, Element::Geo, Element::Dendro,
+    ];
+}
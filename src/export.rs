@@ -0,0 +1,29 @@
+//! Escaping for the hand-written CSV and JSON exports
+
+use std::fmt::Write;
+
+/// Quotes a CSV field if it needs to be
+pub(crate) fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// A JSON string literal
+pub(crate) fn json_string(string: &str) -> String {
+    let mut json = String::from("\"");
+
+    for char in string.chars() {
+        match char {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            char if char.is_control() => write!(json, "\\u{:04x}", char as u32).unwrap(),
+            char => json.push(char),
+        }
+    }
+
+    json.push('"');
+    json
+}
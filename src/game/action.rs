@@ -0,0 +1,850 @@
+use std::fmt::{Debug, Display};
+use crate::*;
+
+/// Something a player can do when it's their turn
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    /// Plays a card from the hand, paying its cost with the player's dice
+    PlayCard { card: ActionCard, target: Option<Target> },
+    /// Discards a card from the hand to turn one die into the active character's element
+    ElementalTuning { card: ActionCard, die: Die },
+    /// Makes the active character use one of its skills, by position in
+    /// [`CharacterCard::skills`]
+    UseSkill { skill: usize },
+    /// Switches the active character, paying one die unless discounted
+    SwitchCharacter { to: usize },
+    /// Declares the end of the round, giving up on any further action until the next one
+    EndRound,
+    /// Picks a new active character, answering [`Decision::ChooseActive`]
+    ChooseActive { character: usize },
+    /// Shuffles the selected cards back into the pile and draws as many, answering
+    /// [`Decision::Mulligan`]. Bit `i` of `cards` selects the card at position `i` in the hand
+    SwapCards { cards: u16 },
+    /// Rerolls the selected dice, answering [`Decision::Reroll`]. Selecting no dice gives up on
+    /// any reroll left
+    Reroll { dice: Dice },
+}
+
+/// A choice a player has to make before the match can go on, regardless of whose turn it is
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Decision {
+    /// The player's active character was defeated and they have to choose the next one, which
+    /// is free and doesn't use up their turn
+    ChooseActive { player: PlayerId },
+    /// At the start of the match, the player may swap any of their starting cards once
+    Mulligan { player: PlayerId },
+    /// The player may reroll any of their dice, during the Roll Phase or because of a card
+    /// (see [`PlayerState::pending_rerolls`])
+    Reroll { player: PlayerId },
+}
+
+impl Decision {
+    /// The player who has to decide
+    pub fn player(&self) -> PlayerId {
+        match self {
+            Self::ChooseActive { player } | Self::Mulligan { player } | Self::Reroll { player } => *player,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ActionError {
+    /// Actions can only be taken during the Action Phase
+    WrongPhase,
+    /// It's the other player's turn
+    NotYourTurn,
+    /// The card isn't in the player's hand
+    NotInHand(ActionCard),
+    /// The card can't be played on this target, or can't be played at all right now
+    InvalidTarget(ActionCard),
+    /// The player doesn't have the dice to pay for the action
+    NotEnoughDice,
+    /// The active character doesn't have enough energy for the action
+    NotEnoughEnergy,
+    /// The card's cost asks for something players can't pay yet, like discarding other cards
+    UnsupportedCost(ActionCard),
+    /// The die can't be used for Elemental Tuning
+    InvalidDie(Die),
+    /// The active character has no such skill, or can't use skills right now
+    InvalidSkill(usize),
+    /// The character can't be switched to or chosen as the active character
+    InvalidCharacter(usize),
+    /// Another player has to make a decision first, or this player has to answer theirs
+    DecisionPending,
+    /// The action answers a decision nobody has to make
+    NoDecisionPending,
+    /// The selected cards or dice aren't all in the player's hand or dice pool
+    InvalidSelection,
+}
+
+impl Display for ActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WrongPhase         => write!(f, "Actions can only be taken during the Action Phase"),
+            Self::NotYourTurn        => write!(f, "It is not this player's turn"),
+            Self::NotInHand(card)    => write!(f, "{} is not in the player's hand", card.name()),
+            Self::InvalidTarget(card) => write!(f, "{} cannot be played on this target", card.name()),
+            Self::NotEnoughDice      => write!(f, "Not enough dice to pay for this action"),
+            Self::NotEnoughEnergy    => write!(f, "The active character doesn't have enough energy"),
+            Self::UnsupportedCost(card) => write!(f, "The cost of {} cannot be paid yet", card.name()),
+            Self::InvalidDie(die)    => write!(f, "{die:?} cannot be used for Elemental Tuning"),
+            Self::InvalidSkill(index) => write!(f, "The active character cannot use skill {index}"),
+            Self::InvalidCharacter(index) => write!(f, "Character {index} cannot become the active character"),
+            Self::DecisionPending    => write!(f, "A decision has to be made first"),
+            Self::NoDecisionPending  => write!(f, "There is no decision to make"),
+            Self::InvalidSelection   => write!(f, "The selection doesn't match the player's cards or dice"),
+        }
+    }
+}
+
+impl Debug for ActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl std::error::Error for ActionError {}
+
+/// Whether playing a card is a fast action, which every card is except talents that make their
+/// character use a skill
+pub(crate) fn is_fast_card(card: ActionCard) -> bool {
+    card.talent().is_none_or(|talent| talent.skill().is_none())
+}
+
+impl GameState {
+    /// The decision a player has to make before anything else can happen, if any
+    pub fn decision(&self) -> Option<Decision> {
+        self.decision
+    }
+
+    /// The player expected to take the next action: whoever has a decision to make, otherwise
+    /// the player whose turn it is
+    pub fn acting_player(&self) -> PlayerId {
+        self.decision.map(|decision| decision.player()).unwrap_or(self.turn)
+    }
+
+    /// Every action the acting player can take right now
+    pub fn legal_actions(&self) -> Vec<Action> {
+        match self.decision {
+            Some(Decision::ChooseActive { player }) => {
+                return (0..self.player(player).characters().len())
+                    .filter(|index| self.player(player).character(*index).is_alive())
+                    .map(|character| Action::ChooseActive { character })
+                    .collect()
+            },
+            Some(Decision::Mulligan { player }) => {
+                let hand = self.player(player).hand().len();
+                return (0..1 << hand).map(|cards| Action::SwapCards { cards }).collect()
+            },
+            Some(Decision::Reroll { player }) => {
+                return subsets(self.player(player).dice()).into_iter().map(|dice| Action::Reroll { dice }).collect()
+            },
+            None => (),
+        }
+
+        if self.phase() != Phase::ActionPhase {
+            return Vec::new()
+        }
+
+        let player = self.turn();
+        let state = self.player(player);
+        let mut actions = Vec::new();
+        let mut seen = Vec::new();
+
+        for &card in state.hand() {
+            if seen.contains(&card) {
+                continue
+            }
+
+            seen.push(card);
+
+            if self.can_pay_card(player, card) && !self.arcane_spent(player, card) {
+                for target in card.effect().targets(self, player) {
+                    actions.push(Action::PlayCard { card, target });
+                }
+            }
+
+            for die in tuning_dice(state) {
+                actions.push(Action::ElementalTuning { card, die });
+            }
+        }
+
+        for skill in 0..state.active().card().skills().len() {
+            if self.can_use_skill(player, skill) {
+                actions.push(Action::UseSkill { skill });
+            }
+        }
+
+        for to in 0..state.characters().len() {
+            if self.check_switch(player, to).is_ok() {
+                actions.push(Action::SwitchCharacter { to });
+            }
+        }
+
+        actions.push(Action::EndRound);
+        actions
+    }
+
+    /// Whether an action leaves the turn to the player who took it (fast action) rather than
+    /// passing it to the opponent (combat action)
+    /// 
+    /// Switching characters is a combat action unless an effect on the field says otherwise.
+    pub fn is_fast(&self, action: &Action) -> bool {
+        match action {
+            Action::PlayCard { card, .. } => is_fast_card(*card),
+            Action::ElementalTuning { .. } | Action::ChooseActive { .. } => true,
+            Action::SwapCards { .. } | Action::Reroll { .. } => true,
+            Action::UseSkill { .. } | Action::EndRound => false,
+            Action::SwitchCharacter { to } => self.switch_cost(self.turn, *to).1,
+        }
+    }
+
+    /// Takes an action for the acting player
+    /// 
+    /// Accepted actions are recorded in the event log, followed by whatever they caused.
+    pub fn apply(&mut self, action: Action) -> Result<(), ActionError> {
+        let logged = self.events.len();
+        self.record(Event::Action { player: self.acting_player(), action });
+
+        let result = self.apply_action(action);
+
+        if result.is_err() {
+            self.events.truncate(logged);
+        }
+
+        result
+    }
+
+    fn apply_action(&mut self, action: Action) -> Result<(), ActionError> {
+        if let Some(decision) = self.decision {
+            return match (decision, action) {
+                (Decision::ChooseActive { player }, Action::ChooseActive { character }) => self.choose_active(player, character),
+                (Decision::Mulligan { player }, Action::SwapCards { cards }) => self.mulligan(player, cards),
+                (Decision::Reroll { player }, Action::Reroll { dice }) => self.reroll(player, dice),
+                _ => Err(ActionError::DecisionPending),
+            }
+        }
+
+        if self.phase() != Phase::ActionPhase {
+            return Err(ActionError::WrongPhase)
+        }
+
+        let player = self.turn();
+        let fast = self.is_fast(&action);
+
+        match action {
+            Action::PlayCard { card, target } => self.play_card(player, card, target)?,
+            Action::ElementalTuning { card, die } => self.elemental_tuning(player, card, die)?,
+            Action::UseSkill { skill } => self.use_skill_action(player, skill)?,
+            Action::SwitchCharacter { to } => self.switch_character(player, to)?,
+            Action::ChooseActive { .. } | Action::SwapCards { .. } | Action::Reroll { .. } => {
+                return Err(ActionError::NoDecisionPending)
+            },
+            Action::EndRound => {
+                self.declare_end(player);
+                return Ok(())
+            },
+        }
+
+        if !fast {
+            self.end_turn(player);
+        }
+
+        Ok(())
+    }
+
+    fn can_pay_card(&self, player: PlayerId, card: ActionCard) -> bool {
+        let (cost, _) = self.card_cost(player, card);
+        let state = self.player(player);
+
+        cost.discard() == 0
+            && state.active().energy() >= cost.energy()
+            && state.dice().payment(&cost, &state.valuable_elements()).is_some()
+    }
+
+    /// Whether the card is an Arcane Legend and the player already played one this match
+    fn arcane_spent(&self, player: PlayerId, card: ActionCard) -> bool {
+        card.arcane().is_some() && self.player(player).played().iter().any(|played| played.arcane().is_some())
+    }
+
+    fn play_card(&mut self, player: PlayerId, card: ActionCard, target: Option<Target>) -> Result<(), ActionError> {
+        let position = self.player(player).hand().iter().position(|held| *held == card)
+            .ok_or(ActionError::NotInHand(card))?;
+
+        if self.arcane_spent(player, card) || !card.effect().targets(self, player).contains(&target) {
+            return Err(ActionError::InvalidTarget(card))
+        }
+
+        let (cost, _) = self.card_cost(player, card);
+        let state = self.player(player);
+
+        // there's no way to choose which cards to discard yet
+        if cost.discard() > 0 {
+            return Err(ActionError::UnsupportedCost(card))
+        }
+
+        if state.active().energy() < cost.energy() {
+            return Err(ActionError::NotEnoughEnergy)
+        }
+
+        if state.dice().payment(&cost, &state.valuable_elements()).is_none() {
+            return Err(ActionError::NotEnoughDice)
+        }
+
+        // only use up discounts once we know the card can be played
+        let cost = self.commit_card_cost(player, card);
+        let state = self.player_mut(player);
+        let payment = state.dice.payment(&cost, &state.valuable_elements()).ok_or(ActionError::NotEnoughDice)?;
+
+        state.dice.remove_all(&payment);
+        state.hand.remove(position);
+        state.played.push(card);
+
+        let active = state.active;
+        self.lose_energy(player, active, cost.energy());
+        self.record(Event::CardPlayed { player, card, target });
+        self.spent(player, payment);
+
+        card.effect().play(self, player, target);
+        self.dispatch_all(&mut Hook::AfterCardPlayed { player, card });
+
+        Ok(())
+    }
+
+    fn can_use_skill(&self, player: PlayerId, index: usize) -> bool {
+        self.check_skill(player, index).is_ok()
+    }
+
+    /// Whether the active character can use a skill right now, without using up any discount
+    fn check_skill(&self, player: PlayerId, index: usize) -> Result<(), ActionError> {
+        let state = self.player(player);
+        let active = state.active();
+
+        let frozen = active.has_status(EntityKind::Status(StatusKind::Frozen));
+
+        if !active.is_alive() || frozen || index >= active.card().skills().len() {
+            return Err(ActionError::InvalidSkill(index))
+        }
+
+        let (cost, _) = self.skill_cost(player, index);
+
+        if active.energy() < cost.energy() {
+            return Err(ActionError::NotEnoughEnergy)
+        }
+
+        if state.dice().skill_payment(&cost, &state.valuable_elements()).is_none() {
+            return Err(ActionError::NotEnoughDice)
+        }
+
+        Ok(())
+    }
+
+    fn use_skill_action(&mut self, player: PlayerId, index: usize) -> Result<(), ActionError> {
+        self.check_skill(player, index)?;
+
+        let cost = self.commit_skill_cost(player, index);
+        let state = self.player_mut(player);
+        let payment = state.dice.skill_payment(&cost, &state.valuable_elements()).ok_or(ActionError::NotEnoughDice)?;
+
+        state.dice.remove_all(&payment);
+        self.spent(player, payment);
+
+        self.use_skill(player, index);
+        Ok(())
+    }
+
+    /// Whether the player can switch to a character right now, without using up any discount
+    fn check_switch(&self, player: PlayerId, to: usize) -> Result<(), ActionError> {
+        let state = self.player(player);
+
+        if to >= state.characters().len() || to == state.active_index() || !state.character(to).is_alive() {
+            return Err(ActionError::InvalidCharacter(to))
+        }
+
+        let (cost, _, _) = self.switch_cost(player, to);
+
+        if state.dice().payment(&cost, &state.valuable_elements()).is_none() {
+            return Err(ActionError::NotEnoughDice)
+        }
+
+        Ok(())
+    }
+
+    fn switch_character(&mut self, player: PlayerId, to: usize) -> Result<(), ActionError> {
+        self.check_switch(player, to)?;
+
+        let (cost, _) = self.commit_switch_cost(player, to);
+        let state = self.player_mut(player);
+        let payment = state.dice.payment(&cost, &state.valuable_elements()).ok_or(ActionError::NotEnoughDice)?;
+
+        state.dice.remove_all(&payment);
+        self.spent(player, payment);
+        self.switch_active(player, to);
+
+        Ok(())
+    }
+
+    /// Replaces a defeated active character. Doesn't cost anything nor use up a turn
+    fn choose_active(&mut self, player: PlayerId, character: usize) -> Result<(), ActionError> {
+        let state = self.player(player);
+
+        if character >= state.characters().len() || !state.character(character).is_alive() {
+            return Err(ActionError::InvalidCharacter(character))
+        }
+
+        self.decision = None;
+        self.switch_active(player, character);
+
+        Ok(())
+    }
+
+    fn elemental_tuning(&mut self, player: PlayerId, card: ActionCard, die: Die) -> Result<(), ActionError> {
+        let state = self.player(player);
+        let position = state.hand().iter().position(|held| *held == card)
+            .ok_or(ActionError::NotInHand(card))?;
+
+        if !tuning_dice(state).contains(&die) {
+            return Err(ActionError::InvalidDie(die))
+        }
+
+        let element = state.active().card().element();
+        let state = self.player_mut(player);
+
+        state.hand.remove(position);
+        state.dice.remove(die, 1);
+        state.dice.add(Die::Element(element), 1);
+
+        Ok(())
+    }
+
+    /// Swaps the selected starting cards, then moves on to the other player's mulligan or to the
+    /// first round
+    fn mulligan(&mut self, player: PlayerId, cards: u16) -> Result<(), ActionError> {
+        let hand = self.player(player).hand().len();
+
+        if cards >> hand != 0 {
+            return Err(ActionError::InvalidSelection)
+        }
+
+        let state = self.player_mut(player);
+        let mut swapped = Vec::new();
+
+        for position in (0..hand).rev() {
+            if cards & (1 << position) != 0 {
+                swapped.push(state.hand.remove(position));
+            }
+        }
+
+        // swapped cards go back into the pile only after drawing their replacements
+        self.draw_cards(player, swapped.len());
+
+        let mut pile = std::mem::take(&mut self.player_mut(player).pile);
+        pile.extend(swapped);
+        self.rng().shuffle(&mut pile);
+        self.player_mut(player).pile = pile;
+
+        self.decision = None;
+
+        match player {
+            PlayerId::First => self.decision = Some(Decision::Mulligan { player: PlayerId::Second }),
+            PlayerId::Second => self.start_round(),
+        }
+
+        Ok(())
+    }
+
+    /// Rerolls the selected dice, using up one of the player's rerolls (or all of them if no
+    /// dice are selected)
+    fn reroll(&mut self, player: PlayerId, dice: Dice) -> Result<(), ActionError> {
+        if !self.player(player).dice().contains(&dice) {
+            return Err(ActionError::InvalidSelection)
+        }
+
+        let rolled: Dice = (0..dice.total()).map(|_| self.random_die()).collect();
+        let state = self.player_mut(player);
+
+        state.dice.remove_all(&dice);
+        state.dice.add_all(&rolled);
+        state.pending_rerolls = if dice.is_empty() { 0 } else { state.pending_rerolls - 1 };
+
+        self.decision = None;
+
+        if self.phase() == Phase::RollPhase {
+            self.continue_roll_phase();
+        } else if self.player(player).pending_rerolls() > 0 {
+            self.decision = Some(Decision::Reroll { player });
+        }
+
+        Ok(())
+    }
+
+    fn spent(&mut self, player: PlayerId, dice: Dice) {
+        if !dice.is_empty() {
+            self.record(Event::DiceSpent { player, dice });
+        }
+    }
+
+    /// Passes the turn to the opponent after a combat action, unless they already ended their
+    /// round
+    fn end_turn(&mut self, player: PlayerId) {
+        if !self.player(player.opponent()).ended_round() {
+            self.turn = player.opponent();
+        }
+    }
+
+    /// Ends the player's round. The first player to do so acts first in the next round, which
+    /// starts once both players are done
+    fn declare_end(&mut self, player: PlayerId) {
+        let opponent_ended = self.player(player.opponent()).ended_round();
+        self.player_mut(player).ended_round = true;
+
+        if opponent_ended {
+            self.end_round();
+        } else {
+            self.first = player;
+            self.turn = player.opponent();
+        }
+    }
+}
+
+/// Every way of picking some of the dice in a pool, from picking none to picking all of them
+fn subsets(dice: &Dice) -> Vec<Dice> {
+    dice.iter().fold(vec![Dice::new()], |subsets, (die, count)| {
+        subsets.iter()
+            .flat_map(|subset| (0..=count).map(move |amount| {
+                let mut subset = *subset;
+                subset.add(die, amount);
+                subset
+            }))
+            .collect()
+    })
+}
+
+/// Dice that can be turned into the active character's element
+fn tuning_dice(state: &PlayerState) -> Vec<Die> {
+    let element = Die::Element(state.active().card().element());
+
+    state.dice().iter()
+        .map(|(die, _)| die)
+        .filter(|die| *die != Die::Omni && *die != element)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::skip_decisions;
+
+    const STRATEGIZE: ActionCard = ActionCard::Event(EventCard::Normal(NormalEventCard::Strategize));
+    const LEAVE_IT_TO_ME: ActionCard = ActionCard::Event(EventCard::Normal(NormalEventCard::LeaveItToMe));
+    const SWEET_MADAME: ActionCard = ActionCard::Event(EventCard::Food(FoodCard::SweetMadame));
+    const PAIMON: ActionCard = ActionCard::Support(SupportCard::Companion(CompanionCard::Paimon));
+    const DAWN_WINERY: ActionCard = ActionCard::Support(SupportCard::Location(LocationCard::DawnWinery));
+    const JOYOUS_CELEBRATION: ActionCard = ActionCard::Event(EventCard::Arcane(ArcaneLegendCard::JoyousCelebration));
+    const ANCIENT_COURTYARD: ActionCard = ActionCard::Event(EventCard::Arcane(ArcaneLegendCard::AncientCourtyard));
+
+    fn deck() -> Deck {
+        let characters = [CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle];
+        let actions = [
+            STRATEGIZE, LEAVE_IT_TO_ME, SWEET_MADAME, PAIMON, DAWN_WINERY,
+            ActionCard::Event(EventCard::Normal(NormalEventCard::TossUp)),
+            ActionCard::Event(EventCard::Normal(NormalEventCard::Starsigns)),
+            ActionCard::Event(EventCard::Normal(NormalEventCard::ChangingShifts)),
+            ActionCard::Event(EventCard::Normal(NormalEventCard::QuickKnit)),
+            ActionCard::Event(EventCard::Normal(NormalEventCard::SendOff)),
+            ActionCard::Event(EventCard::Food(FoodCard::MondstadtHashBrown)),
+            ActionCard::Event(EventCard::Food(FoodCard::JueyunGuoba)),
+            ActionCard::Event(EventCard::Food(FoodCard::LotusFlowerCrisp)),
+            ActionCard::Equipment(EquipmentCard::Weapon(WeaponCard::WhiteTassel)),
+            ActionCard::Support(SupportCard::Item(ItemCard::NRE)),
+        ];
+
+        let cards = characters.into_iter().map(Card::from)
+            .chain(actions.into_iter().chain(actions).map(Card::from));
+
+        Deck::from_iter(cards).unwrap()
+    }
+
+    /// A fresh match where the first player holds exactly the given cards and 8 Omni dice
+    fn game(hand: &[ActionCard]) -> GameState {
+        let mut game = GameState::new(&deck(), &deck(), 7);
+        skip_decisions(&mut game);
+        let state = game.player_mut(PlayerId::First);
+
+        state.hand = hand.to_vec();
+        state.dice = Dice::new();
+        state.dice.add(Die::Omni, 8);
+        game
+    }
+
+    #[test]
+    fn strategize_draws_two() {
+        let mut game = game(&[STRATEGIZE]);
+
+        assert!(game.legal_actions().contains(&Action::PlayCard { card: STRATEGIZE, target: None }));
+        game.apply(Action::PlayCard { card: STRATEGIZE, target: None }).unwrap();
+
+        let state = game.player(PlayerId::First);
+        assert_eq!(state.hand().len(), 2);
+        assert_eq!(state.dice().total(), 7);
+        assert_eq!(game.turn(), PlayerId::First);
+    }
+
+    #[test]
+    fn food_heals_and_satiates() {
+        let mut game = game(&[SWEET_MADAME, SWEET_MADAME]);
+        game.player_mut(PlayerId::First).characters[0].hp = 5;
+
+        game.apply(Action::PlayCard { card: SWEET_MADAME, target: Some(Target::Character(0)) }).unwrap();
+
+        let keqing = game.player(PlayerId::First).character(0);
+        assert_eq!(keqing.hp(), 6);
+        assert!(keqing.has_status(EntityKind::Status(StatusKind::Satiated)));
+        assert_eq!(
+            game.apply(Action::PlayCard { card: SWEET_MADAME, target: Some(Target::Character(0)) }),
+            Err(ActionError::InvalidTarget(SWEET_MADAME)),
+        );
+
+        game.apply(Action::EndRound).unwrap();
+        game.apply(Action::EndRound).unwrap();
+        skip_decisions(&mut game);
+
+        let keqing = game.player(PlayerId::First).character(0);
+        assert!(!keqing.has_status(EntityKind::Status(StatusKind::Satiated)));
+    }
+
+    #[test]
+    fn one_arcane_legend_per_match() {
+        let mut game = game(&[JOYOUS_CELEBRATION, ANCIENT_COURTYARD]);
+        game.equip(PlayerId::First, 0, WeaponCard::WhiteTassel.into());
+
+        game.apply(Action::PlayCard { card: JOYOUS_CELEBRATION, target: None }).unwrap();
+
+        let state = game.player(PlayerId::First);
+        assert!(state.characters().iter().all(|character| character.aura().contains(Element::Electro)));
+        assert!(!game.legal_actions().iter().any(|action| matches!(action, Action::PlayCard { card: ANCIENT_COURTYARD, .. })));
+        assert_eq!(
+            game.apply(Action::PlayCard { card: ANCIENT_COURTYARD, target: None }),
+            Err(ActionError::InvalidTarget(ANCIENT_COURTYARD)),
+        );
+    }
+
+    #[cfg(feature = "custom-cards")]
+    #[test]
+    fn discard_costs_cannot_be_paid_yet() {
+        let offering = CustomCard::new("Test Offering", CustomCategory::Event, CardCost::ZERO.with_discard(1))
+            .register().unwrap();
+        let mut game = game(&[offering.into(), STRATEGIZE]);
+
+        assert!(!game.legal_actions().contains(&Action::PlayCard { card: offering.into(), target: None }));
+        assert_eq!(
+            game.apply(Action::PlayCard { card: offering.into(), target: None }),
+            Err(ActionError::UnsupportedCost(offering.into())),
+        );
+        assert_eq!(game.player(PlayerId::First).hand().len(), 2);
+    }
+
+    #[test]
+    fn paimon_creates_omni_dice() {
+        let mut game = game(&[PAIMON]);
+
+        game.apply(Action::PlayCard { card: PAIMON, target: None }).unwrap();
+        assert_eq!(game.player(PlayerId::First).dice().total(), 5);
+
+        game.apply(Action::EndRound).unwrap();
+        game.apply(Action::EndRound).unwrap();
+        skip_decisions(&mut game);
+
+        let state = game.player(PlayerId::First);
+        assert_eq!(state.dice().total(), DICE_PER_ROUND + 2);
+        assert!(state.dice().count(Die::Omni) >= 2);
+        assert_eq!(state.supports()[0].usages(), Some(1));
+    }
+
+    #[test]
+    fn switch_discounts_are_only_used_up_when_committed() {
+        let mut game = game(&[DAWN_WINERY, LEAVE_IT_TO_ME]);
+        game.apply(Action::PlayCard { card: DAWN_WINERY, target: None }).unwrap();
+        game.apply(Action::PlayCard { card: LEAVE_IT_TO_ME, target: None }).unwrap();
+
+        let speed = |commit| Hook::Speed {
+            action: CostAction::Switch { from: 0, to: 1 },
+            cost: CardCost::ONE,
+            fast: false,
+            consumed: Vec::new(),
+            commit,
+        };
+
+        for commit in [false, true] {
+            let mut hook = speed(commit);
+            game.dispatch(PlayerId::First, &mut hook);
+
+            let Hook::Speed { cost, fast, consumed, .. } = hook else { unreachable!() };
+            assert_eq!(cost.amount(), 0);
+            assert!(fast);
+            assert_eq!(consumed.len(), 2);
+        }
+
+        let mut hook = speed(true);
+        game.dispatch(PlayerId::First, &mut hook);
+
+        let Hook::Speed { cost, fast, .. } = hook else { unreachable!() };
+        assert_eq!(cost.amount(), 1);
+        assert!(!fast);
+    }
+
+    #[test]
+    fn first_to_end_goes_first() {
+        let mut game = game(&[]);
+
+        game.apply(Action::EndRound).unwrap();
+        assert_eq!(game.turn(), PlayerId::Second);
+        assert_eq!(game.round(), 1);
+
+        game.apply(Action::EndRound).unwrap();
+        skip_decisions(&mut game);
+        assert_eq!(game.round(), 2);
+        assert_eq!(game.first_player(), PlayerId::First);
+        assert_eq!(game.turn(), PlayerId::First);
+    }
+
+    #[test]
+    fn tuning_converts_a_die() {
+        let mut game = game(&[PAIMON]);
+        game.player_mut(PlayerId::First).dice = [Die::Element(Element::Pyro)].into_iter().collect();
+
+        assert_eq!(game.apply(Action::PlayCard { card: PAIMON, target: None }), Err(ActionError::NotEnoughDice));
+        game.apply(Action::ElementalTuning { card: PAIMON, die: Die::Element(Element::Pyro) }).unwrap();
+
+        let state = game.player(PlayerId::First);
+        assert!(state.hand().is_empty());
+        assert_eq!(state.dice().count(Die::Element(Element::Electro)), 1);
+    }
+
+    #[test]
+    fn thunder_and_eternity_converts_every_die() {
+        let card = ActionCard::Event(EventCard::Normal(NormalEventCard::ThunderAndEternity));
+        let mut game = game(&[card]);
+        game.player_mut(PlayerId::First).dice = [
+            Die::Omni, Die::Element(Element::Pyro), Die::Element(Element::Pyro), Die::Element(Element::Geo),
+        ].into_iter().collect();
+
+        game.apply(Action::PlayCard { card, target: None }).unwrap();
+
+        let dice = game.player(PlayerId::First).dice();
+        assert_eq!((dice.count(Die::Omni), dice.count(Die::Element(Element::Electro))), (1, 3));
+        assert_eq!(game.turn(), PlayerId::First);
+    }
+
+    #[test]
+    fn switching_costs_a_die_and_ends_the_turn() {
+        let mut game = game(&[]);
+
+        assert!(game.legal_actions().contains(&Action::SwitchCharacter { to: 1 }));
+        assert_eq!(game.apply(Action::SwitchCharacter { to: 0 }), Err(ActionError::InvalidCharacter(0)));
+        assert!(!game.is_fast(&Action::SwitchCharacter { to: 1 }));
+
+        game.apply(Action::SwitchCharacter { to: 1 }).unwrap();
+
+        let state = game.player(PlayerId::First);
+        assert_eq!(state.active_index(), 1);
+        assert_eq!(state.dice().total(), 7);
+        assert_eq!(game.turn(), PlayerId::Second);
+    }
+
+    #[test]
+    fn leave_it_to_me_makes_switching_fast() {
+        let mut game = game(&[LEAVE_IT_TO_ME]);
+        game.apply(Action::PlayCard { card: LEAVE_IT_TO_ME, target: None }).unwrap();
+
+        assert!(game.is_fast(&Action::SwitchCharacter { to: 2 }));
+        game.apply(Action::SwitchCharacter { to: 2 }).unwrap();
+        assert_eq!(game.turn(), PlayerId::First);
+
+        // only once
+        assert!(!game.is_fast(&Action::SwitchCharacter { to: 0 }));
+    }
+
+    #[test]
+    fn defeated_active_character_is_replaced_for_free() {
+        let mut game = game(&[]);
+        game.player_mut(PlayerId::Second).characters[0].hp = 1;
+        game.apply(Action::UseSkill { skill: 0 }).unwrap();
+
+        assert_eq!(game.decision(), Some(Decision::ChooseActive { player: PlayerId::Second }));
+        assert_eq!(game.acting_player(), PlayerId::Second);
+        assert_eq!(game.legal_actions(), [Action::ChooseActive { character: 1 }, Action::ChooseActive { character: 2 }]);
+        assert_eq!(game.apply(Action::EndRound), Err(ActionError::DecisionPending));
+
+        game.apply(Action::ChooseActive { character: 2 }).unwrap();
+
+        let state = game.player(PlayerId::Second);
+        assert_eq!(state.active_index(), 2);
+        assert_eq!(state.dice().total(), DICE_PER_ROUND);
+        assert_eq!(game.decision(), None);
+        assert_eq!(game.turn(), PlayerId::Second);
+    }
+
+    #[test]
+    fn mulligan_swaps_selected_cards() {
+        let mut game = GameState::new(&deck(), &deck(), 7);
+        assert_eq!(game.decision(), Some(Decision::Mulligan { player: PlayerId::First }));
+        assert_eq!(game.legal_actions().len(), 1 << STARTING_HAND);
+
+        let kept = game.player(PlayerId::First).hand()[1..].to_vec();
+        assert_eq!(game.apply(Action::SwapCards { cards: 1 << STARTING_HAND }), Err(ActionError::InvalidSelection));
+        game.apply(Action::SwapCards { cards: 0b1 }).unwrap();
+
+        let state = game.player(PlayerId::First);
+        assert_eq!(state.hand().len(), STARTING_HAND);
+        assert_eq!(state.hand()[..STARTING_HAND - 1], kept);
+        assert_eq!(state.pile().len(), 30 - STARTING_HAND);
+
+        assert_eq!(game.decision(), Some(Decision::Mulligan { player: PlayerId::Second }));
+        game.apply(Action::SwapCards { cards: 0 }).unwrap();
+
+        // on to the Roll Phase
+        assert_eq!(game.phase(), Phase::RollPhase);
+        assert_eq!(game.decision(), Some(Decision::Reroll { player: PlayerId::First }));
+    }
+
+    #[test]
+    fn roll_phase_rerolls() {
+        let mut game = GameState::new(&deck(), &deck(), 7);
+        game.apply(Action::SwapCards { cards: 0 }).unwrap();
+        game.apply(Action::SwapCards { cards: 0 }).unwrap();
+
+        let dice = *game.player(PlayerId::First).dice();
+        assert!(game.legal_actions().contains(&Action::Reroll { dice }));
+        assert_eq!(game.apply(Action::EndRound), Err(ActionError::DecisionPending));
+
+        game.apply(Action::Reroll { dice }).unwrap();
+        assert_eq!(game.player(PlayerId::First).dice().total(), DICE_PER_ROUND);
+        assert_eq!(game.player(PlayerId::First).pending_rerolls(), 0);
+
+        assert_eq!(game.decision(), Some(Decision::Reroll { player: PlayerId::Second }));
+        game.apply(Action::Reroll { dice: Dice::new() }).unwrap();
+
+        assert_eq!(game.decision(), None);
+        assert_eq!(game.phase(), Phase::ActionPhase);
+    }
+
+    #[test]
+    fn toss_up_rerolls_twice() {
+        let toss_up = ActionCard::Event(EventCard::Normal(NormalEventCard::TossUp));
+        let mut game = game(&[toss_up]);
+        game.apply(Action::PlayCard { card: toss_up, target: None }).unwrap();
+
+        assert_eq!(game.decision(), Some(Decision::Reroll { player: PlayerId::First }));
+
+        for left in [1, 0] {
+            game.apply(Action::Reroll { dice: [Die::Omni].into_iter().collect() }).unwrap();
+            assert_eq!(game.player(PlayerId::First).pending_rerolls(), left);
+        }
+
+        assert_eq!(game.decision(), None);
+        assert_eq!(game.player(PlayerId::First).dice().total(), 8);
+    }
+}
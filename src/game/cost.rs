@@ -0,0 +1,250 @@
+use crate::*;
+use super::action::is_fast_card;
+
+/// What a cost is paid for, see [`GameState::effective_cost`]
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CostTarget {
+    /// Playing a card from the hand
+    Card(ActionCard),
+    /// Using one of the active character's skills, by position in [`CharacterCard::skills`]
+    Skill(usize),
+    /// Switching the active character to the character at this position
+    Switch(usize),
+}
+
+/// An entity on the field that changed a cost, using up one of its usages once paid
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub struct CostModifier {
+    pub id: EntityId,
+    pub kind: EntityKind,
+    pub location: Location,
+}
+
+/// A cost after every discount and tax on the field
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub struct EffectiveCost {
+    /// The cost before any modifier applied
+    pub base: CardCost,
+    pub cost: CardCost,
+    /// Whether paying it leaves the turn to the player, which some effects grant to switching
+    pub fast: bool,
+    /// The entities that changed the cost, in the order they applied
+    pub modifiers: Vec<CostModifier>,
+}
+
+impl GameState {
+    /// What an action really costs the player right now, with every modifier on the field, and
+    /// which modifiers paying would use up
+    ///
+    /// This is what the rules engine charges when the action is taken, so previews and bots
+    /// can rely on it. Nothing is used up by asking.
+    pub fn effective_cost(&self, player: PlayerId, target: CostTarget) -> EffectiveCost {
+        let base = match target {
+            CostTarget::Card(card) => card.cost(),
+            CostTarget::Skill(index) => self.player(player).active().card().skills().get(index)
+                .map_or(CardCost::ZERO, |skill| skill.cost().into()),
+            CostTarget::Switch(_) => CardCost::ONE,
+        };
+
+        self.effective_cost_from(player, target, base)
+    }
+
+    /// Same as [`effective_cost`](Self::effective_cost), starting from `base` instead of the
+    /// printed cost, such as to preview a card that isn't in the game
+    ///
+    /// Skills can only be paid with dice of the active character's element and unaligned dice,
+    /// so for them exact and matching dice in `base` both count as the former.
+    pub fn effective_cost_from(&self, player: PlayerId, target: CostTarget, base: CardCost) -> EffectiveCost {
+        let active = self.player(player).active_index();
+
+        let (cost, fast, consumed) = match target {
+            CostTarget::Card(card) => {
+                let mut hook = Hook::CardCost { card, cost: base, consumed: Vec::new(), commit: false };
+                self.preview(player, &mut hook);
+
+                let Hook::CardCost { cost, consumed, .. } = hook else { unreachable!() };
+                let fast = is_fast_card(card);
+                (cost, fast, consumed)
+            },
+            CostTarget::Skill(index) => {
+                let Some(skill) = self.player(player).active().card().skills().get(index) else {
+                    return EffectiveCost { base, cost: base, fast: false, modifiers: Vec::new() }
+                };
+
+                let element = base.exact().map_or(self.player(player).active().card().element(), |(element, _)| element);
+                let exact = base.exact().map_or(0, |(_, dice)| dice) + base.matching();
+                let cost = SkillCost::new(element, exact, base.unaligned(), base.energy());
+
+                let mut hook = Hook::SkillCost { character: active, skill: skill.kind(), cost, consumed: Vec::new(), commit: false };
+                self.preview(player, &mut hook);
+
+                let Hook::SkillCost { cost, consumed, .. } = hook else { unreachable!() };
+                (cost.into(), false, consumed)
+            },
+            CostTarget::Switch(to) => {
+                let action = CostAction::Switch { from: active, to };
+                let mut hook = Hook::Speed { action, cost: base, fast: false, consumed: Vec::new(), commit: false };
+                self.preview(player, &mut hook);
+
+                let Hook::Speed { cost, fast, consumed, .. } = hook else { unreachable!() };
+                (cost, fast, consumed)
+            },
+        };
+
+        let modifiers = consumed.into_iter()
+            .filter_map(|id| self.entity(id).map(|(owner, entity)| CostModifier {
+                id,
+                kind: entity.kind(),
+                location: self.player(owner).entity_location(id),
+            }))
+            .collect();
+
+        EffectiveCost { base, cost, fast, modifiers }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    const WHITE_TASSEL: ActionCard = ActionCard::Equipment(EquipmentCard::Weapon(WeaponCard::WhiteTassel));
+
+    #[test]
+    fn material_discounts_only_apply_to_their_cards() {
+        let mut game = duel(CharacterCard::Xiangling);
+        game.add_support(PlayerId::First, CompanionCard::Timaeus.into(), None);
+
+        let cost = game.effective_cost(PlayerId::First, CostTarget::Card(WHITE_TASSEL));
+        assert_eq!((cost.cost, cost.modifiers.len()), (CardCost::MATCH2, 0));
+
+        game.add_support(PlayerId::First, CompanionCard::Wagner.into(), None);
+
+        let cost = game.effective_cost(PlayerId::First, CostTarget::Card(WHITE_TASSEL));
+        assert_eq!(cost.cost.amount(), 0);
+        assert_eq!(cost.modifiers.iter().map(|modifier| modifier.kind).collect::<Vec<_>>(), vec![
+            EntityKind::Card(CompanionCard::Wagner.into()),
+        ]);
+        assert_eq!(cost.modifiers[0].location, Location::Support);
+    }
+
+    #[test]
+    fn master_zhang_counts_armed_characters() {
+        let mut game = duel_with([CharacterCard::Xiangling, CharacterCard::Keqing, CharacterCard::Noelle]);
+        game.add_support(PlayerId::First, CompanionCard::MasterZhang.into(), None);
+
+        let cost = game.effective_cost(PlayerId::First, CostTarget::Card(WHITE_TASSEL));
+        assert_eq!(cost.cost.amount(), 1);
+
+        game.equip(PlayerId::First, 1, WeaponCard::TravelersHandySword.into());
+
+        let cost = game.effective_cost(PlayerId::First, CostTarget::Card(WHITE_TASSEL));
+        assert_eq!((cost.cost.amount(), cost.modifiers.len()), (0, 1));
+    }
+
+    #[test]
+    fn switching_and_skills() {
+        let mut game = duel_with([CharacterCard::Ganyu, CharacterCard::Xiangling, CharacterCard::Sucrose]);
+        game.add_support(PlayerId::First, LocationCard::DawnWinery.into(), None);
+
+        let switch = game.effective_cost(PlayerId::First, CostTarget::Switch(1));
+        assert_eq!((switch.base.amount(), switch.cost.amount(), switch.fast), (1, 0, false));
+
+        game.equip(PlayerId::First, 0, ArtifactCard::BrokenRimesEcho.into());
+
+        let attack = game.effective_cost(PlayerId::First, CostTarget::Skill(0));
+        assert_eq!(attack.base, CardCost::ZERO.with_exact(Element::Cryo, 1).with_unaligned(2));
+        assert_eq!((attack.cost.exact(), attack.cost.unaligned()), (None, 2));
+        assert_eq!(attack.modifiers[0].location, Location::Equipment(0));
+    }
+
+    #[test]
+    fn asking_leaves_the_game_as_it_was() {
+        let mut game = duel_with([CharacterCard::Ganyu, CharacterCard::Xiangling, CharacterCard::Sucrose]);
+        game.add_support(PlayerId::First, LocationCard::DawnWinery.into(), None);
+        game.add_support(PlayerId::First, CompanionCard::Wagner.into(), None);
+        let before = game.clone();
+
+        for _ in 0..2 {
+            assert_eq!(game.effective_cost(PlayerId::First, CostTarget::Switch(1)).cost.amount(), 0);
+            assert_eq!(game.effective_cost(PlayerId::First, CostTarget::Card(WHITE_TASSEL)).cost.amount(), 0);
+        }
+
+        assert_eq!(game, before);
+    }
+
+    #[test]
+    fn previews_only_change_a_copy() {
+        let mut game = duel(CharacterCard::Xiangling);
+        game.add_support(PlayerId::First, LocationCard::DawnWinery.into(), None);
+        let id = game.player(PlayerId::First).supports()[0].id();
+
+        let mut ctx = Context::preview(&game, PlayerId::First, id);
+        ctx.use_this_round();
+        assert!(!ctx.can_use_this_round());
+
+        assert_eq!(game.player(PlayerId::First).supports()[0].round_usages(), 1);
+    }
+
+    /// Discounts every card by one die, using itself up even when the cost is only previewed
+    #[cfg(feature = "custom-cards")]
+    struct CarelessAltar;
+
+    #[cfg(feature = "custom-cards")]
+    impl Effect for CarelessAltar {
+        fn usages(&self) -> Option<u8> { Some(1) }
+
+        fn triggers(&self) -> &'static [Trigger] {
+            &[Trigger::CardCost]
+        }
+
+        fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+            if let Hook::CardCost { cost, consumed, .. } = hook {
+                cost.discount(1);
+                consumed.push(ctx.id());
+                ctx.consume();
+            }
+        }
+    }
+
+    #[cfg(feature = "custom-cards")]
+    impl CardEffect for CarelessAltar {
+        fn play(&self, _game: &mut GameState, _player: PlayerId, _target: Option<Target>) {}
+    }
+
+    #[cfg(feature = "custom-cards")]
+    #[test]
+    fn custom_effects_that_ignore_commit_leave_previews_alone() {
+        let altar = CustomCard::new("Test Altar", CustomCategory::Location, CardCost::ZERO)
+            .with_effect(&CarelessAltar)
+            .register().unwrap();
+        let mut game = duel(CharacterCard::Xiangling);
+        game.add_support(PlayerId::First, altar.into(), None);
+        let before = game.clone();
+
+        for _ in 0..2 {
+            assert_eq!(game.card_cost(PlayerId::First, WHITE_TASSEL).0.amount(), 1);
+            assert_eq!(game.effective_cost(PlayerId::First, CostTarget::Card(WHITE_TASSEL)).modifiers.len(), 1);
+        }
+
+        assert_eq!(game, before);
+    }
+
+    #[test]
+    fn agrees_with_the_rules_engine() {
+        let mut game = duel(CharacterCard::Xiangling);
+        game.turn = PlayerId::First;
+        game.add_support(PlayerId::First, CompanionCard::Wagner.into(), None);
+        game.player_mut(PlayerId::First).hand.push(WHITE_TASSEL);
+
+        let preview = game.effective_cost(PlayerId::First, CostTarget::Card(WHITE_TASSEL));
+        let dice = game.player(PlayerId::First).dice().total();
+        let target = Some(Target::Character(0));
+
+        game.apply(Action::PlayCard { card: WHITE_TASSEL, target }).unwrap();
+
+        assert_eq!(game.player(PlayerId::First).dice().total(), dice - preview.cost.amount());
+        assert!(game.effective_cost(PlayerId::First, CostTarget::Card(WHITE_TASSEL)).modifiers.is_empty());
+    }
+}
@@ -0,0 +1,380 @@
+use std::fmt;
+use crate::{Card, Element};
+
+pub use reaction::{Aura, Reaction};
+mod reaction;
+
+/// The type of a damage instance
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DamageKind {
+    Physical,
+    Elemental(Element),
+    /// Piercing damage ignores every bonus, reaction, reduction and shield
+    Piercing,
+}
+
+impl DamageKind {
+    pub fn element(&self) -> Option<Element> {
+        if let Self::Elemental(element) = self { Some(*element) } else { None }
+    }
+}
+
+/// Whatever caused a damage modifier to be applied, so it can be displayed back to the user
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ModifierSource {
+    /// An equipped card (weapon, artifact or talent) or any other card effect
+    Card(Card),
+    /// A character or combat status, by name
+    Status(&'static str),
+    Reaction(Reaction),
+    Other(&'static str),
+}
+
+impl ModifierSource {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Card(card)         => card.name(),
+            Self::Status(name)       => name,
+            Self::Reaction(reaction) => reaction.name(),
+            Self::Other(name)        => name,
+        }
+    }
+}
+
+/// A status protecting a character from incoming damage
+/// 
+/// These are resolved in the official order: every [`Reduction`] first, then every [`Shield`],
+/// each in the order they were given.
+/// 
+/// [`Reduction`]: DefenseKind::Reduction
+/// [`Shield`]: DefenseKind::Shield
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub struct Defense {
+    source: ModifierSource,
+    kind: DefenseKind,
+    usages: u8,
+}
+
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum DefenseKind {
+    /// Reduces damage by `amount` if the incoming damage is at least `min`, using up one usage
+    Reduction { amount: u8, min: u8 },
+    /// Absorbs damage, using up one usage per point absorbed
+    Shield,
+}
+
+impl Defense {
+    pub fn reduction(source: ModifierSource, amount: u8, min: u8, usages: u8) -> Self {
+        Self { source, kind: DefenseKind::Reduction { amount, min }, usages }
+    }
+
+    pub fn shield(source: ModifierSource, points: u8) -> Self {
+        Self { source, kind: DefenseKind::Shield, usages: points }
+    }
+
+    pub fn source(&self) -> ModifierSource {
+        self.source
+    }
+
+    pub fn kind(&self) -> DefenseKind {
+        self.kind
+    }
+
+    /// Usages left, which for shields are the damage points it can still absorb
+    pub fn usages(&self) -> u8 {
+        self.usages
+    }
+}
+
+/// Builder for a single damage instance, going through the official calculation order once
+/// [`resolve`](Self::resolve)d:
+/// 
+/// 1. Damage type conversion (only affects physical damage)
+/// 2. Additive bonuses, including the one from the elemental reaction
+/// 3. Multipliers
+/// 4. Damage reduction statuses
+/// 5. Shields
+/// 
+/// # Example
+/// 
+/// ```
+/// # use genius_invokation::*;
+/// let mut aura = Aura::default();
+/// aura.apply(Element::Pyro);
+/// 
+/// let mut defenses = [Defense::shield(ModifierSource::Status("Crystallize"), 1)];
+/// 
+/// let breakdown = DamageCalculation::new(2, DamageKind::Physical)
+///     .convert(Element::Cryo, ModifierSource::Status("Cryo Infusion"))
+///     .bonus(ModifierSource::Card(WeaponCard::AquilaFavonia.into()), 1)
+///     .resolve(&mut aura, &mut defenses);
+/// 
+/// // 2 base + 1 from the weapon + 2 from Melt - 1 absorbed by the shield
+/// assert_eq!(breakdown.reaction(), Some(Reaction::Melt));
+/// assert_eq!(breakdown.dealt(), 4);
+/// assert_eq!(defenses[0].usages(), 0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DamageCalculation {
+    base: u8,
+    kind: DamageKind,
+    conversion: Option<(Element, ModifierSource)>,
+    bonuses: Vec<(ModifierSource, u8)>,
+    multipliers: Vec<(ModifierSource, u8)>,
+}
+
+impl DamageCalculation {
+    pub fn new(base: u8, kind: DamageKind) -> Self {
+        Self { base, kind, conversion: None, bonuses: Vec::new(), multipliers: Vec::new() }
+    }
+
+    /// Converts physical damage into elemental damage
+    /// 
+    /// Does nothing to elemental or piercing damage. If converted more than once, the first
+    /// conversion is the one that sticks.
+    pub fn convert(&mut self, element: Element, source: ModifierSource) -> &mut Self {
+        if self.conversion.is_none() {
+            self.conversion = Some((element, source));
+        }
+
+        self
+    }
+
+    /// Adds a flat damage bonus
+    pub fn bonus(&mut self, source: ModifierSource, amount: u8) -> &mut Self {
+        if amount > 0 {
+            self.bonuses.push((source, amount));
+        }
+
+        self
+    }
+
+    /// Multiplies the damage after every additive bonus has been applied
+    pub fn multiply(&mut self, source: ModifierSource, factor: u8) -> &mut Self {
+        self.multipliers.push((source, factor));
+        self
+    }
+
+    /// The damage type after conversions, which is what will be applied to the target
+    pub fn kind(&self) -> DamageKind {
+        match (self.kind, self.conversion) {
+            (DamageKind::Physical, Some((element, _))) => DamageKind::Elemental(element),
+            (kind, _) => kind,
+        }
+    }
+
+    /// Computes the final damage against a target, applying its element to `aura` and using up
+    /// `defenses`
+    pub fn resolve(&self, aura: &mut Aura, defenses: &mut [Defense]) -> DamageBreakdown {
+        let mut steps = vec![DamageStep::Base(self.base)];
+        let mut damage = self.base;
+
+        if self.kind == DamageKind::Piercing {
+            return DamageBreakdown { kind: self.kind, reaction: None, steps, dealt: damage }
+        }
+
+        let kind = self.kind();
+
+        if let (DamageKind::Physical, Some((element, source))) = (self.kind, self.conversion) {
+            steps.push(DamageStep::Converted { to: element, source });
+        }
+
+        for &(source, amount) in &self.bonuses {
+            damage = damage.saturating_add(amount);
+            steps.push(DamageStep::Bonus { source, amount });
+        }
+
+        let reaction = kind.element().and_then(|element| aura.apply(element));
+
+        if let Some(reaction) = reaction {
+            let amount = reaction.damage_bonus();
+
+            if amount > 0 {
+                damage = damage.saturating_add(amount);
+                steps.push(DamageStep::Bonus { source: ModifierSource::Reaction(reaction), amount });
+            }
+        }
+
+        for &(source, factor) in &self.multipliers {
+            damage = damage.saturating_mul(factor);
+            steps.push(DamageStep::Multiplied { source, factor });
+        }
+
+        for defense in defenses.iter_mut().filter(|def| def.usages > 0) {
+            if let DefenseKind::Reduction { amount, min } = defense.kind {
+                if damage > 0 && damage >= min {
+                    let amount = amount.min(damage);
+                    damage -= amount;
+                    defense.usages -= 1;
+                    steps.push(DamageStep::Reduced { source: defense.source, amount });
+                }
+            }
+        }
+
+        for defense in defenses.iter_mut().filter(|def| def.kind == DefenseKind::Shield) {
+            if damage > 0 && defense.usages > 0 {
+                let amount = defense.usages.min(damage);
+                damage -= amount;
+                defense.usages -= amount;
+                steps.push(DamageStep::Absorbed { source: defense.source, amount });
+            }
+        }
+
+        DamageBreakdown { kind, reaction, steps, dealt: damage }
+    }
+}
+
+/// A single step in a damage calculation
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum DamageStep {
+    Base(u8),
+    Converted { to: Element, source: ModifierSource },
+    Bonus { source: ModifierSource, amount: u8 },
+    Multiplied { source: ModifierSource, factor: u8 },
+    Reduced { source: ModifierSource, amount: u8 },
+    Absorbed { source: ModifierSource, amount: u8 },
+}
+
+impl fmt::Display for DamageStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base(amount) => write!(f, "{amount} base damage"),
+            Self::Converted { to, source } => write!(f, "converted to {to:?} by {}", source.name()),
+            Self::Bonus { source, amount } => write!(f, "+{amount} from {}", source.name()),
+            Self::Multiplied { source, factor } => write!(f, "x{factor} from {}", source.name()),
+            Self::Reduced { source, amount } => write!(f, "-{amount} reduced by {}", source.name()),
+            Self::Absorbed { source, amount } => write!(f, "-{amount} absorbed by {}", source.name()),
+        }
+    }
+}
+
+/// The itemized result of a [`DamageCalculation`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DamageBreakdown {
+    kind: DamageKind,
+    reaction: Option<Reaction>,
+    steps: Vec<DamageStep>,
+    dealt: u8,
+}
+
+impl DamageBreakdown {
+    /// Damage that never reached its target
+    pub(crate) fn missed(kind: DamageKind) -> Self {
+        Self { kind, reaction: None, steps: Vec::new(), dealt: 0 }
+    }
+
+    /// The final damage type, after conversions
+    pub fn kind(&self) -> DamageKind {
+        self.kind
+    }
+
+    /// The reaction this damage triggered, if any
+    pub fn reaction(&self) -> Option<Reaction> {
+        self.reaction
+    }
+
+    /// Every step that lead to the final damage, in the order they were applied
+    pub fn steps(&self) -> &[DamageStep] {
+        &self.steps
+    }
+
+    /// The damage actually dealt to the character's HP
+    pub fn dealt(&self) -> u8 {
+        self.dealt
+    }
+}
+
+impl fmt::Display for DamageBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "{step}")?;
+        }
+
+        write!(f, "= {} damage dealt", self.dealt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INFUSION: ModifierSource = ModifierSource::Status("Infusion");
+
+    #[test]
+    fn piercing_ignores_everything() {
+        let mut aura = Aura::default();
+        aura.apply(Element::Cryo);
+
+        let mut defenses = [
+            Defense::reduction(INFUSION, 1, 0, 1),
+            Defense::shield(INFUSION, 2),
+        ];
+
+        let breakdown = DamageCalculation::new(1, DamageKind::Piercing)
+            .bonus(INFUSION, 3)
+            .resolve(&mut aura, &mut defenses);
+
+        assert_eq!(breakdown.dealt(), 1);
+        assert_eq!(breakdown.reaction(), None);
+        assert!(aura.contains(Element::Cryo));
+        assert_eq!(defenses[0].usages(), 1);
+        assert_eq!(defenses[1].usages(), 2);
+    }
+
+    #[test]
+    fn conversion_only_affects_physical() {
+        let mut elemental = DamageCalculation::new(1, DamageKind::Elemental(Element::Pyro));
+        elemental.convert(Element::Cryo, INFUSION);
+
+        let mut physical = DamageCalculation::new(1, DamageKind::Physical);
+        physical.convert(Element::Cryo, INFUSION);
+
+        assert_eq!(elemental.kind(), DamageKind::Elemental(Element::Pyro));
+        assert_eq!(physical.kind(), DamageKind::Elemental(Element::Cryo));
+    }
+
+    #[test]
+    fn reductions_before_shields() {
+        let mut aura = Aura::default();
+        let mut defenses = [
+            Defense::shield(INFUSION, 1),
+            Defense::reduction(INFUSION, 1, 3, 2),
+        ];
+
+        let breakdown = DamageCalculation::new(3, DamageKind::Physical)
+            .multiply(INFUSION, 2)
+            .resolve(&mut aura, &mut defenses);
+
+        // (3 * 2) - 1 reduced - 1 absorbed
+        assert_eq!(breakdown.dealt(), 4);
+        assert_eq!(defenses[0].usages(), 0);
+        assert_eq!(defenses[1].usages(), 1);
+        assert!(matches!(breakdown.steps()[2], DamageStep::Reduced { amount: 1, .. }));
+
+        // reduction requires at least 3 damage
+        let breakdown = DamageCalculation::new(2, DamageKind::Physical)
+            .resolve(&mut aura, &mut defenses);
+
+        assert_eq!(breakdown.dealt(), 2);
+        assert_eq!(defenses[1].usages(), 1);
+    }
+
+    #[test]
+    fn cryo_dendro_coexist() {
+        let mut aura = Aura::default();
+
+        assert_eq!(aura.apply(Element::Dendro), None);
+        assert_eq!(aura.apply(Element::Cryo), None);
+        assert_eq!(aura.elements().collect::<Vec<_>>(), vec![Element::Cryo, Element::Dendro]);
+
+        // Cryo takes priority when reacting
+        assert_eq!(aura.apply(Element::Pyro), Some(Reaction::Melt));
+        assert_eq!(aura.apply(Element::Pyro), Some(Reaction::Burning));
+        assert!(aura.is_empty());
+
+        assert_eq!(aura.apply(Element::Anemo), None);
+        assert!(aura.is_empty());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::Element::{self, *};
+
+/// Elemental reactions triggered when an element is applied to a character that already has an
+/// element attached to it
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Reaction {
+    Melt,
+    Vaporize,
+    Overloaded,
+    Superconduct,
+    ElectroCharged,
+    Frozen,
+    /// Anemo reacting with the given element
+    Swirl(Element),
+    /// Geo reacting with the given element
+    Crystallize(Element),
+    Bloom,
+    Burning,
+    Quicken,
+}
+
+impl Reaction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Melt           => "Melt",
+            Self::Vaporize       => "Vaporize",
+            Self::Overloaded     => "Overloaded",
+            Self::Superconduct   => "Superconduct",
+            Self::ElectroCharged => "Electro-Charged",
+            Self::Frozen         => "Frozen",
+            Self::Swirl(_)       => "Swirl",
+            Self::Crystallize(_) => "Crystallize",
+            Self::Bloom          => "Bloom",
+            Self::Burning        => "Burning",
+            Self::Quicken        => "Quicken",
+        }
+    }
+
+    /// The extra damage this reaction adds to the damage that triggered it
+    /// 
+    /// Side effects (such as Superconduct's piercing damage or Crystallize's shield) are not
+    /// included, those are up to whoever resolves the reaction.
+    pub fn damage_bonus(&self) -> u8 {
+        match self {
+            Self::Melt | Self::Vaporize | Self::Overloaded => 2,
+            Self::Swirl(_) => 0,
+            _ => 1,
+        }
+    }
+
+    /// Which reaction happens when `applied` hits a character with `attached` on it, if any
+    pub fn between(applied: Element, attached: Element) -> Option<Reaction> {
+        let reaction = match (applied, attached) {
+            (Pyro, Cryo)    | (Cryo, Pyro)    => Self::Melt,
+            (Pyro, Hydro)   | (Hydro, Pyro)   => Self::Vaporize,
+            (Pyro, Electro) | (Electro, Pyro) => Self::Overloaded,
+            (Cryo, Electro) | (Electro, Cryo) => Self::Superconduct,
+            (Hydro, Electro) | (Electro, Hydro) => Self::ElectroCharged,
+            (Hydro, Cryo)   | (Cryo, Hydro)   => Self::Frozen,
+            (Dendro, Hydro) | (Hydro, Dendro) => Self::Bloom,
+            (Dendro, Pyro)  | (Pyro, Dendro)  => Self::Burning,
+            (Dendro, Electro) | (Electro, Dendro) => Self::Quicken,
+            (Anemo, Cryo | Hydro | Pyro | Electro) => Self::Swirl(attached),
+            (Geo, Cryo | Hydro | Pyro | Electro)   => Self::Crystallize(attached),
+            _ => return None,
+        };
+
+        Some(reaction)
+    }
+}
+
+/// The elements currently attached to a character
+/// 
+/// Anemo and Geo never stay attached, and the only two elements that can be attached at the same
+/// time are Cryo and Dendro.
+/// 
+/// # Example
+/// 
+/// ```
+/// # use genius_invokation::{Aura, Element, Reaction};
+/// let mut aura = Aura::default();
+/// 
+/// assert_eq!(aura.apply(Element::Hydro), None);
+/// assert_eq!(aura.apply(Element::Pyro), Some(Reaction::Vaporize));
+/// assert!(aura.is_empty());
+/// ```
+#[derive(Debug, Default, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aura {
+    first: Option<Element>,
+    second: Option<Element>,
+}
+
+impl Aura {
+    pub fn is_empty(&self) -> bool {
+        self.first.is_none()
+    }
+
+    pub fn contains(&self, element: Element) -> bool {
+        self.first == Some(element) || self.second == Some(element)
+    }
+
+    /// Iterates over the attached elements, Cryo first when Cryo and Dendro coexist, which is
+    /// also the order incoming elements react with them
+    pub fn elements(&self) -> impl Iterator<Item=Element> {
+        self.first.into_iter().chain(self.second)
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Applies an element to this aura, returning the reaction it triggered (if any)
+    /// 
+    /// The element consumed by the reaction is removed from the aura.
+    pub fn apply(&mut self, element: Element) -> Option<Reaction> {
+        if self.contains(element) {
+            return None
+        }
+
+        // when both Cryo and Dendro are attached, the incoming element reacts with Cryo first
+        for attached in self.elements() {
+            if let Some(reaction) = Reaction::between(element, attached) {
+                self.remove(attached);
+                return Some(reaction)
+            }
+        }
+
+        if matches!(element, Anemo | Geo) {
+            return None
+        }
+
+        match self.first {
+            None => self.first = Some(element),
+            Some(attached) => {
+                // no reaction with a single attached element means Cryo and Dendro coexisting
+                let (cryo, dendro) = if attached == Cryo { (attached, element) } else { (element, attached) };
+                self.first = Some(cryo);
+                self.second = Some(dendro);
+            }
+        }
+
+        None
+    }
+
+    fn remove(&mut self, element: Element) {
+        if self.second == Some(element) {
+            self.second = None;
+        } else if self.first == Some(element) {
+            self.first = self.second.take();
+        }
+    }
+}
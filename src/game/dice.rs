@@ -0,0 +1,258 @@
+use crate::{CardCost, Die, Element, SkillCost};
+
+/// A pool of elemental dice, such as the ones a player holds during a match
+/// 
+/// # Example
+/// 
+/// ```
+/// # use genius_invokation::{Dice, Die, Element::*};
+/// let mut dice = Dice::new();
+/// dice.add(Die::Omni, 2);
+/// dice.add(Die::Element(Pyro), 3);
+/// 
+/// assert_eq!(dice.total(), 5);
+/// assert_eq!(dice.count(Die::Element(Pyro)), 3);
+/// ```
+#[derive(Debug, Default, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dice {
+    counts: [u8; 8],
+}
+
+impl Dice {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(&self, die: Die) -> u8 {
+        self.counts[index(die)]
+    }
+
+    pub fn total(&self) -> u8 {
+        self.counts.iter().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+
+    pub fn add(&mut self, die: Die, amount: u8) {
+        self.counts[index(die)] = self.counts[index(die)].saturating_add(amount);
+    }
+
+    /// Removes `amount` dice of a kind, returning false (and removing nothing) if there aren't
+    /// enough of them
+    pub fn remove(&mut self, die: Die, amount: u8) -> bool {
+        let count = &mut self.counts[index(die)];
+
+        if *count < amount {
+            false
+        } else {
+            *count -= amount;
+            true
+        }
+    }
+
+    /// Whether every die in `other` is also in `self`
+    pub fn contains(&self, other: &Dice) -> bool {
+        self.counts.iter().zip(other.counts).all(|(have, need)| *have >= need)
+    }
+
+    /// Removes every die in `other` from this pool, returning false (and removing nothing) if
+    /// this pool doesn't contain all of them
+    pub fn remove_all(&mut self, other: &Dice) -> bool {
+        if !self.contains(other) {
+            return false
+        }
+
+        for (have, need) in self.counts.iter_mut().zip(other.counts) {
+            *have -= need;
+        }
+
+        true
+    }
+
+    pub fn add_all(&mut self, other: &Dice) {
+        for (have, more) in self.counts.iter_mut().zip(other.counts) {
+            *have = have.saturating_add(more);
+        }
+    }
+
+    /// Iterates over each kind of die in the pool alongside its amount, skipping the ones the
+    /// pool has none of. Omni dice come first, then elements in [`Element::ALL`] order
+    pub fn iter(&self) -> impl Iterator<Item=(Die, u8)> + '_ {
+        DICE_ORDER.iter().map(|die| (*die, self.count(*die))).filter(|(_, count)| *count > 0)
+    }
+
+    /// Iterates over every single die, one by one
+    pub fn iter_each(&self) -> impl Iterator<Item=Die> + '_ {
+        self.iter().flat_map(|(die, count)| std::iter::repeat_n(die, count as usize))
+    }
+
+    /// Chooses which dice would be used to pay for `cost`, if possible
+    /// 
+    /// Elements in `keep` are considered valuable (usually the elements of the player's
+    /// characters) so they're the last ones to be spent, with Omni dice only being used when
+    /// there's no other option.
+    pub fn payment(&self, cost: &CardCost, keep: &[Element]) -> Option<Dice> {
+        let mut payment = Dice::new();
+        let mut rest = *self;
+
+        if let Some((element, amount)) = cost.exact() {
+            let used = rest.count(Die::Element(element)).min(amount);
+
+            if used + rest.count(Die::Omni) < amount { return None }
+
+            payment.add(Die::Element(element), used);
+            payment.add(Die::Omni, amount - used);
+            rest.remove_all(&payment);
+        }
+
+        if cost.matching() > 0 {
+            let amount = cost.matching();
+            let omni = rest.count(Die::Omni);
+
+            // prefer a single element that can pay without Omni, the least valuable first
+            let best = Element::ALL.iter()
+                .map(|el| (*el, rest.count(Die::Element(*el))))
+                .filter(|(_, count)| *count > 0 && count + omni >= amount)
+                .min_by_key(|(el, count)| (*count < amount, keep.contains(el), *count));
+
+            let matching = match best {
+                Some((element, count)) => {
+                    let used = count.min(amount);
+                    Dice::from_iter(std::iter::repeat_n(Die::Element(element), used as usize)
+                        .chain(std::iter::repeat_n(Die::Omni, (amount - used) as usize)))
+                },
+                None if omni >= amount => Dice::from_iter(std::iter::repeat_n(Die::Omni, amount as usize)),
+                None => return None,
+            };
+
+            payment.add_all(&matching);
+            rest.remove_all(&matching);
+        }
+
+        if cost.unaligned() > 0 {
+            payment.add_all(&rest.unaligned(cost.unaligned(), keep)?);
+        }
+
+        Some(payment)
+    }
+
+    /// Same as [`payment`](Self::payment), but for the cost of a skill
+    pub fn skill_payment(&self, cost: &SkillCost, keep: &[Element]) -> Option<Dice> {
+        self.payment(&CardCost::from(*cost), keep)
+    }
+
+    /// Picks any `amount` dice, spending the least valuable ones first
+    fn unaligned(&self, amount: u8, keep: &[Element]) -> Option<Dice> {
+        if self.total() < amount { return None }
+
+        let mut elements: Vec<Element> = Element::ALL.to_vec();
+        elements.sort_by_key(|el| (keep.contains(el), self.count(Die::Element(*el))));
+
+        let mut payment = Dice::new();
+        let mut left = amount;
+
+        for element in elements {
+            let used = self.count(Die::Element(element)).min(left);
+            payment.add(Die::Element(element), used);
+            left -= used;
+        }
+
+        payment.add(Die::Omni, left);
+        Some(payment)
+    }
+}
+
+const DICE_ORDER: [Die; 8] = [
+    Die::Omni,
+    Die::Element(Element::Cryo), Die::Element(Element::Hydro),
+    Die::Element(Element::Pyro), Die::Element(Element::Electro),
+    Die::Element(Element::Anemo), Die::Element(Element::Geo),
+    Die::Element(Element::Dendro),
+];
+
+fn index(die: Die) -> usize {
+    match die {
+        Die::Omni => 0,
+        Die::Element(element) => 1 + Element::ALL.iter().position(|el| *el == element).unwrap(),
+    }
+}
+
+impl FromIterator<Die> for Dice {
+    fn from_iter<T: IntoIterator<Item=Die>>(iter: T) -> Self {
+        let mut dice = Dice::new();
+        iter.into_iter().for_each(|die| dice.add(die, 1));
+        dice
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::Element::*;
+
+    fn dice(list: &[(Die, u8)]) -> Dice {
+        let mut dice = Dice::new();
+        list.iter().for_each(|(die, amount)| dice.add(*die, *amount));
+        dice
+    }
+
+    #[test]
+    fn same_prefers_single_element() {
+        let pool = dice(&[(Die::Omni, 2), (Die::Element(Pyro), 1), (Die::Element(Cryo), 3)]);
+        let payment = pool.payment(&CardCost::MATCH3, &[]).unwrap();
+
+        assert_eq!(payment, dice(&[(Die::Element(Cryo), 3)]));
+
+        // Cryo is valuable, but spending Omni dice is even worse
+        let payment = pool.payment(&CardCost::MATCH3, &[Cryo]).unwrap();
+        assert_eq!(payment, dice(&[(Die::Element(Cryo), 3)]));
+
+        let pool = dice(&[(Die::Omni, 2), (Die::Element(Pyro), 1)]);
+        let payment = pool.payment(&CardCost::MATCH2, &[]).unwrap();
+        assert_eq!(payment, dice(&[(Die::Element(Pyro), 1), (Die::Omni, 1)]));
+    }
+
+    #[test]
+    fn not_enough_dice() {
+        let pool = dice(&[(Die::Element(Pyro), 1), (Die::Element(Cryo), 1)]);
+
+        assert_eq!(pool.payment(&CardCost::MATCH2, &[]), None);
+        assert_eq!(pool.payment(&CardCost::new(DiceCost::Exact(Hydro), 1, 0), &[]), None);
+        assert!(pool.payment(&CardCost::ANY2, &[]).is_some());
+    }
+
+    #[test]
+    fn mixed_payment() {
+        let pool = dice(&[(Die::Omni, 1), (Die::Element(Pyro), 2), (Die::Element(Geo), 2)]);
+        let cost = CardCost::ZERO.with_exact(Pyro, 1).with_matching(2).with_unaligned(1);
+
+        assert_eq!(pool.payment(&cost, &[Pyro]), Some(dice(&[(Die::Element(Pyro), 2), (Die::Element(Geo), 2)])));
+        assert_eq!(pool.payment(&cost.with_matching(3), &[]), Some(dice(&[(Die::Element(Pyro), 2), (Die::Element(Geo), 2), (Die::Omni, 1)])));
+        assert_eq!(pool.payment(&cost.with_matching(3).with_unaligned(2), &[]), None);
+    }
+
+    #[test]
+    fn any_keeps_valuable_dice() {
+        let pool = dice(&[(Die::Omni, 1), (Die::Element(Pyro), 2), (Die::Element(Geo), 1)]);
+        let payment = pool.payment(&CardCost::ANY2, &[Pyro]).unwrap();
+
+        assert_eq!(payment, dice(&[(Die::Element(Geo), 1), (Die::Element(Pyro), 1)]));
+    }
+
+    #[test]
+    fn skill_payment() {
+        let pool = dice(&[(Die::Omni, 1), (Die::Element(Pyro), 2), (Die::Element(Geo), 2)]);
+        let cost = SkillCost::new(Pyro, 3, 0, 0);
+
+        assert_eq!(pool.skill_payment(&cost, &[Pyro]), Some(dice(&[(Die::Element(Pyro), 2), (Die::Omni, 1)])));
+
+        let cost = SkillCost::new(Geo, 1, 2, 0);
+        assert_eq!(pool.skill_payment(&cost, &[Geo]), Some(dice(&[(Die::Element(Geo), 1), (Die::Element(Pyro), 2)])));
+
+        let cost = SkillCost::new(Geo, 4, 0, 0);
+        assert_eq!(pool.skill_payment(&cost, &[]), None);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::*;
+use super::character_targets;
+use super::support::{support_targets, place};
+use super::event::{food_targets, eat};
+
+/// The behaviour of a custom card registered without an effect, which only depends on its
+/// category
+pub(crate) fn placement(card: CustomCardId) -> &'static dyn CardEffect {
+    Box::leak(Box::new(Placement(card)))
+}
+
+/// Equipment and supports are placed like built-in ones, events do nothing and food only
+/// satiates
+struct Placement(CustomCardId);
+
+impl Effect for Placement {}
+
+impl CardEffect for Placement {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        match self.0.category() {
+            CustomCategory::Weapon(weapon) => character_targets(game, player, |ch| ch.card().weapon() == Some(weapon)),
+            CustomCategory::Artifact => character_targets(game, player, |_| true),
+            CustomCategory::Location | CustomCategory::Companion | CustomCategory::Item => support_targets(game, player),
+            CustomCategory::Event => vec![None],
+            CustomCategory::Food => food_targets(game, player),
+        }
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        match self.0.category() {
+            CustomCategory::Weapon(_) | CustomCategory::Artifact => {
+                if let Some(Target::Character(index)) = target {
+                    game.equip(player, index, self.0.into());
+                }
+            },
+            CustomCategory::Location | CustomCategory::Companion | CustomCategory::Item => place(game, player, target, self.0),
+            CustomCategory::Event => (),
+            CustomCategory::Food => { eat(game, player, target, None); },
+        }
+    }
+}
@@ -0,0 +1,812 @@
+use crate::*;
+use super::{holder_skill, character_targets};
+use crate::game::skill;
+
+pub(super) fn weapon(card: WeaponCard) -> &'static dyn CardEffect {
+    match card {
+        WeaponCard::MagicGuide            => &Weapon(WeaponCard::MagicGuide),
+        WeaponCard::RavenBow              => &Weapon(WeaponCard::RavenBow),
+        WeaponCard::WhiteIronGreatsword   => &Weapon(WeaponCard::WhiteIronGreatsword),
+        WeaponCard::WhiteTassel           => &Weapon(WeaponCard::WhiteTassel),
+        WeaponCard::TravelersHandySword   => &Weapon(WeaponCard::TravelersHandySword),
+        WeaponCard::SacrificialFragments  => &Sacrificial(WeaponCard::SacrificialFragments),
+        WeaponCard::SacrificialBow        => &Sacrificial(WeaponCard::SacrificialBow),
+        WeaponCard::SacrificialGreatsword => &Sacrificial(WeaponCard::SacrificialGreatsword),
+        WeaponCard::SacrificialSword      => &Sacrificial(WeaponCard::SacrificialSword),
+        WeaponCard::SkywardAtlas          => &SkywardAtlas,
+        WeaponCard::SkywardHarp           => &SkywardHarp,
+        WeaponCard::SkywardSpine          => &SkywardSpine,
+        WeaponCard::WolfsGravestone       => &WolfsGravestone,
+        WeaponCard::LithicSpear           => &LithicSpear,
+        WeaponCard::AquilaFavonia         => &AquilaFavonia,
+        WeaponCard::FruitOfFulfillment    => &FruitOfFulfillment,
+        WeaponCard::KingsSquire           => &KingsSquire,
+        WeaponCard::TheBell               => &TheBell,
+        WeaponCard::EngulfingLightning    => &EngulfingLightning,
+        WeaponCard::FavoniusSword         => &FavoniusSword,
+    }
+}
+
+pub(super) fn artifact(card: ArtifactCard) -> &'static dyn CardEffect {
+    use Element::*;
+
+    match card {
+        ArtifactCard::AdventurersBandana           => &AdventurersBandana,
+        ArtifactCard::LuckyDogsSilverCirclet       => &LuckyDogsSilverCirclet,
+        ArtifactCard::TravelingDoctorsHandkerchief => &TravelingDoctorsHandkerchief,
+        ArtifactCard::GamblersEarrings             => &GamblersEarrings,
+        ArtifactCard::InstructorsCap               => &InstructorsCap,
+        ArtifactCard::ExilesCirclet                => &ExilesCirclet,
+        ArtifactCard::BrokenRimesEcho              => &ElementalArtifact(ArtifactCard::BrokenRimesEcho, Cryo, false),
+        ArtifactCard::BlizzardStrayer              => &ElementalArtifact(ArtifactCard::BlizzardStrayer, Cryo, true),
+        ArtifactCard::WineStainedTricorne          => &ElementalArtifact(ArtifactCard::WineStainedTricorne, Hydro, false),
+        ArtifactCard::HeartOfDepth                 => &ElementalArtifact(ArtifactCard::HeartOfDepth, Hydro, true),
+        ArtifactCard::WitchsScorchingHat           => &ElementalArtifact(ArtifactCard::WitchsScorchingHat, Pyro, false),
+        ArtifactCard::CrimsonWitchOfFlames         => &ElementalArtifact(ArtifactCard::CrimsonWitchOfFlames, Pyro, true),
+        ArtifactCard::ThunderSummonersCrown        => &ElementalArtifact(ArtifactCard::ThunderSummonersCrown, Electro, false),
+        ArtifactCard::ThunderingFury               => &ElementalArtifact(ArtifactCard::ThunderingFury, Electro, true),
+        ArtifactCard::ViridescentVenerersDiadem    => &ElementalArtifact(ArtifactCard::ViridescentVenerersDiadem, Anemo, false),
+        ArtifactCard::ViridescentVenerer           => &ElementalArtifact(ArtifactCard::ViridescentVenerer, Anemo, true),
+        ArtifactCard::MaskOfSolitudeBasalt         => &ElementalArtifact(ArtifactCard::MaskOfSolitudeBasalt, Geo, false),
+        ArtifactCard::ArchaicPetra                 => &ElementalArtifact(ArtifactCard::ArchaicPetra, Geo, true),
+        ArtifactCard::LaurelCoronet                => &ElementalArtifact(ArtifactCard::LaurelCoronet, Dendro, false),
+        ArtifactCard::DeepwoodMemories             => &ElementalArtifact(ArtifactCard::DeepwoodMemories, Dendro, true),
+        ArtifactCard::OrnateKabuto                 => &OrnateKabuto,
+        ArtifactCard::EmblemOfSeveredFate          => &EmblemOfSeveredFate,
+    }
+}
+
+pub(super) fn talent(card: TalentCard) -> &'static dyn CardEffect {
+    // static promotion needs a constant expression, hence the lookup table
+    &TALENTS[card as usize]
+}
+
+macro_rules! talents {
+    ($($card:ident)+) => {
+        [$(Talent(TalentCard::$card),)+]
+    };
+}
+
+static TALENTS: [Talent; 32] = talents!(
+    KantenSenmyouBlessing SteadyBreathing ShakenNotPurred UndividedHeart ColdBloodedStrike
+    GloriousSeason ProphecyOfSubmersion TheScentRemained FeatherfallJudgment StellarPredator
+    ThunderingPenance Awakening StrategicReserve IGotYourBack FloralSidewinder LandsOfDandelion
+    ChaoticEntropy GrandExpectation FlowingFlame Crossfire NaganoharaMeteorSwarm MirrorCage
+    StreamingSurge StonehideReforged ProliferatingSpores TranscendentAutomaton PaidInFull
+    MysticalAbandon EndlessWaltz StalwartAndTrue TheShrinesSacredShade KeenSight
+);
+
+fn weapon_targets(game: &GameState, player: PlayerId, card: WeaponCard) -> Vec<Option<Target>> {
+    character_targets(game, player, |ch| ch.card().weapon().is_some() && ch.card().weapon() == card.subtype())
+}
+
+fn equip(game: &mut GameState, player: PlayerId, target: Option<Target>, card: impl Into<ActionCard>) {
+    if let Some(Target::Character(index)) = target {
+        game.equip(player, index, card.into());
+    }
+}
+
+/// Every weapon adds 1 damage to the skills of whoever holds it
+fn weapon_bonus(ctx: &Context, hook: &mut Hook, card: WeaponCard) {
+    if let Hook::OutgoingDamage { info, calc } = hook {
+        if holder_skill(ctx, info).is_some() {
+            calc.bonus(ModifierSource::Card(card.into()), 1);
+        }
+    }
+}
+
+/// Weapons with no effect other than +1 damage
+struct Weapon(WeaponCard);
+
+impl Effect for Weapon {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, self.0);
+    }
+}
+
+impl CardEffect for Weapon {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, self.0)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, self.0);
+    }
+}
+
+/// After using an elemental skill, creates a die of the holder's element, once per round
+struct Sacrificial(WeaponCard);
+
+impl Effect for Sacrificial {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, self.0);
+
+        if let Hook::AfterSkill { player, character, skill: SkillKind::ElementalSkill } = *hook {
+            if player == ctx.owner() && Some(character) == ctx.character() && ctx.can_use_this_round() {
+                let element = ctx.game().player(player).character(character).card().element();
+                ctx.game_mut().add_dice(player, Die::Element(element), 1);
+                ctx.use_this_round();
+            }
+        }
+    }
+}
+
+impl CardEffect for Sacrificial {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, self.0)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, self.0);
+    }
+}
+
+/// Normal attacks deal 1 additional damage, once per round
+struct SkywardAtlas;
+
+impl Effect for SkywardAtlas {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, WeaponCard::SkywardAtlas);
+
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            if holder_skill(ctx, info) == Some(SkillKind::NormalAttack) && ctx.can_use_this_round() {
+                calc.bonus(ModifierSource::Card(WeaponCard::SkywardAtlas.into()), 1);
+                ctx.use_this_round();
+            }
+        }
+    }
+}
+
+impl CardEffect for SkywardAtlas {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, WeaponCard::SkywardAtlas)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, WeaponCard::SkywardAtlas);
+    }
+}
+
+/// After a normal attack, deals 1 physical damage, once per round
+struct SkywardHarp;
+
+impl Effect for SkywardHarp {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, WeaponCard::SkywardHarp);
+
+        if let Hook::AfterSkill { player, character, skill: SkillKind::NormalAttack } = *hook {
+            if player == ctx.owner() && Some(character) == ctx.character() && ctx.can_use_this_round() {
+                ctx.use_this_round();
+
+                let opponent = player.opponent();
+                let target = ctx.game().player(opponent).active_index();
+                let source = DamageSource::Effect(EntityKind::Card(WeaponCard::SkywardHarp.into()));
+                let info = DamageInfo { attacker: player, source, target };
+                ctx.game_mut().deal_damage(info, 1, DamageKind::Physical);
+            }
+        }
+    }
+}
+
+impl CardEffect for SkywardHarp {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, WeaponCard::SkywardHarp)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, WeaponCard::SkywardHarp);
+    }
+}
+
+/// After a normal attack, the holder gains 1 energy, once per round
+struct SkywardSpine;
+
+impl Effect for SkywardSpine {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, WeaponCard::SkywardSpine);
+
+        if let Hook::AfterSkill { player, character, skill: SkillKind::NormalAttack } = *hook {
+            if player == ctx.owner() && Some(character) == ctx.character() && ctx.can_use_this_round() {
+                ctx.use_this_round();
+                ctx.game_mut().gain_energy(player, character, 1);
+            }
+        }
+    }
+}
+
+impl CardEffect for SkywardSpine {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, WeaponCard::SkywardSpine)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, WeaponCard::SkywardSpine);
+    }
+}
+
+/// Deals 2 additional damage to targets with 6 HP or less
+struct WolfsGravestone;
+
+impl Effect for WolfsGravestone {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, WeaponCard::WolfsGravestone);
+
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            let target = ctx.game().player(info.attacker.opponent()).character(info.target);
+
+            if holder_skill(ctx, info).is_some() && target.hp() <= 6 {
+                calc.bonus(ModifierSource::Card(WeaponCard::WolfsGravestone.into()), 2);
+            }
+        }
+    }
+}
+
+impl CardEffect for WolfsGravestone {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, WeaponCard::WolfsGravestone)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, WeaponCard::WolfsGravestone);
+    }
+}
+
+/// When played, shields the holder with 1 point for each Liyue character in the party
+struct LithicSpear;
+
+impl Effect for LithicSpear {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, WeaponCard::LithicSpear);
+    }
+}
+
+impl CardEffect for LithicSpear {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, WeaponCard::LithicSpear)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, WeaponCard::LithicSpear);
+
+        if let Some(Target::Character(index)) = target {
+            let liyue = game.player(player).characters().iter()
+                .filter(|ch| ch.card().faction() == Faction::Liyue)
+                .count() as u8;
+
+            if liyue > 0 {
+                let shield = game.add_character_status(player, index, EntityKind::Status(StatusKind::LithicSpear));
+                game.set_usages(shield, liyue.min(3));
+            }
+        }
+    }
+}
+
+/// Heals the holder after the opponent uses a skill, twice per round
+struct AquilaFavonia;
+
+impl Effect for AquilaFavonia {
+    fn per_round(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, WeaponCard::AquilaFavonia);
+
+        if let Hook::AfterSkill { player, .. } = *hook {
+            if player != ctx.owner() && ctx.is_active() && ctx.can_use_this_round() {
+                ctx.use_this_round();
+
+                let (owner, holder) = (ctx.owner(), ctx.character().unwrap());
+                ctx.game_mut().heal(owner, holder, 1);
+            }
+        }
+    }
+}
+
+impl CardEffect for AquilaFavonia {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, WeaponCard::AquilaFavonia)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, WeaponCard::AquilaFavonia);
+    }
+}
+
+/// Draws 2 cards when played
+struct FruitOfFulfillment;
+
+impl Effect for FruitOfFulfillment {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, WeaponCard::FruitOfFulfillment);
+    }
+}
+
+impl CardEffect for FruitOfFulfillment {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, WeaponCard::FruitOfFulfillment)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, WeaponCard::FruitOfFulfillment);
+        game.draw_cards(player, 2);
+    }
+}
+
+/// The first elemental skill the holder uses once it's equipped costs 2 less dice
+struct KingsSquire;
+
+impl Effect for KingsSquire {
+    fn counter(&self) -> u8 { 1 }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::SkillCost]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, WeaponCard::KingsSquire);
+
+        if let Hook::SkillCost { character, skill: SkillKind::ElementalSkill, cost, consumed, commit } = hook {
+            if Some(*character) == ctx.character() && ctx.entity().counter() > 0 && cost.dice() > 0 {
+                cost.discount_exact(2);
+                consumed.push(ctx.id());
+
+                if *commit {
+                    ctx.set_counter(0);
+                }
+            }
+        }
+    }
+}
+
+impl CardEffect for KingsSquire {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, WeaponCard::KingsSquire)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, WeaponCard::KingsSquire);
+    }
+}
+
+/// After the holder uses a skill, shields the party with a Rebellious Shield, once per round
+struct TheBell;
+
+impl Effect for TheBell {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, WeaponCard::TheBell);
+
+        if let Hook::AfterSkill { player, character, .. } = *hook {
+            if player == ctx.owner() && Some(character) == ctx.character() && ctx.can_use_this_round() {
+                ctx.use_this_round();
+                ctx.game_mut().add_combat_status(player, EntityKind::Status(StatusKind::RebelliousShield));
+            }
+        }
+    }
+}
+
+impl CardEffect for TheBell {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, WeaponCard::TheBell)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, WeaponCard::TheBell);
+    }
+}
+
+/// The holder gains 1 energy at the start of the Action Phase if it has none
+struct EngulfingLightning;
+
+impl Effect for EngulfingLightning {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::ActionPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, WeaponCard::EngulfingLightning);
+
+        if let (Hook::ActionPhase, Some(holder)) = (hook, ctx.character()) {
+            let owner = ctx.owner();
+            let character = ctx.game().player(owner).character(holder);
+
+            if character.is_alive() && character.energy() == 0 {
+                ctx.game_mut().gain_energy(owner, holder, 1);
+            }
+        }
+    }
+}
+
+impl CardEffect for EngulfingLightning {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, WeaponCard::EngulfingLightning)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, WeaponCard::EngulfingLightning);
+    }
+}
+
+/// After an elemental skill, the holder gains 1 more energy, once per round
+struct FavoniusSword;
+
+impl Effect for FavoniusSword {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        weapon_bonus(ctx, hook, WeaponCard::FavoniusSword);
+
+        after_own_skill(ctx, hook, SkillKind::ElementalSkill, |ctx, character| {
+            let owner = ctx.owner();
+            ctx.game_mut().gain_energy(owner, character, 1);
+        });
+    }
+}
+
+impl CardEffect for FavoniusSword {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        weapon_targets(game, player, WeaponCard::FavoniusSword)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, WeaponCard::FavoniusSword);
+    }
+}
+
+/// Implements the targeting and equipping shared by every artifact
+macro_rules! artifact_card {
+    ($name:ident) => {
+        impl CardEffect for $name {
+            fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+                character_targets(game, player, |_| true)
+            }
+
+            fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+                equip(game, player, target, ArtifactCard::$name);
+            }
+        }
+    };
+}
+
+/// Runs `effect` when the holder uses a skill of the given kind, if it can still be used this
+/// round
+fn after_own_skill(ctx: &mut Context, hook: &Hook, kind: SkillKind, effect: impl FnOnce(&mut Context, usize)) {
+    if let Hook::AfterSkill { player, character, skill } = *hook {
+        if player == ctx.owner() && Some(character) == ctx.character() && skill == kind && ctx.can_use_this_round() {
+            ctx.use_this_round();
+            effect(ctx, character);
+        }
+    }
+}
+
+/// Heals the holder for 1 HP after a normal attack, three times per round
+struct AdventurersBandana;
+
+impl Effect for AdventurersBandana {
+    fn per_round(&self) -> Option<u8> { Some(3) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        after_own_skill(ctx, hook, SkillKind::NormalAttack, |ctx, character| {
+            let owner = ctx.owner();
+            ctx.game_mut().heal(owner, character, 1);
+        });
+    }
+}
+
+artifact_card!(AdventurersBandana);
+
+/// Heals the holder for 2 HP after an elemental skill, once per round
+struct LuckyDogsSilverCirclet;
+
+impl Effect for LuckyDogsSilverCirclet {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        after_own_skill(ctx, hook, SkillKind::ElementalSkill, |ctx, character| {
+            let owner = ctx.owner();
+            ctx.game_mut().heal(owner, character, 2);
+        });
+    }
+}
+
+artifact_card!(LuckyDogsSilverCirclet);
+
+/// Heals every character for 1 HP after an elemental burst, once per round
+struct TravelingDoctorsHandkerchief;
+
+impl Effect for TravelingDoctorsHandkerchief {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        after_own_skill(ctx, hook, SkillKind::ElementalBurst, |ctx, _| {
+            let owner = ctx.owner();
+
+            for index in 0..ctx.game().player(owner).characters().len() {
+                ctx.game_mut().heal(owner, index, 1);
+            }
+        });
+    }
+}
+
+artifact_card!(TravelingDoctorsHandkerchief);
+
+/// Creates 2 Omni dice when an opposing character is defeated while the holder is active
+struct GamblersEarrings;
+
+impl Effect for GamblersEarrings {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::Defeated]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::Defeated { player, .. } = *hook {
+            if player != ctx.owner() && ctx.is_active() {
+                let owner = ctx.owner();
+                ctx.game_mut().add_dice(owner, Die::Omni, 2);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+artifact_card!(GamblersEarrings);
+
+/// Creates a die of the holder's element after their damage triggers a reaction, three times
+/// per round
+struct InstructorsCap;
+
+impl Effect for InstructorsCap {
+    fn per_round(&self) -> Option<u8> { Some(3) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterDamage { info, breakdown } = hook {
+            if holder_skill(ctx, info).is_some() && breakdown.reaction().is_some() && ctx.can_use_this_round() {
+                ctx.use_this_round();
+
+                let owner = ctx.owner();
+                let element = ctx.game().player(owner).character(ctx.character().unwrap()).card().element();
+                ctx.game_mut().add_dice(owner, Die::Element(element), 1);
+            }
+        }
+    }
+}
+
+artifact_card!(InstructorsCap);
+
+/// Every other character gains 1 energy after the holder uses an elemental burst, once per
+/// round
+struct ExilesCirclet;
+
+impl Effect for ExilesCirclet {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        after_own_skill(ctx, hook, SkillKind::ElementalBurst, |ctx, character| {
+            let owner = ctx.owner();
+
+            for index in 0..ctx.game().player(owner).characters().len() {
+                if index != character {
+                    ctx.game_mut().gain_energy(owner, index, 1);
+                }
+            }
+        });
+    }
+}
+
+artifact_card!(ExilesCirclet);
+
+/// The holder gains 1 energy after another character of the party uses an elemental burst
+fn other_burst_energy(ctx: &mut Context, hook: &Hook) {
+    if let Hook::AfterSkill { player, character, skill: SkillKind::ElementalBurst } = *hook {
+        let Some(holder) = ctx.character() else { return };
+
+        if player == ctx.owner() && character != holder {
+            ctx.game_mut().gain_energy(player, holder, 1);
+        }
+    }
+}
+
+/// The holder gains 1 energy after another character uses an elemental burst
+struct OrnateKabuto;
+
+impl Effect for OrnateKabuto {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        other_burst_energy(ctx, hook);
+    }
+}
+
+artifact_card!(OrnateKabuto);
+
+/// Same as the Ornate Kabuto, and the holder's elemental bursts deal 2 more damage, once per
+/// round
+struct EmblemOfSeveredFate;
+
+impl Effect for EmblemOfSeveredFate {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        other_burst_energy(ctx, hook);
+
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            if holder_skill(ctx, info) == Some(SkillKind::ElementalBurst) && ctx.can_use_this_round() {
+                calc.bonus(ModifierSource::Card(ArtifactCard::EmblemOfSeveredFate.into()), 2);
+                ctx.use_this_round();
+            }
+        }
+    }
+}
+
+artifact_card!(EmblemOfSeveredFate);
+
+/// Skills of the artifact's element cost one less die of that element once per round. The
+/// three-cost versions also guarantee two dice of that element during the Roll Phase
+struct ElementalArtifact(ArtifactCard, Element, bool);
+
+impl Effect for ElementalArtifact {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::SkillCost, Trigger::RollPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        let Self(_, element, rolls) = *self;
+
+        match hook {
+            Hook::SkillCost { character, cost, consumed, commit, .. } => {
+                let applies = Some(*character) == ctx.character()
+                    && cost.element() == element
+                    && cost.dice() > 0;
+
+                if applies && ctx.can_use_this_round() {
+                    cost.discount_exact(1);
+                    consumed.push(ctx.id());
+
+                    if *commit {
+                        ctx.use_this_round();
+                    }
+                }
+            },
+            Hook::RollPhase { fixed, .. } if rolls => {
+                fixed.extend([Die::Element(element); 2]);
+            },
+            _ => (),
+        }
+    }
+}
+
+impl CardEffect for ElementalArtifact {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        character_targets(game, player, |_| true)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, self.0);
+    }
+}
+
+/// Talents can only be equipped to their character while it's the active character, which
+/// then immediately uses the skill printed on the talent (if any)
+/// 
+/// What the talent actually does is mostly up to the character's skills, the few talents with
+/// an effect of their own delegate to it.
+struct Talent(TalentCard);
+
+impl Effect for Talent {
+    fn per_round(&self) -> Option<u8> {
+        skill::talent_effect(self.0).and_then(|effect| effect.per_round())
+    }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        skill::talent_effect(self.0).map(|effect| effect.triggers()).unwrap_or(&[])
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Some(effect) = skill::talent_effect(self.0) {
+            effect.on_trigger(ctx, hook);
+        }
+    }
+}
+
+impl CardEffect for Talent {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        let state = game.player(player);
+        let active = state.active();
+
+        if active.card() == self.0.character() && active.is_alive() && active.energy() >= self.0.cost().energy() {
+            vec![Some(Target::Character(state.active_index()))]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        equip(game, player, target, self.0);
+
+        if let Some(index) = self.0.skill() {
+            game.use_skill(player, index);
+        }
+    }
+}
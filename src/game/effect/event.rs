@@ -0,0 +1,1000 @@
+use crate::*;
+use super::{holder_skill, character_targets};
+
+pub(super) fn normal(card: NormalEventCard) -> &'static dyn CardEffect {
+    match card {
+        NormalEventCard::TossUp                    => &TossUp,
+        NormalEventCard::SendOff                   => &SendOff,
+        NormalEventCard::Starsigns                 => &Starsigns,
+        NormalEventCard::CalxsArts                 => &CalxsArts,
+        NormalEventCard::QuickKnit                 => &QuickKnit,
+        NormalEventCard::Strategize                => &Strategize,
+        NormalEventCard::LeaveItToMe               => &LeaveItToMe,
+        NormalEventCard::GuardiansOath             => &GuardiansOath,
+        NormalEventCard::ChangingShifts            => &ChangingShifts,
+        NormalEventCard::IHaventLostYet            => &IHaventLostYet,
+        NormalEventCard::AbyssalSummons            => &AbyssalSummons,
+        NormalEventCard::MasterOfWeaponry          => &MasterOfWeaponry,
+        NormalEventCard::WhenTheCraneReturned      => &WhenTheCraneReturned,
+        NormalEventCard::TheBestestTravelCompanion => &TheBestestTravelCompanion,
+        NormalEventCard::BlessingOfTheDivineRelicsInstallation => &BlessingOfTheDivineRelicsInstallation,
+        NormalEventCard::ThunderAndEternity        => &ThunderAndEternity,
+        NormalEventCard::HeavyStrike               => &HeavyStrike,
+    }
+}
+
+pub(super) fn food(card: FoodCard) -> &'static dyn CardEffect {
+    match card {
+        FoodCard::MondstadtHashBrown    => &HealingFood(2),
+        FoodCard::SweetMadame           => &HealingFood(1),
+        FoodCard::JueyunGuoba           => &JueyunGuoba,
+        FoodCard::AdeptusTemptation     => &AdeptusTemptation,
+        FoodCard::LotusFlowerCrisp      => &LotusFlowerCrisp,
+        FoodCard::NorthernSmokedChicken => &NorthernSmokedChicken,
+        FoodCard::MushroomPizza         => &MushroomPizza,
+        FoodCard::MintyMeatRolls        => &MintyMeatRolls,
+        FoodCard::SashimiPlatter        => &SashimiPlatter,
+        FoodCard::MatsutakeMeatRolls    => &MatsutakeMeatRolls,
+    }
+}
+
+pub(super) fn resonance(card: ElementalResonanceCard) -> &'static dyn CardEffect {
+    use ElementalResonanceCard::*;
+
+    match card {
+        WovenIce          => &Woven(Element::Cryo),
+        WovenWaters       => &Woven(Element::Hydro),
+        WovenFlames       => &Woven(Element::Pyro),
+        WovenThunder      => &Woven(Element::Electro),
+        WovenWinds        => &Woven(Element::Anemo),
+        WovenStone        => &Woven(Element::Geo),
+        WovenWeeds        => &Woven(Element::Dendro),
+        ShatteringIce     => &ShatteringIceCard,
+        SoothingWater     => &SoothingWaterCard,
+        FerventFlames     => &FerventFlamesCard,
+        HighVoltage       => &HighVoltageCard,
+        ImpetuousWinds    => &ImpetuousWindsCard,
+        EnduringRock      => &EnduringRockCard,
+        SprawlingGreenery => &SprawlingGreeneryCard,
+    }
+}
+
+pub(super) fn arcane(card: ArcaneLegendCard) -> &'static dyn CardEffect {
+    match card {
+        ArcaneLegendCard::AncientCourtyard  => &AncientCourtyard,
+        ArcaneLegendCard::CovenantOfRock    => &CovenantOfRock,
+        ArcaneLegendCard::JoyousCelebration => &JoyousCelebration,
+    }
+}
+
+/// Uses up one usage of a status that discounts a cost, if the cost is being paid for real
+fn consume_discount(ctx: &mut Context, consumed: &mut Vec<EntityId>, commit: bool) {
+    consumed.push(ctx.id());
+
+    if commit {
+        ctx.consume();
+    }
+}
+
+fn every_summon(game: &GameState, players: &[PlayerId]) -> Vec<Option<Target>> {
+    players.iter()
+        .flat_map(|player| (0..game.player(*player).summons().len()).map(|index| Some(Target::Summon(*player, index))))
+        .collect()
+}
+
+fn summon_id(game: &GameState, target: Option<Target>) -> Option<EntityId> {
+    match target {
+        Some(Target::Summon(player, index)) => game.player(player).summons().get(index).map(|summon| summon.id()),
+        _ => None,
+    }
+}
+
+/// Every pair of characters between which something can be moved
+fn transfer_targets(game: &GameState, player: PlayerId, can_move: impl Fn(&CharacterState, &CharacterState) -> bool) -> Vec<Option<Target>> {
+    let characters = game.player(player).characters();
+    let mut targets = Vec::new();
+
+    for (from, source) in characters.iter().enumerate() {
+        for (to, dest) in characters.iter().enumerate() {
+            if from != to && source.is_alive() && dest.is_alive() && can_move(source, dest) {
+                targets.push(Some(Target::Transfer(from, to)));
+            }
+        }
+    }
+
+    targets
+}
+
+/// Rerolls dice twice
+struct TossUp;
+
+impl Effect for TossUp {}
+
+impl CardEffect for TossUp {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        game.grant_rerolls(player, 2);
+    }
+}
+
+/// Removes 2 usages from a summon
+struct SendOff;
+
+impl Effect for SendOff {}
+
+impl CardEffect for SendOff {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        every_summon(game, &[player.opponent(), player])
+    }
+
+    fn play(&self, game: &mut GameState, _player: PlayerId, target: Option<Target>) {
+        if let Some(id) = summon_id(game, target) {
+            let usages = game.entity(id).and_then(|(_, summon)| summon.usages()).unwrap_or(0);
+            game.set_usages(id, usages.saturating_sub(2));
+        }
+    }
+}
+
+/// The active character gains 1 energy
+struct Starsigns;
+
+impl Effect for Starsigns {}
+
+impl CardEffect for Starsigns {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        let active = game.player(player).active();
+
+        if active.energy() < active.max_energy() { vec![None] } else { Vec::new() }
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        let active = game.player(player).active_index();
+        game.gain_energy(player, active, 1);
+    }
+}
+
+/// Moves 1 energy from each standby character to the active character
+struct CalxsArts;
+
+impl Effect for CalxsArts {}
+
+impl CardEffect for CalxsArts {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        let state = game.player(player);
+        let active = state.active();
+        let others_have_energy = state.characters().iter().enumerate()
+            .any(|(index, ch)| index != state.active_index() && ch.is_alive() && ch.energy() > 0);
+
+        if active.energy() < active.max_energy() && others_have_energy { vec![None] } else { Vec::new() }
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        let active = game.player(player).active_index();
+
+        for index in 0..game.player(player).characters().len() {
+            let ch = game.player(player).character(active);
+
+            if index != active && ch.energy() < ch.max_energy() && game.player(player).character(index).is_alive() {
+                let moved = game.lose_energy(player, index, 1);
+                game.gain_energy(player, active, moved);
+            }
+        }
+    }
+}
+
+/// Adds 1 usage to one of the player's summons
+struct QuickKnit;
+
+impl Effect for QuickKnit {}
+
+impl CardEffect for QuickKnit {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        every_summon(game, &[player])
+    }
+
+    fn play(&self, game: &mut GameState, _player: PlayerId, target: Option<Target>) {
+        if let Some(id) = summon_id(game, target) {
+            let usages = game.entity(id).and_then(|(_, summon)| summon.usages()).unwrap_or(0);
+            game.set_usages(id, usages + 1);
+        }
+    }
+}
+
+/// Draws 2 cards
+struct Strategize;
+
+impl Effect for Strategize {}
+
+impl CardEffect for Strategize {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        game.draw_cards(player, 2);
+    }
+}
+
+/// The next switch is a fast action
+struct LeaveItToMe;
+
+impl Effect for LeaveItToMe {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::Speed]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::Speed { action: CostAction::Switch { .. }, fast, consumed, commit, .. } = hook {
+            if !*fast {
+                *fast = true;
+                consume_discount(ctx, consumed, *commit);
+            }
+        }
+    }
+}
+
+impl CardEffect for LeaveItToMe {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        game.add_combat_status(player, EntityKind::Card(NormalEventCard::LeaveItToMe.into()));
+    }
+}
+
+/// Destroys every shield on both sides of the field
+struct GuardiansOath;
+
+impl Effect for GuardiansOath {}
+
+impl CardEffect for GuardiansOath {
+    fn play(&self, game: &mut GameState, _player: PlayerId, _target: Option<Target>) {
+        let shields: Vec<EntityId> = [PlayerId::First, PlayerId::Second].iter()
+            .flat_map(|player| game.player(*player).entities())
+            .filter(|entity| entity.kind().effect().defense() == Some(DefenseKind::Shield))
+            .map(|entity| entity.id())
+            .collect();
+
+        shields.into_iter().for_each(|id| game.remove_entity(id));
+    }
+}
+
+/// The next switch costs 1 less die
+struct ChangingShifts;
+
+impl Effect for ChangingShifts {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::Speed]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::Speed { action: CostAction::Switch { .. }, cost, consumed, commit, .. } = hook {
+            if cost.amount() > 0 {
+                cost.discount(1);
+                consume_discount(ctx, consumed, *commit);
+            }
+        }
+    }
+}
+
+impl CardEffect for ChangingShifts {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        game.add_combat_status(player, EntityKind::Card(NormalEventCard::ChangingShifts.into()));
+    }
+}
+
+/// Only playable after one of the player's characters was defeated this round. Creates an Omni
+/// die and gives the active character 1 energy
+struct IHaventLostYet;
+
+impl Effect for IHaventLostYet {}
+
+impl CardEffect for IHaventLostYet {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        if game.player(player).defeated_this_round() { vec![None] } else { Vec::new() }
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        let active = game.player(player).active_index();
+
+        game.add_dice(player, Die::Omni, 1);
+        game.gain_energy(player, active, 1);
+    }
+}
+
+/// Summons a random Hilichurl
+struct AbyssalSummons;
+
+impl Effect for AbyssalSummons {}
+
+impl CardEffect for AbyssalSummons {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        const HILICHURLS: [SummonKind; 4] = [
+            SummonKind::CryoHilichurlShooter, SummonKind::HydroSamachurl,
+            SummonKind::HilichurlBerserker, SummonKind::ElectroHilichurlShooter,
+        ];
+
+        let index = game.rng().below(HILICHURLS.len());
+        game.add_summon(player, HILICHURLS[index]);
+    }
+}
+
+/// Moves a weapon to another character who can wield it
+struct MasterOfWeaponry;
+
+impl Effect for MasterOfWeaponry {}
+
+impl CardEffect for MasterOfWeaponry {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        transfer_targets(game, player, |from, to| {
+            from.weapon().is_some() && from.card().weapon() == to.card().weapon()
+        })
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        if let Some(Target::Transfer(from, to)) = target {
+            game.transfer_equipment(player, from, to, |card| card.weapon().is_some());
+        }
+    }
+}
+
+/// Moves an artifact to another character
+struct BlessingOfTheDivineRelicsInstallation;
+
+impl Effect for BlessingOfTheDivineRelicsInstallation {}
+
+impl CardEffect for BlessingOfTheDivineRelicsInstallation {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        transfer_targets(game, player, |from, _| from.artifact().is_some())
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        if let Some(Target::Transfer(from, to)) = target {
+            game.transfer_equipment(player, from, to, |card| card.artifact().is_some());
+        }
+    }
+}
+
+/// After the next skill, switches to the next character
+struct WhenTheCraneReturned;
+
+impl Effect for WhenTheCraneReturned {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterSkill { player, .. } = *hook {
+            if player == ctx.owner() {
+                ctx.consume();
+
+                if let Some(next) = ctx.game().next_character(player) {
+                    ctx.game_mut().switch_active(player, next);
+                }
+            }
+        }
+    }
+}
+
+impl CardEffect for WhenTheCraneReturned {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        game.add_combat_status(player, EntityKind::Card(NormalEventCard::WhenTheCraneReturned.into()));
+    }
+}
+
+/// Turns the 2 dice used to play it into Omni dice
+struct TheBestestTravelCompanion;
+
+impl Effect for TheBestestTravelCompanion {}
+
+impl CardEffect for TheBestestTravelCompanion {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        game.add_dice(player, Die::Omni, 2);
+    }
+}
+
+/// Converts every die other than Omni dice to the active character's element
+struct ThunderAndEternity;
+
+impl Effect for ThunderAndEternity {}
+
+impl CardEffect for ThunderAndEternity {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        let element = game.player(player).active().card().element();
+        let dice: Vec<(Die, u8)> = game.player(player).dice().iter()
+            .filter(|(die, _)| *die != Die::Omni && *die != Die::Element(element))
+            .collect();
+
+        for (die, amount) in dice {
+            game.player_mut(player).dice.remove(die, amount);
+            game.add_dice(player, Die::Element(element), amount);
+        }
+    }
+}
+
+/// The active character's next normal attack this round deals 1 more damage
+struct HeavyStrike;
+
+impl Effect for HeavyStrike {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            if info.attacker == ctx.owner() && matches!(info.source, DamageSource::Skill { skill: SkillKind::NormalAttack, .. }) {
+                calc.bonus(ModifierSource::Card(NormalEventCard::HeavyStrike.into()), 1);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+impl CardEffect for HeavyStrike {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        game.add_combat_status(player, EntityKind::Card(NormalEventCard::HeavyStrike.into()));
+    }
+}
+
+/// Food can only be eaten by characters that aren't satiated yet
+pub(super) fn food_targets(game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+    character_targets(game, player, |ch| !ch.has_status(EntityKind::Status(StatusKind::Satiated)))
+}
+
+/// Satiates the target, optionally giving it a status named after the food
+pub(super) fn eat(game: &mut GameState, player: PlayerId, target: Option<Target>, status: Option<FoodCard>) -> Option<usize> {
+    let Some(Target::Character(index)) = target else { return None };
+
+    if let Some(food) = status {
+        game.add_character_status(player, index, EntityKind::Card(food.into()));
+    }
+
+    game.add_character_status(player, index, EntityKind::Status(StatusKind::Satiated));
+    Some(index)
+}
+
+/// Food that only heals
+struct HealingFood(u8);
+
+impl Effect for HealingFood {}
+
+impl CardEffect for HealingFood {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        food_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        if let Some(index) = eat(game, player, target, None) {
+            game.heal(player, index, self.0);
+        }
+    }
+}
+
+/// The character's normal attacks deal 1 more damage this round, three times
+struct JueyunGuoba;
+
+impl Effect for JueyunGuoba {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            if holder_skill(ctx, info) == Some(SkillKind::NormalAttack) {
+                calc.bonus(ModifierSource::Card(FoodCard::JueyunGuoba.into()), 1);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+impl CardEffect for JueyunGuoba {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        food_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        eat(game, player, target, Some(FoodCard::JueyunGuoba));
+    }
+}
+
+/// The character's next elemental burst this round deals 3 more damage
+struct AdeptusTemptation;
+
+impl Effect for AdeptusTemptation {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            if holder_skill(ctx, info) == Some(SkillKind::ElementalBurst) {
+                calc.bonus(ModifierSource::Card(FoodCard::AdeptusTemptation.into()), 3);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+impl CardEffect for AdeptusTemptation {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        food_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        eat(game, player, target, Some(FoodCard::AdeptusTemptation));
+    }
+}
+
+/// The next damage the character takes this round is reduced by 3
+struct LotusFlowerCrisp;
+
+impl Effect for LotusFlowerCrisp {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Reduction { amount: 3, min: 1 })
+    }
+}
+
+impl CardEffect for LotusFlowerCrisp {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        food_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        eat(game, player, target, Some(FoodCard::LotusFlowerCrisp));
+    }
+}
+
+/// Normal attacks of the holder cost one less unaligned die
+fn normal_attack_discount(ctx: &mut Context, hook: &mut Hook) {
+    if let Hook::SkillCost { character, skill: SkillKind::NormalAttack, cost, consumed, commit } = hook {
+        if Some(*character) == ctx.character() && cost.unaligned() > 0 {
+            cost.discount_unaligned(1);
+            consume_discount(ctx, consumed, *commit);
+        }
+    }
+}
+
+/// The character's next normal attack this round costs 1 less unaligned die
+struct NorthernSmokedChicken;
+
+impl Effect for NorthernSmokedChicken {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::SkillCost]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        normal_attack_discount(ctx, hook);
+    }
+}
+
+impl CardEffect for NorthernSmokedChicken {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        food_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        eat(game, player, target, Some(FoodCard::NorthernSmokedChicken));
+    }
+}
+
+/// The character's normal attacks cost 1 less unaligned die this round, three times
+struct MintyMeatRolls;
+
+impl Effect for MintyMeatRolls {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::SkillCost]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        normal_attack_discount(ctx, hook);
+    }
+}
+
+impl CardEffect for MintyMeatRolls {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        food_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        eat(game, player, target, Some(FoodCard::MintyMeatRolls));
+    }
+}
+
+/// Heals the character for 1 HP, then 1 more HP in each of the next two End Phases
+struct MushroomPizza;
+
+impl Effect for MushroomPizza {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        if let Some(index) = ctx.character() {
+            let owner = ctx.owner();
+            ctx.game_mut().heal(owner, index, 1);
+            ctx.consume();
+        }
+    }
+}
+
+impl CardEffect for MushroomPizza {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        food_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        if let Some(index) = eat(game, player, target, Some(FoodCard::MushroomPizza)) {
+            game.heal(player, index, 1);
+        }
+    }
+}
+
+/// The character's normal attacks deal 1 more damage this round
+struct SashimiPlatter;
+
+impl Effect for SashimiPlatter {
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            if holder_skill(ctx, info) == Some(SkillKind::NormalAttack) {
+                calc.bonus(ModifierSource::Card(FoodCard::SashimiPlatter.into()), 1);
+            }
+        }
+    }
+}
+
+impl CardEffect for SashimiPlatter {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        food_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        eat(game, player, target, Some(FoodCard::SashimiPlatter));
+    }
+}
+
+/// Heals the character for 2 HP, then 1 more HP in each of the next three End Phases
+struct MatsutakeMeatRolls;
+
+impl Effect for MatsutakeMeatRolls {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        if let Some(index) = ctx.character() {
+            let owner = ctx.owner();
+            ctx.game_mut().heal(owner, index, 1);
+            ctx.consume();
+        }
+    }
+}
+
+impl CardEffect for MatsutakeMeatRolls {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        food_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        if let Some(index) = eat(game, player, target, Some(FoodCard::MatsutakeMeatRolls)) {
+            game.heal(player, index, 2);
+        }
+    }
+}
+
+/// Creates a die of the given element
+struct Woven(Element);
+
+impl Effect for Woven {}
+
+impl CardEffect for Woven {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        game.add_dice(player, Die::Element(self.0), 1);
+    }
+}
+
+/// The active character's next damage this round is increased by 2
+struct ShatteringIceCard;
+
+impl Effect for ShatteringIceCard {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            if holder_skill(ctx, info).is_some() {
+                calc.bonus(ModifierSource::Card(ElementalResonanceCard::ShatteringIce.into()), 2);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+impl CardEffect for ShatteringIceCard {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        let active = game.player(player).active_index();
+        game.add_character_status(player, active, EntityKind::Card(ElementalResonanceCard::ShatteringIce.into()));
+    }
+}
+
+/// Heals the active character for 2 HP and the others for 1 HP
+struct SoothingWaterCard;
+
+impl Effect for SoothingWaterCard {}
+
+impl CardEffect for SoothingWaterCard {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        let active = game.player(player).active_index();
+
+        for index in 0..game.player(player).characters().len() {
+            game.heal(player, index, if index == active { 2 } else { 1 });
+        }
+    }
+}
+
+/// The active character's next Pyro-related reaction this round deals 3 more damage
+struct FerventFlamesCard;
+
+impl Effect for FerventFlamesCard {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            let reaction = ctx.game().predict_reaction(info, calc.kind());
+            let pyro_related = matches!(reaction, Some(
+                Reaction::Melt | Reaction::Vaporize | Reaction::Overloaded | Reaction::Burning
+                | Reaction::Swirl(Element::Pyro) | Reaction::Crystallize(Element::Pyro)
+            ));
+
+            if holder_skill(ctx, info).is_some() && pyro_related {
+                calc.bonus(ModifierSource::Card(ElementalResonanceCard::FerventFlames.into()), 3);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+impl CardEffect for FerventFlamesCard {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        let active = game.player(player).active_index();
+        game.add_character_status(player, active, EntityKind::Card(ElementalResonanceCard::FerventFlames.into()));
+    }
+}
+
+/// One character without full energy gains 1 energy, the active character having priority
+struct HighVoltageCard;
+
+impl Effect for HighVoltageCard {}
+
+impl CardEffect for HighVoltageCard {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        if game.missing_energy(player).is_some() { vec![None] } else { Vec::new() }
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        if let Some(index) = game.missing_energy(player) {
+            game.gain_energy(player, index, 1);
+        }
+    }
+}
+
+/// Switches to the target character and creates an Omni die
+struct ImpetuousWindsCard;
+
+impl Effect for ImpetuousWindsCard {}
+
+impl CardEffect for ImpetuousWindsCard {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        let active = game.player(player).active_index();
+
+        character_targets(game, player, |ch| ch.card() != game.player(player).character(active).card())
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        if let Some(Target::Character(index)) = target {
+            game.switch_active(player, index);
+        }
+
+        game.add_dice(player, Die::Omni, 1);
+    }
+}
+
+/// After a character deals Geo damage this round, adds 3 points to a shield on the player's
+/// side of the field
+struct EnduringRockCard;
+
+impl Effect for EnduringRockCard {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterDamage { info, breakdown } = hook {
+            let own_skill = info.attacker == ctx.owner() && matches!(info.source, DamageSource::Skill { .. });
+
+            if own_skill && breakdown.kind() == DamageKind::Elemental(Element::Geo) {
+                let shield = ctx.game().player(ctx.owner()).combat_statuses().iter()
+                    .find(|status| status.kind().effect().defense() == Some(DefenseKind::Shield))
+                    .map(|status| (status.id(), status.usages().unwrap_or(0)));
+
+                if let Some((id, usages)) = shield {
+                    ctx.game_mut().set_usages(id, usages + 3);
+                    ctx.consume();
+                }
+            }
+        }
+    }
+}
+
+impl CardEffect for EnduringRockCard {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        game.add_combat_status(player, EntityKind::Card(ElementalResonanceCard::EnduringRock.into()));
+    }
+}
+
+/// The next elemental reaction this round deals 2 more damage
+struct SprawlingGreeneryCard;
+
+impl Effect for SprawlingGreeneryCard {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            if ctx.game().predict_reaction(info, calc.kind()).is_some() {
+                calc.bonus(ModifierSource::Card(ElementalResonanceCard::SprawlingGreenery.into()), 2);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+impl CardEffect for SprawlingGreeneryCard {
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        game.add_combat_status(player, EntityKind::Card(ElementalResonanceCard::SprawlingGreenery.into()));
+    }
+}
+
+/// Only playable while one of the player's characters has a weapon or an artifact. The next
+/// weapon or artifact card played this round costs 2 less dice
+struct AncientCourtyard;
+
+impl Effect for AncientCourtyard {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::CardCost]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::CardCost { card, cost, consumed, commit } = hook {
+            if (card.weapon().is_some() || card.artifact().is_some()) && cost.amount() > 0 {
+                cost.discount(2);
+                consume_discount(ctx, consumed, *commit);
+            }
+        }
+    }
+}
+
+impl CardEffect for AncientCourtyard {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        let equipped = game.player(player).characters().iter()
+            .any(|character| character.weapon().is_some() || character.artifact().is_some());
+
+        if equipped { vec![None] } else { Vec::new() }
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        game.add_combat_status(player, EntityKind::Card(ArcaneLegendCard::AncientCourtyard.into()));
+    }
+}
+
+/// Only playable without any dice left. Creates 2 dice of different random elements
+struct CovenantOfRock;
+
+impl Effect for CovenantOfRock {}
+
+impl CardEffect for CovenantOfRock {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        if game.player(player).dice().total() == 0 { vec![None] } else { Vec::new() }
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        let first = game.rng().below(Element::ALL.len());
+        let mut second = game.rng().below(Element::ALL.len() - 1);
+
+        if second >= first {
+            second += 1;
+        }
+
+        game.add_dice(player, Die::Element(Element::ALL[first]), 1);
+        game.add_dice(player, Die::Element(Element::ALL[second]), 1);
+    }
+}
+
+/// Only playable while the active character is Cryo, Hydro, Pyro, Electro or Dendro. Applies
+/// its element to every character of the player
+struct JoyousCelebration;
+
+impl JoyousCelebration {
+    fn element(game: &GameState, player: PlayerId) -> Option<Element> {
+        let element = game.player(player).active().card().element();
+
+        (element != Element::Anemo && element != Element::Geo).then_some(element)
+    }
+}
+
+impl Effect for JoyousCelebration {}
+
+impl CardEffect for JoyousCelebration {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        match Self::element(game, player) {
+            Some(_) => vec![None],
+            None => Vec::new(),
+        }
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, _target: Option<Target>) {
+        let Some(element) = Self::element(game, player) else { return };
+
+        for index in 0..game.player(player).characters().len() {
+            if game.player(player).character(index).is_alive() {
+                game.apply_element(player, index, element);
+            }
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use crate::*;
+
+mod equipment;
+mod support;
+mod event;
+mod status;
+mod summon;
+
+#[cfg(feature = "custom-cards")]
+pub(crate) use custom::placement as custom_placement;
+#[cfg(feature = "custom-cards")]
+mod custom;
+
+/// Moments during a match where effects on the field get a chance to act
+/// 
+/// Each variant matches a [`Hook`] variant of the same name.
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    RollPhase,
+    ActionPhase,
+    EndPhase,
+    CardCost,
+    SkillCost,
+    Speed,
+    OutgoingDamage,
+    IncomingDamage,
+    AfterDamage,
+    AfterSkill,
+    AfterSwitch,
+    AfterCardPlayed,
+    Defeated,
+}
+
+/// Actions that have to be paid for with dice, alongside whatever they act on
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum CostAction {
+    PlayCard(ActionCard),
+    Skill { character: usize, skill: SkillKind },
+    Switch { from: usize, to: usize },
+}
+
+/// Whatever dealt a damage instance
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DamageSource {
+    /// A character using one of its skills
+    Skill { character: usize, skill: SkillKind },
+    Summon(SummonKind),
+    /// Any other effect on the field, such as an equipped weapon
+    Effect(EntityKind),
+    /// Side effects of elemental reactions, such as Superconduct's piercing damage
+    Reaction(Reaction),
+}
+
+/// Who dealt damage to whom
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DamageInfo {
+    pub attacker: PlayerId,
+    pub source: DamageSource,
+    /// The character being hit, which belongs to the attacker's opponent
+    pub target: usize,
+}
+
+/// Data passed to effects when a [`Trigger`] happens
+/// 
+/// Hooks that can be changed by effects (costs, damage) are read back by the engine once every
+/// effect has seen them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hook {
+    /// Before the player's dice are rolled. Dice in `fixed` are guaranteed to come out
+    RollPhase { fixed: Vec<Die>, rerolls: u8 },
+    ActionPhase,
+    EndPhase,
+    /// The dice cost of an action card. Effects that discount it must add their id to
+    /// `consumed`, and only update their own usages if `commit` is set, since costs are also
+    /// computed when merely listing which actions are available. Without `commit`, anything an
+    /// effect changes through [`Context::game_mut`] is thrown away
+    CardCost { card: ActionCard, cost: CardCost, consumed: Vec<EntityId>, commit: bool },
+    /// Same as [`CardCost`](Self::CardCost), but for a character's skill
+    SkillCost { character: usize, skill: SkillKind, cost: SkillCost, consumed: Vec<EntityId>, commit: bool },
+    /// Whether an action is a fast action, which doesn't end the player's turn. Also used for
+    /// the cost of switching characters
+    Speed { action: CostAction, cost: CardCost, fast: bool, consumed: Vec<EntityId>, commit: bool },
+    /// Damage about to be dealt, which effects from the attacker's side may modify
+    OutgoingDamage { info: DamageInfo, calc: DamageCalculation },
+    /// Same as [`OutgoingDamage`](Self::OutgoingDamage), for effects on the defender's side.
+    /// Shields and damage reductions aren't applied here, see [`Effect::defense`]
+    IncomingDamage { info: DamageInfo, calc: DamageCalculation },
+    AfterDamage { info: DamageInfo, breakdown: DamageBreakdown },
+    AfterSkill { player: PlayerId, character: usize, skill: SkillKind },
+    AfterSwitch { player: PlayerId, from: usize, to: usize },
+    AfterCardPlayed { player: PlayerId, card: ActionCard },
+    /// A character was defeated
+    Defeated { player: PlayerId, character: usize },
+}
+
+impl Hook {
+    pub fn trigger(&self) -> Trigger {
+        match self {
+            Self::RollPhase { .. }       => Trigger::RollPhase,
+            Self::ActionPhase            => Trigger::ActionPhase,
+            Self::EndPhase               => Trigger::EndPhase,
+            Self::CardCost { .. }        => Trigger::CardCost,
+            Self::SkillCost { .. }       => Trigger::SkillCost,
+            Self::Speed { .. }           => Trigger::Speed,
+            Self::OutgoingDamage { .. }  => Trigger::OutgoingDamage,
+            Self::IncomingDamage { .. }  => Trigger::IncomingDamage,
+            Self::AfterDamage { .. }     => Trigger::AfterDamage,
+            Self::AfterSkill { .. }      => Trigger::AfterSkill,
+            Self::AfterSwitch { .. }     => Trigger::AfterSwitch,
+            Self::AfterCardPlayed { .. } => Trigger::AfterCardPlayed,
+            Self::Defeated { .. }        => Trigger::Defeated,
+        }
+    }
+}
+
+/// What an action card is aimed at when played
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Target {
+    /// One of the player's own characters, by position
+    Character(usize),
+    /// A summon on either side of the field, by position
+    Summon(PlayerId, usize),
+    /// One of the player's supports, to be replaced when the support zone is full
+    Support(usize),
+    /// Moving something from one of the player's characters to another
+    Transfer(usize, usize),
+}
+
+/// The behaviour of anything that stays on the field
+/// 
+/// Every entity kind has a single static implementation of this trait, while the state that
+/// changes during a match (usages, counters, duration) lives in its [`Entity`].
+pub trait Effect: Sync {
+    /// Usages this effect starts with, or `None` if it isn't limited by usages
+    /// 
+    /// Entities are discarded once their usages reach zero.
+    fn usages(&self) -> Option<u8> { None }
+
+    /// How many times this effect can trigger each round, if limited
+    fn per_round(&self) -> Option<u8> { None }
+
+    /// Starting value of the entity's [`counter`](Entity::counter)
+    fn counter(&self) -> u8 { 0 }
+
+    fn duration(&self) -> Duration { Duration::Permanent }
+
+    /// Which triggers [`on_trigger`](Self::on_trigger) should be called for
+    fn triggers(&self) -> &'static [Trigger] { &[] }
+
+    fn on_trigger(&self, _ctx: &mut Context, _hook: &mut Hook) {}
+
+    /// The protection this effect gives against incoming damage, if any
+    fn defense(&self) -> Option<DefenseKind> { None }
+
+    /// What happens when another entity of the same kind is created on the same spot
+    fn stacking(&self) -> Stacking { Stacking::Refresh }
+
+    /// Whether the entity is discarded as soon as its usages run out, which a few summons
+    /// delay until they had their last say during the End Phase
+    fn discard_when_spent(&self) -> bool { true }
+}
+
+/// The behaviour of an action card when played
+/// 
+/// Cards that stay on the field afterwards (as a support, as equipment or as a status named
+/// after themselves) use the [`Effect`] half of this trait for that.
+pub trait CardEffect: Effect {
+    /// Every valid target for this card right now, `None` meaning it doesn't need one
+    /// 
+    /// An empty list means the card can't be played at all.
+    fn targets(&self, _game: &GameState, _player: PlayerId) -> Vec<Option<Target>> {
+        vec![None]
+    }
+
+    /// Applies the card. Its cost has already been paid at this point
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>);
+}
+
+impl ActionCard {
+    /// The executable behaviour of this card
+    pub fn effect(&self) -> &'static dyn CardEffect {
+        match self {
+            Self::Equipment(EquipmentCard::Weapon(card))   => equipment::weapon(*card),
+            Self::Equipment(EquipmentCard::Artifact(card)) => equipment::artifact(*card),
+            Self::Equipment(EquipmentCard::Talent(card))   => equipment::talent(*card),
+            Self::Support(SupportCard::Companion(card))    => support::companion(*card),
+            Self::Support(SupportCard::Location(card))     => support::location(*card),
+            Self::Support(SupportCard::Item(card))         => support::item(*card),
+            Self::Event(EventCard::Normal(card))           => event::normal(*card),
+            Self::Event(EventCard::Food(card))             => event::food(*card),
+            Self::Event(EventCard::Resonance(card))        => event::resonance(*card),
+            Self::Event(EventCard::Arcane(card))           => event::arcane(*card),
+            #[cfg(feature = "custom-cards")]
+            Self::Custom(card)                             => card.effect(),
+        }
+    }
+}
+
+impl EntityKind {
+    /// The behaviour of entities of this kind
+    pub fn effect(&self) -> &'static dyn Effect {
+        match self {
+            Self::Card(card)   => card.effect(),
+            Self::Status(kind) => status::effect(*kind),
+            Self::Summon(kind) => summon::effect(*kind),
+        }
+    }
+}
+
+/// Handle given to an [`Effect`] when it's triggered
+pub struct Context<'g> {
+    game: Access<'g>,
+    owner: PlayerId,
+    id: EntityId,
+}
+
+/// How a [`Context`] holds the game: exclusively when effects trigger, or shared while a cost
+/// is only being previewed, until an effect asks to change it anyway and gets its own copy
+enum Access<'g> {
+    Exclusive(&'g mut GameState),
+    Shared(&'g GameState),
+    Copied(Box<GameState>),
+}
+
+impl<'g> Context<'g> {
+    pub(crate) fn new(game: &'g mut GameState, owner: PlayerId, id: EntityId) -> Self {
+        Self { game: Access::Exclusive(game), owner, id }
+    }
+
+    /// A context for a cost hook that isn't committed, which can't change the game
+    pub(crate) fn preview(game: &'g GameState, owner: PlayerId, id: EntityId) -> Self {
+        Self { game: Access::Shared(game), owner, id }
+    }
+
+    pub fn game(&self) -> &GameState {
+        match &self.game {
+            Access::Exclusive(game) => game,
+            Access::Shared(game) => game,
+            Access::Copied(game) => game,
+        }
+    }
+
+    /// For cost hooks that aren't committed, this is a copy of the game that's thrown away once
+    /// the effect returns, so effects that don't check `commit` can't change the real one
+    pub fn game_mut(&mut self) -> &mut GameState {
+        if let Access::Shared(game) = self.game {
+            self.game = Access::Copied(Box::new(game.clone()));
+        }
+
+        match &mut self.game {
+            Access::Exclusive(game) => game,
+            Access::Copied(game) => game,
+            Access::Shared(_) => unreachable!(),
+        }
+    }
+
+    /// The player whose side of the field this entity is on
+    pub fn owner(&self) -> PlayerId {
+        self.owner
+    }
+
+    pub fn id(&self) -> EntityId {
+        self.id
+    }
+
+    /// This entity, which must not have been discarded
+    pub fn entity(&self) -> &Entity {
+        self.game().entity(self.id).expect("entity was discarded").1
+    }
+
+    /// The position of the character this entity is attached to, for equipment and character
+    /// statuses
+    pub fn character(&self) -> Option<usize> {
+        self.game().player(self.owner).entity_location(self.id).character()
+    }
+
+    /// Whether the character this entity is attached to is the active character
+    pub fn is_active(&self) -> bool {
+        self.character() == Some(self.game().player(self.owner).active_index())
+    }
+
+    /// Whether this effect can still trigger this round
+    pub fn can_use_this_round(&self) -> bool {
+        self.entity().round_usages() > 0
+    }
+
+    /// Uses up one of this round's usages
+    pub fn use_this_round(&mut self) {
+        let id = self.id;
+
+        if let Some(entity) = self.game_mut().entity_mut(id) {
+            entity.set_round_usages(entity.round_usages().saturating_sub(1));
+        }
+    }
+
+    /// Uses up one usage, discarding this entity if there are none left
+    pub fn consume(&mut self) {
+        self.consume_many(1);
+    }
+
+    pub fn consume_many(&mut self, amount: u8) {
+        let id = self.id;
+
+        if let Some(entity) = self.game_mut().entity_mut(id) {
+            let usages = entity.usages().unwrap_or(0);
+            entity.set_usages(usages.saturating_sub(amount));
+        }
+
+        self.game_mut().discard_spent();
+    }
+
+    pub fn set_counter(&mut self, counter: u8) {
+        let id = self.id;
+
+        if let Some(entity) = self.game_mut().entity_mut(id) {
+            entity.set_counter(counter);
+        }
+    }
+
+    pub fn discard(&mut self) {
+        let id = self.id;
+        self.game_mut().remove_entity(id);
+    }
+}
+
+/// The skill used by the character this entity is attached to, if that's what dealt the damage
+pub(crate) fn holder_skill(ctx: &Context, info: &DamageInfo) -> Option<SkillKind> {
+    match info.source {
+        DamageSource::Skill { character, skill } => {
+            let holder = ctx.character()?;
+            (info.attacker == ctx.owner() && character == holder).then_some(skill)
+        },
+        _ => None,
+    }
+}
+
+/// Every alive character of the player as a target
+pub(crate) fn character_targets(game: &GameState, player: PlayerId, filter: impl Fn(&CharacterState) -> bool) -> Vec<Option<Target>> {
+    game.player(player).characters().iter().enumerate()
+        .filter(|(_, ch)| ch.is_alive() && filter(ch))
+        .map(|(index, _)| Some(Target::Character(index)))
+        .collect()
+}
@@ -0,0 +1,158 @@
+use crate::*;
+use crate::game::skill::{cryo, hydro, pyro, electro, geo, dendro};
+
+pub(super) fn effect(kind: StatusKind) -> &'static dyn Effect {
+    match kind {
+        StatusKind::Satiated                 => &Satiated,
+        StatusKind::LithicSpear              => &LithicSpear,
+        StatusKind::RebelliousShield         => &RebelliousShield,
+        StatusKind::CrystallizeShield        => &CrystallizeShield,
+        StatusKind::Frozen                   => &Frozen,
+        StatusKind::DendroCore               => &DendroCore,
+        StatusKind::CatalyzingField          => &CatalyzingField,
+        StatusKind::KamisatoArtSenho         => &cryo::KamisatoArtSenho,
+        StatusKind::CryoElementalInfusion    => &cryo::CryoElementalInfusion,
+        StatusKind::ChonghuaFrostField       => &cryo::ChonghuaFrostField,
+        StatusKind::CatClawShield            => &cryo::CatClawShield,
+        StatusKind::IceLotus                 => &cryo::IceLotus,
+        StatusKind::Icicle                   => &cryo::Icicle,
+        StatusKind::IcyQuill                 => &cryo::IcyQuill,
+        StatusKind::IllusoryTorrent          => &hydro::IllusoryTorrent,
+        StatusKind::IllusoryBubble           => &hydro::IllusoryBubble,
+        StatusKind::RainSword                => &hydro::RainSword,
+        StatusKind::RainbowBladework         => &hydro::RainbowBladework,
+        StatusKind::Refraction               => &hydro::Refraction,
+        StatusKind::UniversalRevelry         => &hydro::UniversalRevelry,
+        StatusKind::InspirationField         => &pyro::InspirationField,
+        StatusKind::PyroElementalInfusion    => &pyro::PyroElementalInfusion,
+        StatusKind::Pyronado                 => &pyro::Pyronado,
+        StatusKind::NiwabiEnshou             => &pyro::NiwabiEnshou,
+        StatusKind::AurousBlaze              => &pyro::AurousBlaze,
+        StatusKind::Stealth                  => &pyro::Stealth,
+        StatusKind::PactswornPathclearer     => &electro::PactswornPathclearer,
+        StatusKind::LightningStiletto        => &electro::LightningStiletto,
+        StatusKind::ElectroElementalInfusion => &electro::ElectroElementalInfusion,
+        StatusKind::TheWolfWithin            => &electro::TheWolfWithin,
+        StatusKind::TenkoThunderbolts        => &electro::TenkoThunderbolts,
+        StatusKind::RiteOfDispatch           => &electro::RiteOfDispatch,
+        StatusKind::JadeScreen               => &geo::JadeScreen,
+        StatusKind::FullPlate                => &geo::FullPlate,
+        StatusKind::SweepingTime             => &geo::SweepingTime,
+        StatusKind::Stonehide                => &geo::Stonehide,
+        StatusKind::StoneForce               => &geo::StoneForce,
+        StatusKind::Sprout                   => &dendro::Sprout,
+        StatusKind::RadicalVitality          => &dendro::RadicalVitality,
+        StatusKind::VijnanaSuffusion         => &dendro::VijnanaSuffusion,
+    }
+}
+
+/// Lasts until the end of the round
+struct Satiated;
+
+impl Effect for Satiated {
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+}
+
+/// Shield whose points are set by the Lithic Spear when equipped
+struct LithicSpear;
+
+impl Effect for LithicSpear {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Shield)
+    }
+}
+
+/// Shield of 1 point created by The Bell, stacking up to 2
+struct RebelliousShield;
+
+impl Effect for RebelliousShield {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn stacking(&self) -> Stacking { Stacking::Stack { max: 2 } }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Shield)
+    }
+}
+
+/// Shield of 1 point, stacking up to 2
+struct CrystallizeShield;
+
+impl Effect for CrystallizeShield {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn stacking(&self) -> Stacking { Stacking::Stack { max: 2 } }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Shield)
+    }
+}
+
+/// Lasts until the end of the round, or until the character takes physical or Pyro damage,
+/// which deals 2 more damage
+struct Frozen;
+
+impl Effect for Frozen {
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::IncomingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::IncomingDamage { info, calc } = hook {
+            let shattered = matches!(calc.kind(), DamageKind::Physical | DamageKind::Elemental(Element::Pyro));
+
+            if Some(info.target) == ctx.character() && shattered {
+                calc.bonus(ModifierSource::Status(StatusKind::Frozen.name()), 2);
+                ctx.discard();
+            }
+        }
+    }
+}
+
+/// Adds 2 to the next Pyro or Electro damage dealt to the opposing active character
+struct DendroCore;
+
+impl Effect for DendroCore {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        reaction_bonus(ctx, hook, StatusKind::DendroCore, &[Element::Pyro, Element::Electro], 2);
+    }
+}
+
+/// Adds 1 to the next two Electro or Dendro damage dealt to the opposing active character
+struct CatalyzingField;
+
+impl Effect for CatalyzingField {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        reaction_bonus(ctx, hook, StatusKind::CatalyzingField, &[Element::Electro, Element::Dendro], 1);
+    }
+}
+
+/// Damage bonus of the combat statuses left behind by Dendro reactions, which only apply to
+/// damage against the opposing active character
+fn reaction_bonus(ctx: &mut Context, hook: &mut Hook, status: StatusKind, elements: &[Element], amount: u8) {
+    if let Hook::OutgoingDamage { info, calc } = hook {
+        let active = ctx.game().player(info.attacker.opponent()).active_index();
+        let matching = calc.kind().element().is_some_and(|element| elements.contains(&element));
+
+        if info.attacker == ctx.owner() && info.target == active && matching {
+            calc.bonus(ModifierSource::Status(status.name()), amount);
+            ctx.consume();
+        }
+    }
+}
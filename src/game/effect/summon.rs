@@ -0,0 +1,69 @@
+use crate::*;
+use crate::game::skill::{cryo, hydro, pyro, electro, anemo, dendro};
+
+pub(super) fn effect(kind: SummonKind) -> &'static dyn Effect {
+    match kind {
+        SummonKind::CryoHilichurlShooter      => &EndPhaseDamage(SummonKind::CryoHilichurlShooter, Element::Cryo),
+        SummonKind::HydroSamachurl            => &EndPhaseDamage(SummonKind::HydroSamachurl, Element::Hydro),
+        SummonKind::HilichurlBerserker        => &EndPhaseDamage(SummonKind::HilichurlBerserker, Element::Pyro),
+        SummonKind::ElectroHilichurlShooter   => &EndPhaseDamage(SummonKind::ElectroHilichurlShooter, Element::Electro),
+        SummonKind::BurningFlame              => &BurningFlame,
+        SummonKind::FrostflakeSekiNoTo        => &cryo::FrostflakeSekiNoTo,
+        SummonKind::DrunkenMist               => &cryo::DrunkenMist,
+        SummonKind::SacredCryoPearl           => &cryo::SacredCryoPearl,
+        SummonKind::MelodyLoop                => &hydro::MelodyLoop,
+        SummonKind::Reflection                => &hydro::Reflection,
+        SummonKind::OceanicMimicSquirrel      => &hydro::OceanicMimicSquirrel,
+        SummonKind::OceanicMimicRaptor        => &hydro::OceanicMimicRaptor,
+        SummonKind::OceanicMimicFrog          => &hydro::OceanicMimicFrog,
+        SummonKind::Guoba                     => &pyro::Guoba,
+        SummonKind::Oz                        => &electro::Oz,
+        SummonKind::DandelionField            => &anemo::DandelionField,
+        SummonKind::LargeWindSpirit           => &anemo::LargeWindSpirit,
+        SummonKind::ShadowswordLoneGale       => &anemo::ShadowswordLoneGale,
+        SummonKind::ShadowswordGallopingFrost => &anemo::ShadowswordGallopingFrost,
+        SummonKind::CuileinAnbar              => &dendro::CuileinAnbar,
+        SummonKind::TalismanSpirit            => &cryo::TalismanSpirit,
+        SummonKind::SalonMembers              => &hydro::SalonMembers,
+        SummonKind::FierySanctumField         => &pyro::FierySanctumField,
+        SummonKind::SesshouSakura             => &electro::SesshouSakura,
+        SummonKind::ClusterbloomArrow         => &dendro::ClusterbloomArrow,
+    }
+}
+
+/// Deals 1 elemental damage to the opposing active character during the End Phase, twice
+struct EndPhaseDamage(SummonKind, Element);
+
+impl Effect for EndPhaseDamage {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let attacker = ctx.owner();
+        let target = ctx.game().player(attacker.opponent()).active_index();
+        let info = DamageInfo { attacker, source: DamageSource::Summon(self.0), target };
+
+        ctx.game_mut().deal_damage(info, 1, DamageKind::Elemental(self.1));
+        ctx.consume();
+    }
+}
+
+/// Deals 1 Pyro damage during the End Phase, stacking up to 2 usages
+struct BurningFlame;
+
+impl Effect for BurningFlame {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn stacking(&self) -> Stacking { Stacking::Stack { max: 2 } }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        EndPhaseDamage(SummonKind::BurningFlame, Element::Pyro).on_trigger(ctx, hook);
+    }
+}
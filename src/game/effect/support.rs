@@ -0,0 +1,755 @@
+use crate::*;
+
+pub(super) fn companion(card: CompanionCard) -> &'static dyn CardEffect {
+    match card {
+        CompanionCard::Paimon         => &Paimon,
+        CompanionCard::Katheryne      => &Katheryne,
+        CompanionCard::Timaeus        => &Timaeus,
+        CompanionCard::Wagner         => &Wagner,
+        CompanionCard::ChefMao        => &ChefMao,
+        CompanionCard::Tubby          => &Tubby,
+        CompanionCard::Timmie         => &Timmie,
+        CompanionCard::Liben          => &Liben,
+        CompanionCard::Ellin          => &Ellin,
+        CompanionCard::LiuSu          => &LiuSu,
+        CompanionCard::ChangTheNinth  => &ChangTheNinth,
+        CompanionCard::IronTongueTian => &IronTongueTian,
+        CompanionCard::Xudong         => &Xudong,
+        CompanionCard::MasterZhang    => &MasterZhang,
+    }
+}
+
+pub(super) fn location(card: LocationCard) -> &'static dyn CardEffect {
+    match card {
+        LocationCard::DawnWinery               => &DawnWinery,
+        LocationCard::FavoniusCathedral        => &FavoniusCathedral,
+        LocationCard::KnightsOfFavoniusLibrary => &KnightsOfFavoniusLibrary,
+        LocationCard::JadeChamber              => &JadeChamber,
+        LocationCard::LiyueHarborWharf         => &LiyueHarborWharf,
+        LocationCard::WangshuInn               => &WangshuInn,
+        LocationCard::Tenshukaku               => &Tenshukaku,
+        LocationCard::GrandNarukamiShrine      => &GrandNarukamiShrine,
+    }
+}
+
+pub(super) fn item(card: ItemCard) -> &'static dyn CardEffect {
+    match card {
+        ItemCard::ParametricTransformer => &ParametricTransformer,
+        ItemCard::NRE                   => &Nre,
+        ItemCard::TreasureSeekingSeelie => &TreasureSeekingSeelie,
+    }
+}
+
+/// When the support zone is full, one of the supports has to be replaced
+pub(super) fn support_targets(game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+    let supports = game.player(player).supports().len();
+
+    if supports < MAX_SUPPORTS {
+        vec![None]
+    } else {
+        (0..supports).map(|index| Some(Target::Support(index))).collect()
+    }
+}
+
+pub(super) fn place(game: &mut GameState, player: PlayerId, target: Option<Target>, card: impl Into<ActionCard>) {
+    let replace = match target {
+        Some(Target::Support(index)) => Some(index),
+        _ => None,
+    };
+
+    game.add_support(player, card.into(), replace);
+}
+
+/// Implements the placement shared by most supports
+macro_rules! support_card {
+    ($name:ident : $card:expr) => {
+        impl CardEffect for $name {
+            fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+                support_targets(game, player)
+            }
+
+            fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+                place(game, player, target, $card);
+            }
+        }
+    };
+}
+
+/// Switching characters costs 1 less die, once per round
+struct DawnWinery;
+
+impl Effect for DawnWinery {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::Speed]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::Speed { action: CostAction::Switch { .. }, cost, consumed, commit, .. } = hook {
+            if cost.amount() > 0 && ctx.can_use_this_round() {
+                cost.discount(1);
+                consumed.push(ctx.id());
+
+                if *commit {
+                    ctx.use_this_round();
+                }
+            }
+        }
+    }
+}
+
+support_card!(DawnWinery: LocationCard::DawnWinery);
+
+/// Heals the active character for 2 HP during the End Phase
+struct FavoniusCathedral;
+
+impl Effect for FavoniusCathedral {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let owner = ctx.owner();
+        let active = ctx.game().player(owner).active();
+
+        if active.hp() < active.max_hp() {
+            let index = ctx.game().player(owner).active_index();
+            ctx.game_mut().heal(owner, index, 2);
+            ctx.consume();
+        }
+    }
+}
+
+support_card!(FavoniusCathedral: LocationCard::FavoniusCathedral);
+
+/// Rerolls dice when played, and grants an extra reroll every Roll Phase
+struct KnightsOfFavoniusLibrary;
+
+impl Effect for KnightsOfFavoniusLibrary {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::RollPhase]
+    }
+
+    fn on_trigger(&self, _ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::RollPhase { rerolls, .. } = hook {
+            *rerolls += 1;
+        }
+    }
+}
+
+impl CardEffect for KnightsOfFavoniusLibrary {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        support_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        place(game, player, target, LocationCard::KnightsOfFavoniusLibrary);
+        game.grant_rerolls(player, 1);
+    }
+}
+
+/// Two dice of the active character's element are guaranteed during the Roll Phase
+struct JadeChamber;
+
+impl Effect for JadeChamber {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::RollPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::RollPhase { fixed, .. } = hook {
+            let element = ctx.game().player(ctx.owner()).active().card().element();
+            fixed.extend([Die::Element(element); 2]);
+        }
+    }
+}
+
+support_card!(JadeChamber: LocationCard::JadeChamber);
+
+/// Draws 2 cards during the End Phase
+struct LiyueHarborWharf;
+
+impl Effect for LiyueHarborWharf {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let owner = ctx.owner();
+        ctx.game_mut().draw_cards(owner, 2);
+        ctx.consume();
+    }
+}
+
+support_card!(LiyueHarborWharf: LocationCard::LiyueHarborWharf);
+
+/// Heals the most damaged standby character for 2 HP during the End Phase
+struct WangshuInn;
+
+impl Effect for WangshuInn {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let owner = ctx.owner();
+        let state = ctx.game().player(owner);
+
+        let target = state.characters().iter().enumerate()
+            .filter(|(index, ch)| *index != state.active_index() && ch.is_alive() && ch.hp() < ch.max_hp())
+            .max_by_key(|(index, ch)| (ch.max_hp() - ch.hp(), std::cmp::Reverse(*index)))
+            .map(|(index, _)| index);
+
+        if let Some(index) = target {
+            ctx.game_mut().heal(owner, index, 2);
+            ctx.consume();
+        }
+    }
+}
+
+support_card!(WangshuInn: LocationCard::WangshuInn);
+
+/// Creates an Omni die at the start of the Action Phase if the player has 5 different kinds of
+/// dice
+struct Tenshukaku;
+
+impl Effect for Tenshukaku {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::ActionPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let owner = ctx.owner();
+
+        if ctx.game().player(owner).dice().iter().count() >= 5 {
+            ctx.game_mut().add_dice(owner, Die::Omni, 1);
+        }
+    }
+}
+
+support_card!(Tenshukaku: LocationCard::Tenshukaku);
+
+fn random_basic_die(game: &mut GameState, player: PlayerId) {
+    let element = Element::ALL[game.rng().below(Element::ALL.len())];
+    game.add_dice(player, Die::Element(element), 1);
+}
+
+/// Creates a random basic die when played, and another at the start of the Action Phase
+struct GrandNarukamiShrine;
+
+impl Effect for GrandNarukamiShrine {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::ActionPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let owner = ctx.owner();
+        random_basic_die(ctx.game_mut(), owner);
+        ctx.consume();
+    }
+}
+
+impl CardEffect for GrandNarukamiShrine {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        support_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        place(game, player, target, LocationCard::GrandNarukamiShrine);
+        random_basic_die(game, player);
+    }
+}
+
+/// Creates 2 Omni dice at the start of the Action Phase
+struct Paimon;
+
+impl Effect for Paimon {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::ActionPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let owner = ctx.owner();
+        ctx.game_mut().add_dice(owner, Die::Omni, 2);
+        ctx.consume();
+    }
+}
+
+support_card!(Paimon: CompanionCard::Paimon);
+
+/// The first switch of each round is a fast action
+struct Katheryne;
+
+impl Effect for Katheryne {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::Speed]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::Speed { action: CostAction::Switch { .. }, fast, consumed, commit, .. } = hook {
+            if !*fast && ctx.can_use_this_round() {
+                *fast = true;
+                consumed.push(ctx.id());
+
+                if *commit {
+                    ctx.use_this_round();
+                }
+            }
+        }
+    }
+}
+
+support_card!(Katheryne: CompanionCard::Katheryne);
+
+/// Stores materials every End Phase, which can be spent to play equipment of a given type for
+/// free, once per round
+fn material_discount(ctx: &mut Context, hook: &mut Hook, applies: impl Fn(ActionCard) -> bool) {
+    match hook {
+        Hook::CardCost { card, cost, consumed, commit } => {
+            let amount = cost.amount();
+
+            if applies(*card) && amount > 0 && ctx.entity().counter() >= amount && ctx.can_use_this_round() {
+                cost.discount(amount);
+                consumed.push(ctx.id());
+
+                if *commit {
+                    let left = ctx.entity().counter() - amount;
+                    ctx.set_counter(left);
+                    ctx.use_this_round();
+                }
+            }
+        },
+        Hook::EndPhase => {
+            let counter = ctx.entity().counter();
+            ctx.set_counter(counter.saturating_add(1));
+        },
+        _ => (),
+    }
+}
+
+struct Timaeus;
+
+impl Effect for Timaeus {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn counter(&self) -> u8 { 2 }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::CardCost, Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        material_discount(ctx, hook, |card| card.artifact().is_some());
+    }
+}
+
+support_card!(Timaeus: CompanionCard::Timaeus);
+
+struct Wagner;
+
+impl Effect for Wagner {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn counter(&self) -> u8 { 2 }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::CardCost, Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        material_discount(ctx, hook, |card| card.weapon().is_some());
+    }
+}
+
+support_card!(Wagner: CompanionCard::Wagner);
+
+/// After playing a food card, creates a die of the active character's element, once per round
+struct ChefMao;
+
+impl Effect for ChefMao {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterCardPlayed]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterCardPlayed { player, card } = *hook {
+            if player == ctx.owner() && card.food().is_some() && ctx.can_use_this_round() {
+                ctx.use_this_round();
+
+                let element = ctx.game().player(player).active().card().element();
+                ctx.game_mut().add_dice(player, Die::Element(element), 1);
+            }
+        }
+    }
+}
+
+support_card!(ChefMao: CompanionCard::ChefMao);
+
+/// Location cards cost 2 less dice, once per round
+struct Tubby;
+
+impl Effect for Tubby {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::CardCost]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::CardCost { card, cost, consumed, commit } = hook {
+            if card.location().is_some() && cost.amount() > 0 && ctx.can_use_this_round() {
+                cost.discount(2);
+                consumed.push(ctx.id());
+
+                if *commit {
+                    ctx.use_this_round();
+                }
+            }
+        }
+    }
+}
+
+support_card!(Tubby: CompanionCard::Tubby);
+
+/// Gathers a pigeon every Action Phase. On the third one, draws a card and creates an Omni die
+struct Timmie;
+
+impl Effect for Timmie {
+    fn counter(&self) -> u8 { 1 }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::ActionPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let pigeons = ctx.entity().counter() + 1;
+
+        if pigeons >= 3 {
+            let owner = ctx.owner();
+            ctx.game_mut().draw_cards(owner, 1);
+            ctx.game_mut().add_dice(owner, Die::Omni, 1);
+            ctx.discard();
+        } else {
+            ctx.set_counter(pigeons);
+        }
+    }
+}
+
+support_card!(Timmie: CompanionCard::Timmie);
+
+/// Collects unused dice of different elements during the End Phase. With three collected, draws
+/// 2 cards and creates 2 Omni dice at the start of the next Action Phase
+struct Liben;
+
+impl Effect for Liben {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase, Trigger::ActionPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        let owner = ctx.owner();
+        let collected = ctx.entity().counter();
+
+        match hook {
+            Hook::EndPhase => {
+                let dice: Vec<Die> = ctx.game().player(owner).dice().iter()
+                    .map(|(die, _)| die)
+                    .filter(|die| *die != Die::Omni)
+                    .take(3 - collected as usize)
+                    .collect();
+
+                for die in &dice {
+                    ctx.game_mut().player_mut(owner).dice.remove(*die, 1);
+                }
+
+                ctx.set_counter(collected + dice.len() as u8);
+            },
+            Hook::ActionPhase if collected >= 3 => {
+                ctx.game_mut().draw_cards(owner, 2);
+                ctx.game_mut().add_dice(owner, Die::Omni, 2);
+                ctx.discard();
+            },
+            _ => (),
+        }
+    }
+}
+
+support_card!(Liben: CompanionCard::Liben);
+
+/// Skills already used this round cost 1 less die, once per round
+struct Ellin;
+
+impl Effect for Ellin {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::SkillCost]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::SkillCost { character, skill, cost, consumed, commit } = hook {
+            let used = ctx.game().player(ctx.owner()).used_this_round(*character, *skill);
+
+            if used && cost.dice() > 0 && ctx.can_use_this_round() {
+                cost.discount_exact(1);
+                consumed.push(ctx.id());
+
+                if *commit {
+                    ctx.use_this_round();
+                }
+            }
+        }
+    }
+}
+
+support_card!(Ellin: CompanionCard::Ellin);
+
+/// Characters switched in with no energy gain 1 energy, once per round
+struct LiuSu;
+
+impl Effect for LiuSu {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSwitch]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterSwitch { player, to, .. } = *hook {
+            let energy = ctx.game().player(player).character(to).energy();
+
+            if player == ctx.owner() && energy == 0 && ctx.can_use_this_round() {
+                ctx.use_this_round();
+                ctx.game_mut().gain_energy(player, to, 1);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+support_card!(LiuSu: CompanionCard::LiuSu);
+
+/// Gains inspiration whenever physical or piercing damage is dealt or a reaction is triggered,
+/// by either player. With three, draws 2 cards
+struct ChangTheNinth;
+
+impl Effect for ChangTheNinth {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterDamage { breakdown, .. } = hook {
+            let inspired = breakdown.reaction().is_some()
+                || matches!(breakdown.kind(), DamageKind::Physical | DamageKind::Piercing);
+
+            if inspired {
+                let inspiration = ctx.entity().counter() + 1;
+
+                if inspiration >= 3 {
+                    let owner = ctx.owner();
+                    ctx.game_mut().draw_cards(owner, 2);
+                    ctx.discard();
+                } else {
+                    ctx.set_counter(inspiration);
+                }
+            }
+        }
+    }
+}
+
+support_card!(ChangTheNinth: CompanionCard::ChangTheNinth);
+
+/// One character without full energy gains 1 energy during the End Phase, the active character
+/// having priority
+struct IronTongueTian;
+
+impl Effect for IronTongueTian {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let owner = ctx.owner();
+
+        if let Some(index) = ctx.game().missing_energy(owner) {
+            ctx.game_mut().gain_energy(owner, index, 1);
+            ctx.consume();
+        }
+    }
+}
+
+support_card!(IronTongueTian: CompanionCard::IronTongueTian);
+
+/// Food cards cost 2 less dice, once per round
+struct Xudong;
+
+impl Effect for Xudong {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::CardCost]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::CardCost { card, cost, consumed, commit } = hook {
+            if card.food().is_some() && cost.amount() > 0 && ctx.can_use_this_round() {
+                cost.discount(2);
+                consumed.push(ctx.id());
+
+                if *commit {
+                    ctx.use_this_round();
+                }
+            }
+        }
+    }
+}
+
+support_card!(Xudong: CompanionCard::Xudong);
+
+/// Weapon cards cost 1 less die, and 1 less for every character of the party holding a weapon,
+/// once per round
+struct MasterZhang;
+
+impl Effect for MasterZhang {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::CardCost]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::CardCost { card, cost, consumed, commit } = hook {
+            if card.weapon().is_some() && cost.amount() > 0 && ctx.can_use_this_round() {
+                let armed = ctx.game().player(ctx.owner()).characters().iter()
+                    .filter(|ch| ch.weapon().is_some())
+                    .count() as u8;
+
+                cost.discount(1 + armed);
+                consumed.push(ctx.id());
+
+                if *commit {
+                    ctx.use_this_round();
+                }
+            }
+        }
+    }
+}
+
+support_card!(MasterZhang: CompanionCard::MasterZhang);
+
+/// Gains progress whenever either player uses a skill that deals elemental damage. With three,
+/// creates 3 dice of different basic elements
+struct ParametricTransformer;
+
+impl Effect for ParametricTransformer {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterDamage { info, breakdown } = hook {
+            let from_skill = matches!(info.source, DamageSource::Skill { .. });
+
+            if from_skill && breakdown.kind().element().is_some() {
+                let progress = ctx.entity().counter() + 1;
+
+                if progress >= 3 {
+                    let owner = ctx.owner();
+                    let mut elements = Element::ALL;
+                    ctx.game_mut().rng().shuffle(&mut elements);
+
+                    for element in &elements[..3] {
+                        ctx.game_mut().add_dice(owner, Die::Element(*element), 1);
+                    }
+
+                    ctx.discard();
+                } else {
+                    ctx.set_counter(progress);
+                }
+            }
+        }
+    }
+}
+
+support_card!(ParametricTransformer: ItemCard::ParametricTransformer);
+
+/// Gains a clue after one of the player's characters uses a skill, once per round. With three,
+/// draws 3 cards
+struct TreasureSeekingSeelie;
+
+impl Effect for TreasureSeekingSeelie {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterSkill { player, .. } = *hook {
+            if player == ctx.owner() && ctx.can_use_this_round() {
+                ctx.use_this_round();
+                let clues = ctx.entity().counter() + 1;
+
+                if clues >= 3 {
+                    ctx.game_mut().draw_cards(player, 3);
+                    ctx.discard();
+                } else {
+                    ctx.set_counter(clues);
+                }
+            }
+        }
+    }
+}
+
+support_card!(TreasureSeekingSeelie: ItemCard::TreasureSeekingSeelie);
+
+/// Draws a food card when played, and another one after playing food, once per round
+struct Nre;
+
+impl Effect for Nre {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterCardPlayed]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterCardPlayed { player, card } = *hook {
+            if player == ctx.owner() && card.food().is_some() && ctx.can_use_this_round() {
+                ctx.use_this_round();
+                ctx.game_mut().draw_matching(player, |card| card.food().is_some());
+            }
+        }
+    }
+}
+
+impl CardEffect for Nre {
+    fn targets(&self, game: &GameState, player: PlayerId) -> Vec<Option<Target>> {
+        support_targets(game, player)
+    }
+
+    fn play(&self, game: &mut GameState, player: PlayerId, target: Option<Target>) {
+        place(game, player, target, ItemCard::NRE);
+        game.draw_matching(player, |card| card.food().is_some());
+    }
+}
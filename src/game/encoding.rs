@@ -0,0 +1,565 @@
+use crate::*;
+
+/// Version of the layout of [`Observation::encode`] and [`Observation::action_index`]
+///
+/// Both stay the same across releases of this crate as long as this doesn't change. Cards,
+/// statuses and summons added after a version was released don't get their own slots: they
+/// share an "unknown" slot at the end of their table until the next version.
+pub const ENCODING_VERSION: u32 = 2;
+
+/// Length of every [`Observation::encode`]
+pub const ENCODING_SIZE: usize = GLOBAL_SIZE + 2 * PLAYER_SIZE;
+
+/// Amount of distinct [`Observation::action_index`]es
+pub const ACTION_SPACE: usize = SWAP_OFFSET + SWAP_MASKS + REROLL_MASKS;
+
+const CHARACTER_IDS: usize = CHARACTERS.len() + 1;
+const ACTION_IDS: usize = TALENTS.len() + WEAPONS.len() + ARTIFACTS.len() + LOCATIONS.len()
+    + COMPANIONS.len() + ITEMS.len() + ARCANE_LEGENDS.len() + RESONANCES.len() + NORMAL_EVENTS.len()
+    + FOOD.len() + 1;
+const WEAPON_IDS: usize = WEAPONS.len() + 1;
+const ARTIFACT_IDS: usize = ARTIFACTS.len() + 1;
+/// Status kinds, then action cards (which statuses named after a card use)
+const STATUS_IDS: usize = STATUSES.len() + 1 + ACTION_IDS;
+const SUMMON_IDS: usize = SUMMONS.len() + 1;
+const DIE_KINDS: usize = 8;
+
+const CHARACTERS_PER_PLAYER: usize = 3;
+const MAX_SKILLS: usize = 4;
+
+const GLOBAL_SIZE: usize = 1 + 4 + 2 + 4 + 1 + 3;
+const CHARACTER_SIZE: usize = CHARACTER_IDS + 4 + 3 + 7 + WEAPON_IDS + ARTIFACT_IDS + 1 + STATUS_IDS;
+const SUPPORT_SIZE: usize = ACTION_IDS + 3;
+const PLAYER_SIZE: usize = CHARACTERS_PER_PLAYER * CHARACTER_SIZE + STATUS_IDS + SUMMON_IDS
+    + MAX_SUPPORTS * SUPPORT_SIZE + 2 * (ACTION_IDS + 1) + DIE_KINDS + 1 + ACTION_IDS + 3;
+
+/// No target, 3 characters, 4 summons on each side, 4 supports and 6 transfers
+const TARGETS: usize = 1 + CHARACTERS_PER_PLAYER + 2 * MAX_SUMMONS + MAX_SUPPORTS + 6;
+const TUNING_OFFSET: usize = ACTION_IDS * TARGETS;
+const SKILL_OFFSET: usize = TUNING_OFFSET + ACTION_IDS * DIE_KINDS;
+const SWITCH_OFFSET: usize = SKILL_OFFSET + MAX_SKILLS;
+const END_ROUND: usize = SWITCH_OFFSET + CHARACTERS_PER_PLAYER;
+const CHOOSE_OFFSET: usize = END_ROUND + 1;
+const SWAP_OFFSET: usize = CHOOSE_OFFSET + CHARACTERS_PER_PLAYER;
+const SWAP_MASKS: usize = 1 << STARTING_HAND;
+const REROLL_OFFSET: usize = SWAP_OFFSET + SWAP_MASKS;
+const REROLL_MASKS: usize = 1 << DIE_KINDS;
+
+impl Observation {
+    /// The observation as a vector of [`ENCODING_SIZE`] numbers, for machine learning models
+    ///
+    /// Everything is laid out from the viewer's point of view: their side of the field comes
+    /// first, whichever [`PlayerId`] they are. Cards, statuses and summons are identified by
+    /// their position in tables frozen for each [`ENCODING_VERSION`]. Amounts are stored as
+    /// they are, without any scaling. In order:
+    ///
+    /// | Size | Content |
+    /// |------|---------|
+    /// | 1 | Round |
+    /// | 4 | Phase, one-hot: Roll, Action, End, Game Over |
+    /// | 2 | Whether it's the viewer's turn, whether the viewer went first this round |
+    /// | 4 | Pending decision, one-hot: none, choose active, mulligan, reroll |
+    /// | 1 | Whether the viewer has to make the decision |
+    /// | 3 | Outcome: viewer won, opponent won, draw |
+    /// | player | The viewer's side |
+    /// | player | The opponent's side |
+    ///
+    /// Each side is made of:
+    ///
+    /// | Size | Content |
+    /// |------|---------|
+    /// | 3 × character | Characters, in order |
+    /// | 185 | Combat statuses: usages (or 1 if not limited by usages) by status |
+    /// | 26 | Summons: usages (or 1) by summon |
+    /// | 4 × 147 | Supports in order: action card one-hot, usages, counter, rounds left |
+    /// | 144 + 1 | Cards in hand by action card, hand size |
+    /// | 144 + 1 | Cards in the draw pile by action card, pile size |
+    /// | 8 + 1 | Dice by kind (Omni, then [`Element::ALL`]), amount of dice |
+    /// | 144 | Cards played during the match by action card |
+    /// | 3 | Ended the round, had a character defeated this round, pending rerolls |
+    ///
+    /// Hidden information (the opponent's hand, pile and dice) is left as zeros, their sizes
+    /// aren't. Each character is made of:
+    ///
+    /// | Size | Content |
+    /// |------|---------|
+    /// | 33 | Character card, one-hot |
+    /// | 4 | HP, energy, whether alive, whether active |
+    /// | 3 | Whether the Normal Attack, Elemental Skill and Elemental Burst were used this round |
+    /// | 7 | Aura, by element in the order of [`Element::ALL`] |
+    /// | 21 | Weapon, one-hot (last slot for unknown weapons, none if empty) |
+    /// | 23 | Artifact, one-hot |
+    /// | 1 | Whether the talent is equipped |
+    /// | 185 | Character statuses: usages (or 1) by status |
+    ///
+    /// Status tables list every [`StatusKind`] and an unknown slot, followed by every action
+    /// card (for statuses named after a card, such as food buffs). Action cards are listed in
+    /// [`Deck`] order, followed by an unknown slot.
+    pub fn encode(&self) -> Vec<f32> {
+        let mut encoder = Encoder(Vec::with_capacity(ENCODING_SIZE));
+        let viewer = self.viewer;
+
+        encoder.push(self.round as f32);
+        encoder.one_hot(4, Some(match self.phase {
+            Phase::RollPhase => 0,
+            Phase::ActionPhase => 1,
+            Phase::EndPhase => 2,
+            Phase::GameOver => 3,
+        }));
+        encoder.flag(self.turn == viewer);
+        encoder.flag(self.first_player == viewer);
+        encoder.one_hot(4, Some(match self.decision {
+            None => 0,
+            Some(Decision::ChooseActive { .. }) => 1,
+            Some(Decision::Mulligan { .. }) => 2,
+            Some(Decision::Reroll { .. }) => 3,
+        }));
+        encoder.flag(self.decision.is_some_and(|decision| decision.player() == viewer));
+        encoder.flag(self.outcome == Some(Outcome::Winner(viewer)));
+        encoder.flag(self.outcome == Some(Outcome::Winner(viewer.opponent())));
+        encoder.flag(self.outcome == Some(Outcome::Draw));
+
+        encoder.player(self.own());
+        encoder.player(self.opponent());
+
+        debug_assert_eq!(encoder.0.len(), ENCODING_SIZE);
+        encoder.0
+    }
+
+    /// A position for the action in `0..ACTION_SPACE`, the same in every match, for masking
+    /// the outputs of a policy over every possible action
+    ///
+    /// Summon targets are relative to the viewer, like in [`encode`](Self::encode). Returns
+    /// `None` for actions that don't fit in the action space: card swaps with more than
+    /// [`STARTING_HAND`] cards in hand, and rerolls that keep some dice of a kind while
+    /// rerolling others. Every other legal action has a position, distinct from the ones of
+    /// the other legal actions (unless several custom cards, or cards added after this
+    /// [`ENCODING_VERSION`], use the unknown slot).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use genius_invokation::*;
+    /// # let deck = Deck::from_iter(
+    /// #     [CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle].into_iter().map(Card::from)
+    /// #         .chain(NormalEventCard::ALL.into_iter().cycle().take(30).map(Card::from))
+    /// # ).unwrap();
+    /// let game = GameState::new(&deck, &deck, 0);
+    /// let observation = game.observe(game.acting_player());
+    /// let actions = game.legal_actions();
+    ///
+    /// let mask = observation.action_mask(&actions);
+    /// let chosen = mask.iter().position(|legal| *legal).unwrap();
+    ///
+    /// let action = actions.iter().find(|action| observation.action_index(action) == Some(chosen));
+    /// assert!(action.is_some());
+    /// ```
+    pub fn action_index(&self, action: &Action) -> Option<usize> {
+        match *action {
+            Action::PlayCard { card, target } => {
+                let target = match target {
+                    None => 0,
+                    Some(Target::Character(index)) => 1 + bounded(index, CHARACTERS_PER_PLAYER)?,
+                    Some(Target::Summon(player, index)) => {
+                        let side = if player == self.viewer { 0 } else { MAX_SUMMONS };
+                        1 + CHARACTERS_PER_PLAYER + side + bounded(index, MAX_SUMMONS)?
+                    },
+                    Some(Target::Support(index)) => {
+                        1 + CHARACTERS_PER_PLAYER + 2 * MAX_SUMMONS + bounded(index, MAX_SUPPORTS)?
+                    },
+                    Some(Target::Transfer(from, to)) => {
+                        let (from, to) = (bounded(from, CHARACTERS_PER_PLAYER)?, bounded(to, CHARACTERS_PER_PLAYER)?);
+                        let to = match to {
+                            to if to == from => return None,
+                            to if to > from => to - 1,
+                            to => to,
+                        };
+
+                        1 + CHARACTERS_PER_PLAYER + 2 * MAX_SUMMONS + MAX_SUPPORTS + from * 2 + to
+                    },
+                };
+
+                Some(action_card_id(card) * TARGETS + target)
+            },
+            Action::ElementalTuning { card, die } => Some(TUNING_OFFSET + action_card_id(card) * DIE_KINDS + die_id(die)),
+            Action::UseSkill { skill } => Some(SKILL_OFFSET + bounded(skill, MAX_SKILLS)?),
+            Action::SwitchCharacter { to } => Some(SWITCH_OFFSET + bounded(to, CHARACTERS_PER_PLAYER)?),
+            Action::EndRound => Some(END_ROUND),
+            Action::ChooseActive { character } => Some(CHOOSE_OFFSET + bounded(character, CHARACTERS_PER_PLAYER)?),
+            Action::SwapCards { cards } => Some(SWAP_OFFSET + bounded(cards as usize, SWAP_MASKS)?),
+            Action::Reroll { dice } => {
+                let own = self.own().dice?;
+                let mut mask = 0;
+
+                for die in dice_kinds() {
+                    match dice.count(die) {
+                        0 => (),
+                        count if count == own.count(die) => mask |= 1 << die_id(die),
+                        _ => return None,
+                    }
+                }
+
+                Some(REROLL_OFFSET + mask)
+            },
+        }
+    }
+
+    /// Which of the [`ACTION_SPACE`] positions are taken by one of `actions`, see
+    /// [`action_index`](Self::action_index)
+    pub fn action_mask(&self, actions: &[Action]) -> Vec<bool> {
+        let mut mask = vec![false; ACTION_SPACE];
+
+        for index in actions.iter().filter_map(|action| self.action_index(action)) {
+            mask[index] = true;
+        }
+
+        mask
+    }
+}
+
+fn bounded(index: usize, size: usize) -> Option<usize> {
+    (index < size).then_some(index)
+}
+
+struct Encoder(Vec<f32>);
+
+impl Encoder {
+    fn push(&mut self, value: f32) {
+        self.0.push(value);
+    }
+
+    fn flag(&mut self, value: bool) {
+        self.push(if value { 1.0 } else { 0.0 });
+    }
+
+    fn one_hot(&mut self, size: usize, index: Option<usize>) {
+        let start = self.0.len();
+        self.0.resize(start + size, 0.0);
+
+        if let Some(index) = index {
+            self.0[start + index] = 1.0;
+        }
+    }
+
+    /// Adds up `(index, value)` pairs into a section of `size` numbers
+    fn table(&mut self, size: usize, values: impl IntoIterator<Item=(usize, f32)>) {
+        let start = self.0.len();
+        self.0.resize(start + size, 0.0);
+
+        for (index, value) in values {
+            self.0[start + index] += value;
+        }
+    }
+
+    fn cards<'a>(&mut self, cards: impl IntoIterator<Item=&'a ActionCard>) {
+        self.table(ACTION_IDS, cards.into_iter().map(|card| (action_card_id(*card), 1.0)));
+    }
+
+    fn statuses(&mut self, statuses: &[Entity]) {
+        self.table(STATUS_IDS, statuses.iter().filter_map(|status| {
+            let id = match status.kind() {
+                EntityKind::Status(kind) => status_id(kind),
+                EntityKind::Card(card) => STATUSES.len() + 1 + action_card_id(card),
+                EntityKind::Summon(_) => return None,
+            };
+
+            Some((id, stacks(status)))
+        }));
+    }
+
+    fn player(&mut self, view: &PlayerView) {
+        for index in 0..CHARACTERS_PER_PLAYER {
+            match view.characters.get(index) {
+                Some(character) => self.character(view, index, character),
+                None => self.one_hot(CHARACTER_SIZE, None),
+            }
+        }
+
+        self.statuses(&view.combat_statuses);
+        self.table(SUMMON_IDS, view.summons.iter().filter_map(|summon| match summon.kind() {
+            EntityKind::Summon(kind) => Some((summon_id(kind), stacks(summon))),
+            _ => None,
+        }));
+
+        for index in 0..MAX_SUPPORTS {
+            let support = view.supports.get(index);
+
+            self.one_hot(ACTION_IDS, support.and_then(|support| match support.kind() {
+                EntityKind::Card(card) => Some(action_card_id(card)),
+                _ => None,
+            }));
+            self.push(support.and_then(Entity::usages).unwrap_or(0) as f32);
+            self.push(support.map_or(0, Entity::counter) as f32);
+            self.push(support.and_then(Entity::duration).unwrap_or(0) as f32);
+        }
+
+        self.cards(view.hand.iter().flatten());
+        self.push(view.hand_size as f32);
+        self.cards(view.pile.iter().flatten());
+        self.push(view.pile_size as f32);
+        self.table(DIE_KINDS, view.dice.iter().flat_map(|dice| {
+            dice_kinds().map(|die| (die_id(die), dice.count(die) as f32))
+        }));
+        self.push(view.dice_count as f32);
+        self.cards(&view.played);
+
+        self.flag(view.ended_round);
+        self.flag(view.defeated_this_round);
+        self.push(view.pending_rerolls as f32);
+    }
+
+    fn character(&mut self, view: &PlayerView, index: usize, character: &CharacterState) {
+        self.one_hot(CHARACTER_IDS, Some(character_id(character.card())));
+        self.push(character.hp() as f32);
+        self.push(character.energy() as f32);
+        self.flag(character.is_alive());
+        self.flag(view.active == index);
+
+        for kind in [SkillKind::NormalAttack, SkillKind::ElementalSkill, SkillKind::ElementalBurst] {
+            self.flag(view.skills_used.contains(&(index, kind)));
+        }
+
+        self.table(7, character.aura().elements().map(|element| (element_id(element), 1.0)));
+        self.one_hot(WEAPON_IDS, character.weapon().map(|weapon| id(&WEAPONS, weapon)));
+        self.one_hot(ARTIFACT_IDS, character.artifact().map(|artifact| id(&ARTIFACTS, artifact)));
+        self.flag(character.talent().is_some());
+        self.statuses(character.statuses());
+    }
+}
+
+/// Usages left, or 1 for entities that aren't limited by usages
+fn stacks(entity: &Entity) -> f32 {
+    entity.usages().unwrap_or(1) as f32
+}
+
+/// Position in a table, or the unknown slot right after it
+fn id<T: PartialEq>(table: &[T], item: T) -> usize {
+    table.iter().position(|known| *known == item).unwrap_or(table.len())
+}
+
+fn character_id(card: CharacterCard) -> usize {
+    id(&CHARACTERS, card)
+}
+
+fn status_id(kind: StatusKind) -> usize {
+    id(&STATUSES, kind)
+}
+
+fn summon_id(kind: SummonKind) -> usize {
+    id(&SUMMONS, kind)
+}
+
+fn action_card_id(card: ActionCard) -> usize {
+    /// Position and unknown slot within a table, after the tables before it
+    fn within<T: PartialEq>(offset: usize, table: &[T], item: T) -> usize {
+        match table.iter().position(|known| *known == item) {
+            Some(position) => offset + position,
+            None => ACTION_IDS - 1,
+        }
+    }
+
+    let talents = 0;
+    let weapons = talents + TALENTS.len();
+    let artifacts = weapons + WEAPONS.len();
+    let locations = artifacts + ARTIFACTS.len();
+    let companions = locations + LOCATIONS.len();
+    let items = companions + COMPANIONS.len();
+    let arcane = items + ITEMS.len();
+    let resonances = arcane + ARCANE_LEGENDS.len();
+    let normal = resonances + RESONANCES.len();
+    let food = normal + NORMAL_EVENTS.len();
+
+    match card {
+        ActionCard::Equipment(EquipmentCard::Talent(card))   => within(talents, &TALENTS, card),
+        ActionCard::Equipment(EquipmentCard::Weapon(card))   => within(weapons, &WEAPONS, card),
+        ActionCard::Equipment(EquipmentCard::Artifact(card)) => within(artifacts, &ARTIFACTS, card),
+        ActionCard::Support(SupportCard::Location(card))     => within(locations, &LOCATIONS, card),
+        ActionCard::Support(SupportCard::Companion(card))    => within(companions, &COMPANIONS, card),
+        ActionCard::Support(SupportCard::Item(card))         => within(items, &ITEMS, card),
+        ActionCard::Event(EventCard::Arcane(card))           => within(arcane, &ARCANE_LEGENDS, card),
+        ActionCard::Event(EventCard::Resonance(card))        => within(resonances, &RESONANCES, card),
+        ActionCard::Event(EventCard::Normal(card))           => within(normal, &NORMAL_EVENTS, card),
+        ActionCard::Event(EventCard::Food(card))             => within(food, &FOOD, card),
+        #[cfg(feature = "custom-cards")]
+        ActionCard::Custom(_)                                => ACTION_IDS - 1,
+    }
+}
+
+fn element_id(element: Element) -> usize {
+    id(&Element::ALL, element)
+}
+
+/// Omni first, then the elements in the order of [`Element::ALL`]
+fn die_id(die: Die) -> usize {
+    match die {
+        Die::Omni => 0,
+        Die::Element(element) => 1 + element_id(element),
+    }
+}
+
+fn dice_kinds() -> impl Iterator<Item=Die> {
+    [Die::Omni].into_iter().chain(Element::ALL.map(Die::Element))
+}
+
+// The tables below are frozen for ENCODING_VERSION 2: new entries belong in a new version
+
+// Generated from the `v2` slot of every card in cards.json
+include!(concat!(env!("OUT_DIR"), "/cards/encoding.rs"));
+
+const STATUSES: [StatusKind; 40] = [
+    StatusKind::Satiated, StatusKind::LithicSpear, StatusKind::CrystallizeShield,
+    StatusKind::Frozen, StatusKind::DendroCore, StatusKind::CatalyzingField,
+    StatusKind::KamisatoArtSenho, StatusKind::CryoElementalInfusion, StatusKind::ChonghuaFrostField,
+    StatusKind::CatClawShield, StatusKind::IceLotus, StatusKind::Icicle,
+    StatusKind::IllusoryTorrent, StatusKind::IllusoryBubble, StatusKind::RainSword,
+    StatusKind::RainbowBladework, StatusKind::Refraction, StatusKind::InspirationField,
+    StatusKind::PyroElementalInfusion, StatusKind::Pyronado, StatusKind::NiwabiEnshou,
+    StatusKind::AurousBlaze, StatusKind::Stealth, StatusKind::PactswornPathclearer,
+    StatusKind::LightningStiletto, StatusKind::ElectroElementalInfusion, StatusKind::TheWolfWithin,
+    StatusKind::JadeScreen, StatusKind::FullPlate, StatusKind::SweepingTime, StatusKind::Stonehide,
+    StatusKind::StoneForce, StatusKind::Sprout, StatusKind::RadicalVitality,
+    StatusKind::RebelliousShield, StatusKind::IcyQuill, StatusKind::UniversalRevelry,
+    StatusKind::TenkoThunderbolts, StatusKind::RiteOfDispatch, StatusKind::VijnanaSuffusion,
+];
+
+const SUMMONS: [SummonKind; 25] = [
+    SummonKind::CryoHilichurlShooter, SummonKind::HydroSamachurl, SummonKind::HilichurlBerserker,
+    SummonKind::ElectroHilichurlShooter, SummonKind::BurningFlame, SummonKind::FrostflakeSekiNoTo,
+    SummonKind::DrunkenMist, SummonKind::SacredCryoPearl, SummonKind::MelodyLoop,
+    SummonKind::Reflection, SummonKind::OceanicMimicSquirrel, SummonKind::OceanicMimicRaptor,
+    SummonKind::OceanicMimicFrog, SummonKind::Guoba, SummonKind::Oz, SummonKind::DandelionField,
+    SummonKind::LargeWindSpirit, SummonKind::ShadowswordLoneGale,
+    SummonKind::ShadowswordGallopingFrost, SummonKind::CuileinAnbar, SummonKind::TalismanSpirit,
+    SummonKind::SalonMembers, SummonKind::FierySanctumField, SummonKind::SesshouSakura,
+    SummonKind::ClusterbloomArrow,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::testing::*;
+
+    fn game() -> GameState {
+        let mut game = GameState::new(&deck([CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle]), &deck(OPPONENTS), 2);
+        skip_decisions(&mut game);
+        game
+    }
+
+    #[test]
+    fn layout_matches_the_documentation() {
+        assert_eq!(CHARACTER_IDS, 33);
+        assert_eq!(ACTION_IDS, 144);
+        assert_eq!(STATUS_IDS, 185);
+        assert_eq!(SUMMON_IDS, 26);
+        assert_eq!(SUPPORT_SIZE, 147);
+        assert_eq!((WEAPON_IDS, ARTIFACT_IDS), (21, 23));
+
+        // every card has its own slot, leaving the last ones to later cards and custom ones
+        let ids: Vec<usize> = Card::all().filter_map(|card| match card {
+            Card::Action(card) => Some(action_card_id(card)),
+            Card::Character(_) => None,
+        }).collect();
+        assert_eq!(ids, (0..ACTION_IDS - 1).collect::<Vec<_>>());
+
+        let ids: Vec<usize> = CharacterCard::ALL.into_iter().map(character_id).collect();
+        assert_eq!(ids, (0..CHARACTER_IDS - 1).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn card_ids_match_the_frozen_snapshot() {
+        // every card with its id in ENCODING_VERSION 2, which can't change once released
+        let snapshot = include_str!("encoding_v2.txt");
+
+        for line in snapshot.lines() {
+            let (expected, name) = line.split_once('\t').unwrap();
+            let id = match Card::from_name(name) {
+                Some(Card::Character(card)) => character_id(card),
+                Some(Card::Action(card)) => action_card_id(card),
+                None => panic!("{name:?} is no longer a card"),
+            };
+
+            assert_eq!(id.to_string(), expected, "{name}");
+        }
+        assert_eq!(snapshot.lines().count(), Card::all().count());
+    }
+
+    #[test]
+    fn encodings_are_relative_to_the_viewer() {
+        let mut game = game();
+
+        for _ in 0..12 {
+            for viewer in [PlayerId::First, PlayerId::Second] {
+                assert_eq!(game.observe(viewer).encode().len(), ENCODING_SIZE);
+            }
+
+            let actions = game.legal_actions();
+            game.apply(actions[actions.len() / 2]).unwrap();
+        }
+
+        // both players see their own side first
+        let first = game.observe(PlayerId::First).encode();
+        let second = game.observe(PlayerId::Second).encode();
+        let own = GLOBAL_SIZE + character_id(game.player(PlayerId::First).character(0).card());
+        let other = GLOBAL_SIZE + character_id(game.player(PlayerId::Second).character(0).card());
+
+        assert_eq!((first[own], first[other]), (1.0, 0.0));
+        assert_eq!((second[own], second[other]), (0.0, 1.0));
+        assert_eq!(first[other + PLAYER_SIZE], 1.0);
+    }
+
+    #[test]
+    fn hidden_information_is_not_encoded() {
+        let mut game = game();
+        let encoding = game.observe(PlayerId::First).encode();
+
+        let opponent = game.player_mut(PlayerId::Second);
+        opponent.hand.reverse();
+        opponent.hand[0] = CompanionCard::Paimon.into();
+        opponent.dice = [Die::Omni; 8].into_iter().collect();
+
+        assert_eq!(game.observe(PlayerId::First).encode(), encoding);
+    }
+
+    #[test]
+    fn legal_actions_have_distinct_indices() {
+        let mut game = GameState::new(&deck([CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle]), &deck(OPPONENTS), 4);
+
+        for step in 0..40 {
+            if game.is_over() {
+                break
+            }
+
+            let observation = game.observe(game.acting_player());
+            let actions = game.legal_actions();
+            let mut indices: Vec<usize> = actions.iter().filter_map(|action| observation.action_index(action)).collect();
+
+            assert!(indices.iter().all(|index| *index < ACTION_SPACE));
+            assert_eq!(observation.action_mask(&actions).iter().filter(|legal| **legal).count(), indices.len());
+
+            indices.sort();
+            indices.dedup();
+            assert_eq!(indices.len(), observation.action_mask(&actions).iter().filter(|legal| **legal).count());
+
+            // only rerolls can be left out
+            if !matches!(game.decision(), Some(Decision::Reroll { .. })) {
+                assert_eq!(indices.len(), actions.len());
+            }
+
+            game.apply(actions[step * 7 % actions.len()]).unwrap();
+        }
+    }
+
+    #[test]
+    fn whole_kind_rerolls() {
+        let mut game = game();
+        game.player_mut(PlayerId::First).dice = [Die::Omni, Die::Omni, Die::Element(Element::Pyro)].into_iter().collect();
+        let observation = game.observe(PlayerId::First);
+
+        let reroll = |dice: &[Die]| observation.action_index(&Action::Reroll { dice: dice.iter().copied().collect() });
+
+        assert_eq!(reroll(&[]), Some(REROLL_OFFSET));
+        assert_eq!(reroll(&[Die::Element(Element::Pyro)]), Some(REROLL_OFFSET + (1 << die_id(Die::Element(Element::Pyro)))));
+        assert_eq!(reroll(&[Die::Omni, Die::Omni]), Some(REROLL_OFFSET + 1));
+        assert_eq!(reroll(&[Die::Omni]), None);
+    }
+}
@@ -0,0 +1,318 @@
+use crate::{ActionCard, PlayingCard};
+
+/// Unique identifier for an [`Entity`] within a match
+pub type EntityId = u32;
+
+/// Statuses that aren't created directly by an action card, such as the ones created by
+/// character skills
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum StatusKind {
+    /// Characters that ate food can't eat again this round
+    Satiated,
+    /// Shield given by the Lithic Spear, worth one point per character from Liyue
+    LithicSpear,
+    /// Shield created by The Bell after its holder uses a skill, stacking up to 2 points
+    RebelliousShield,
+
+    /// Shield created by Crystallize, stacking up to 2 points
+    CrystallizeShield,
+    /// Frozen characters can't use skills until the end of the round, or until they take
+    /// physical or Pyro damage
+    Frozen,
+    /// Created by Bloom: the next Pyro or Electro damage deals 2 more damage
+    DendroCore,
+    /// Created by Quicken: the next two Electro or Dendro damage deal 1 more damage
+    CatalyzingField,
+
+    KamisatoArtSenho,
+    CryoElementalInfusion,
+    ChonghuaFrostField,
+    CatClawShield,
+    IceLotus,
+    Icicle,
+    IcyQuill,
+
+    IllusoryTorrent,
+    IllusoryBubble,
+    RainSword,
+    RainbowBladework,
+    Refraction,
+    UniversalRevelry,
+
+    InspirationField,
+    PyroElementalInfusion,
+    Pyronado,
+    NiwabiEnshou,
+    AurousBlaze,
+    Stealth,
+
+    PactswornPathclearer,
+    LightningStiletto,
+    ElectroElementalInfusion,
+    TheWolfWithin,
+    TenkoThunderbolts,
+    RiteOfDispatch,
+
+    JadeScreen,
+    FullPlate,
+    SweepingTime,
+    Stonehide,
+    StoneForce,
+
+    Sprout,
+    RadicalVitality,
+    VijnanaSuffusion,
+}
+
+impl StatusKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Satiated                 => "Satiated",
+            Self::LithicSpear              => "Lithic Spear",
+            Self::RebelliousShield         => "Rebellious Shield",
+            Self::CrystallizeShield        => "Crystallize",
+            Self::Frozen                   => "Frozen",
+            Self::DendroCore               => "Dendro Core",
+            Self::CatalyzingField          => "Catalyzing Field",
+            Self::KamisatoArtSenho         => "Kamisato Art: Senho",
+            Self::CryoElementalInfusion    => "Cryo Elemental Infusion",
+            Self::ChonghuaFrostField       => "Chonghua Frost Field",
+            Self::CatClawShield            => "Cat-Claw Shield",
+            Self::IceLotus                 => "Ice Lotus",
+            Self::Icicle                   => "Icicle",
+            Self::IcyQuill                 => "Icy Quill",
+            Self::IllusoryTorrent          => "Illusory Torrent",
+            Self::IllusoryBubble           => "Illusory Bubble",
+            Self::RainSword                => "Rain Sword",
+            Self::RainbowBladework         => "Rainbow Bladework",
+            Self::Refraction               => "Refraction",
+            Self::UniversalRevelry         => "Universal Revelry",
+            Self::InspirationField         => "Inspiration Field",
+            Self::PyroElementalInfusion    => "Pyro Elemental Infusion",
+            Self::Pyronado                 => "Pyronado",
+            Self::NiwabiEnshou             => "Niwabi Enshou",
+            Self::AurousBlaze              => "Aurous Blaze",
+            Self::Stealth                  => "Stealth",
+            Self::PactswornPathclearer     => "Pactsworn Pathclearer",
+            Self::LightningStiletto        => "Lightning Stiletto",
+            Self::ElectroElementalInfusion => "Electro Elemental Infusion",
+            Self::TheWolfWithin            => "The Wolf Within",
+            Self::TenkoThunderbolts        => "Tenko Thunderbolts",
+            Self::RiteOfDispatch           => "Rite of Dispatch",
+            Self::JadeScreen               => "Jade Screen",
+            Self::FullPlate                => "Full Plate",
+            Self::SweepingTime             => "Sweeping Time",
+            Self::Stonehide                => "Stonehide",
+            Self::StoneForce               => "Stone Force",
+            Self::Sprout                   => "Sprout",
+            Self::RadicalVitality          => "Radical Vitality",
+            Self::VijnanaSuffusion         => "Vijnana Suffusion",
+        }
+    }
+}
+
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SummonKind {
+    CryoHilichurlShooter,
+    HydroSamachurl,
+    HilichurlBerserker,
+    ElectroHilichurlShooter,
+
+    /// Created by Burning
+    BurningFlame,
+
+    FrostflakeSekiNoTo,
+    DrunkenMist,
+    SacredCryoPearl,
+    MelodyLoop,
+    Reflection,
+    OceanicMimicSquirrel,
+    OceanicMimicRaptor,
+    OceanicMimicFrog,
+    Guoba,
+    Oz,
+    DandelionField,
+    LargeWindSpirit,
+    ShadowswordLoneGale,
+    ShadowswordGallopingFrost,
+    CuileinAnbar,
+    TalismanSpirit,
+    SalonMembers,
+    FierySanctumField,
+    SesshouSakura,
+    ClusterbloomArrow,
+}
+
+impl SummonKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CryoHilichurlShooter      => "Cryo Hilichurl Shooter",
+            Self::HydroSamachurl            => "Hydro Samachurl",
+            Self::HilichurlBerserker        => "Hilichurl Berserker",
+            Self::ElectroHilichurlShooter   => "Electro Hilichurl Shooter",
+            Self::BurningFlame              => "Burning Flame",
+            Self::FrostflakeSekiNoTo        => "Frostflake Seki no To",
+            Self::DrunkenMist               => "Drunken Mist",
+            Self::SacredCryoPearl           => "Sacred Cryo Pearl",
+            Self::MelodyLoop                => "Melody Loop",
+            Self::Reflection                => "Reflection",
+            Self::OceanicMimicSquirrel      => "Oceanic Mimic: Squirrel",
+            Self::OceanicMimicRaptor        => "Oceanic Mimic: Raptor",
+            Self::OceanicMimicFrog          => "Oceanic Mimic: Frog",
+            Self::Guoba                     => "Guoba",
+            Self::Oz                        => "Oz",
+            Self::DandelionField            => "Dandelion Field",
+            Self::LargeWindSpirit           => "Large Wind Spirit",
+            Self::ShadowswordLoneGale       => "Shadowsword: Lone Gale",
+            Self::ShadowswordGallopingFrost => "Shadowsword: Galloping Frost",
+            Self::CuileinAnbar              => "Cuilein-Anbar",
+            Self::TalismanSpirit            => "Talisman Spirit",
+            Self::SalonMembers              => "Salon Members",
+            Self::FierySanctumField         => "Fiery Sanctum Field",
+            Self::SesshouSakura             => "Sesshou Sakura",
+            Self::ClusterbloomArrow         => "Clusterbloom Arrow",
+        }
+    }
+}
+
+/// What an [`Entity`] is, which also decides how it behaves
+/// 
+/// Cards that stay on the field (supports and equipment) or leave a status named after
+/// themselves (such as food buffs) are [`Card`](Self::Card) entities.
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EntityKind {
+    Card(ActionCard),
+    Status(StatusKind),
+    Summon(SummonKind),
+}
+
+impl EntityKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Card(card)   => card.name(),
+            Self::Status(kind) => kind.name(),
+            Self::Summon(kind) => kind.name(),
+        }
+    }
+}
+
+/// How long an entity stays on the field if its usages don't run out first
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum Duration {
+    /// Stays until its usages run out or something else discards it
+    Permanent,
+    /// Discarded at the end of the given amount of rounds, counting the current one
+    Rounds(u8),
+}
+
+/// What happens when an entity is created while one of the same kind is already there
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum Stacking {
+    /// The old entity is replaced by a fresh one, resetting its usages, counter and duration
+    Refresh,
+    /// The usages of both are added together, up to a maximum. The duration is refreshed
+    Stack { max: u8 },
+}
+
+/// Anything with an effect that stays on the field: a support, an equipped card, a character or
+/// combat status, or a summon
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Entity {
+    id: EntityId,
+    kind: EntityKind,
+    usages: Option<u8>,
+    round_usages: u8,
+    counter: u8,
+    duration: Option<u8>,
+}
+
+impl Entity {
+    pub(crate) fn new(id: EntityId, kind: EntityKind) -> Self {
+        let effect = kind.effect();
+        let duration = match effect.duration() {
+            Duration::Permanent => None,
+            Duration::Rounds(rounds) => Some(rounds),
+        };
+
+        Self {
+            id,
+            kind,
+            usages: effect.usages(),
+            round_usages: effect.per_round().unwrap_or(0),
+            counter: effect.counter(),
+            duration,
+        }
+    }
+
+    pub fn id(&self) -> EntityId {
+        self.id
+    }
+
+    pub fn kind(&self) -> EntityKind {
+        self.kind
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    /// Usages left, or `None` if this entity isn't limited by usages
+    pub fn usages(&self) -> Option<u8> {
+        self.usages
+    }
+
+    /// How many more times this entity may trigger this round, for effects limited per round
+    pub fn round_usages(&self) -> u8 {
+        self.round_usages
+    }
+
+    /// Effect-specific counter, for entities that accumulate something over time (such as
+    /// Timmie's pigeons or Timaeus' Ardent Flowers)
+    pub fn counter(&self) -> u8 {
+        self.counter
+    }
+
+    /// Rounds left before being discarded, if limited by duration
+    pub fn duration(&self) -> Option<u8> {
+        self.duration
+    }
+
+    pub(crate) fn set_usages(&mut self, usages: u8) {
+        self.usages = Some(usages);
+    }
+
+    pub(crate) fn set_counter(&mut self, counter: u8) {
+        self.counter = counter;
+    }
+
+    pub(crate) fn set_round_usages(&mut self, usages: u8) {
+        self.round_usages = usages;
+    }
+
+    pub(crate) fn set_duration(&mut self, rounds: u8) {
+        self.duration = Some(rounds);
+    }
+
+    /// Whether this entity should be discarded
+    pub(crate) fn is_spent(&self) -> bool {
+        self.usages == Some(0) && self.kind.effect().discard_when_spent() || self.duration == Some(0)
+    }
+
+    pub(crate) fn new_round(&mut self) {
+        if let Some(per_round) = self.kind.effect().per_round() {
+            self.round_usages = per_round;
+        }
+    }
+
+    pub(crate) fn end_round(&mut self) {
+        if let Some(rounds) = &mut self.duration {
+            *rounds = rounds.saturating_sub(1);
+        }
+    }
+}
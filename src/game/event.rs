@@ -0,0 +1,87 @@
+use std::fmt::{self, Display};
+use crate::*;
+use super::replay::write_action;
+
+/// Something that happened during a match, as recorded in [`GameState::events`]
+///
+/// The log is meant for reviewing matches afterward: it's detailed enough to follow what
+/// happened, but it doesn't record every state change (healing, energy, card draws...).
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// A player took an action or answered a decision. Events caused by the action come
+    /// right after it
+    Action { player: PlayerId, action: Action },
+    /// A new phase started
+    PhaseChanged { round: u8, phase: Phase },
+    /// A card was played from the hand, after paying for it
+    CardPlayed { player: PlayerId, card: ActionCard, target: Option<Target> },
+    /// Dice were paid for a card, a skill or a switch
+    DiceSpent { player: PlayerId, dice: Dice },
+    SkillUsed { player: PlayerId, character: usize, skill: SkillKind },
+    /// Damage reached a character, with the reaction it triggered if any
+    DamageDealt { info: DamageInfo, kind: DamageKind, amount: u8, reaction: Option<Reaction> },
+    /// A status was added to a character, or to the player's side of the field if `character`
+    /// is `None`. Refreshing or stacking a status counts too
+    StatusApplied { player: PlayerId, character: Option<usize>, status: EntityKind },
+    /// A summon was added to the field, or refreshed
+    Summoned { player: PlayerId, summon: SummonKind },
+    /// A summon acted during the End Phase
+    SummonTriggered { player: PlayerId, summon: SummonKind },
+    /// The active character changed, by choice or because of an effect
+    Switched { player: PlayerId, from: usize, to: usize },
+    Defeated { player: PlayerId, character: usize },
+    GameOver(Outcome),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Action { player, action } => write!(f, "{player:?}: {}", write_action(action)),
+            Self::PhaseChanged { round, phase } => write!(f, "Round {round}: {phase:?}"),
+            Self::CardPlayed { player, card, target: None } => write!(f, "{player:?} plays {}", card.name()),
+            Self::CardPlayed { player, card, target: Some(target) } => {
+                write!(f, "{player:?} plays {} on {target:?}", card.name())
+            },
+            Self::DiceSpent { player, dice } => {
+                let dice: Vec<String> = dice.iter().map(|(die, amount)| format!("{amount} {}", die_name(die))).collect();
+                write!(f, "{player:?} spends {}", dice.join(", "))
+            },
+            Self::SkillUsed { player, character, skill } => {
+                write!(f, "{player:?} character {character} uses {skill:?}")
+            },
+            Self::DamageDealt { info, kind, amount, reaction } => {
+                let kind = match kind {
+                    DamageKind::Elemental(element) => format!("{element:?}"),
+                    kind => format!("{kind:?}"),
+                };
+
+                write!(f, "{:?} {:?} deals {amount} {kind} damage to character {}", info.attacker, info.source, info.target)?;
+
+                match reaction {
+                    Some(reaction) => write!(f, " ({})", reaction.name()),
+                    None => Ok(()),
+                }
+            },
+            Self::StatusApplied { player, character: None, status } => {
+                write!(f, "{player:?} gets {}", status.name())
+            },
+            Self::StatusApplied { player, character: Some(character), status } => {
+                write!(f, "{player:?} character {character} gets {}", status.name())
+            },
+            Self::Summoned { player, summon } => write!(f, "{player:?} summons {}", summon.name()),
+            Self::SummonTriggered { player, summon } => write!(f, "{player:?} {} triggers", summon.name()),
+            Self::Switched { player, from, to } => write!(f, "{player:?} switches from character {from} to {to}"),
+            Self::Defeated { player, character } => write!(f, "{player:?} character {character} is defeated"),
+            Self::GameOver(Outcome::Winner(player)) => write!(f, "{player:?} wins"),
+            Self::GameOver(Outcome::Draw) => write!(f, "Draw"),
+        }
+    }
+}
+
+pub(crate) fn die_name(die: Die) -> String {
+    match die {
+        Die::Omni => "Omni".to_string(),
+        Die::Element(element) => format!("{element:?}"),
+    }
+}
@@ -0,0 +1,52 @@
+pub use damage::{
+    Aura, Reaction, DamageKind, ModifierSource, Defense, DefenseKind,
+    DamageCalculation, DamageStep, DamageBreakdown,
+};
+mod damage;
+
+pub use dice::Dice;
+mod dice;
+
+pub use entity::{EntityId, Entity, EntityKind, StatusKind, SummonKind, Duration, Stacking};
+mod entity;
+
+pub use skill::{SkillKind, SkillCost, Skill, SkillContext};
+mod skill;
+
+pub use effect::{
+    Effect, CardEffect, Context, Hook, Trigger, Target,
+    CostAction, DamageSource, DamageInfo,
+};
+#[cfg(feature = "custom-cards")]
+pub(crate) use effect::custom_placement;
+mod effect;
+
+pub use state::{
+    GameState, PlayerState, CharacterState, PlayerId, Phase, Outcome, Location,
+    STARTING_HAND, DICE_PER_ROUND, REROLLS_PER_ROUND, ROUND_LIMIT, MAX_HAND_SIZE, MAX_SUPPORTS, MAX_SUMMONS,
+};
+mod state;
+
+pub use action::{Action, ActionError, Decision};
+mod action;
+
+pub use cost::{CostTarget, CostModifier, EffectiveCost};
+mod cost;
+
+pub use event::Event;
+mod event;
+
+pub use replay::{Replay, ReplayError};
+mod replay;
+
+pub use observation::{Observation, PlayerView};
+mod observation;
+
+pub use encoding::{ENCODING_VERSION, ENCODING_SIZE, ACTION_SPACE};
+mod encoding;
+
+pub(crate) use rng::Rng;
+mod rng;
+
+#[cfg(test)]
+pub(crate) mod testing;
@@ -0,0 +1,262 @@
+use crate::*;
+use crate::cards::CardOrd;
+use super::Rng;
+
+/// A match as seen by one of the players, see [`GameState::observe`]
+///
+/// Everything on the field is public, but the opponent's hand and dice are hidden (only
+/// their amount is known), and so is the order of both draw piles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Observation {
+    /// The player seeing the match
+    pub viewer: PlayerId,
+    pub round: u8,
+    pub phase: Phase,
+    pub turn: PlayerId,
+    pub first_player: PlayerId,
+    pub decision: Option<Decision>,
+    pub outcome: Option<Outcome>,
+    /// Both sides of the field, by [`PlayerId::index`]
+    pub players: [PlayerView; 2],
+}
+
+/// One side of the field in an [`Observation`]. Hidden information is `None`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerView {
+    pub characters: Vec<CharacterState>,
+    pub active: usize,
+    /// Only known to the player themselves
+    pub hand: Option<Vec<ActionCard>>,
+    pub hand_size: usize,
+    /// The cards left in the pile sorted like a [`Deck`], hiding the order they'll be drawn
+    /// in. Only known to the player themselves
+    pub pile: Option<Vec<ActionCard>>,
+    pub pile_size: usize,
+    /// Only known to the player themselves
+    pub dice: Option<Dice>,
+    pub dice_count: u8,
+    pub combat_statuses: Vec<Entity>,
+    pub summons: Vec<Entity>,
+    pub supports: Vec<Entity>,
+    pub ended_round: bool,
+    pub defeated_this_round: bool,
+    pub pending_rerolls: u8,
+    /// Skills used during the current round, by character
+    pub skills_used: Vec<(usize, SkillKind)>,
+    /// See [`PlayerState::played`]
+    pub played: Vec<ActionCard>,
+}
+
+impl GameState {
+    /// The match as seen by one of the players
+    pub fn observe(&self, viewer: PlayerId) -> Observation {
+        let view = |player: PlayerId| {
+            let state = self.player(player);
+            let own = player == viewer;
+
+            let mut pile = state.pile.clone();
+            pile.sort_by(|a, b| a.cmp(b));
+
+            PlayerView {
+                characters: state.characters.clone(),
+                active: state.active,
+                hand: own.then(|| state.hand.clone()),
+                hand_size: state.hand.len(),
+                pile: own.then_some(pile),
+                pile_size: state.pile.len(),
+                dice: own.then_some(state.dice),
+                dice_count: state.dice.total(),
+                combat_statuses: state.combat_statuses.clone(),
+                summons: state.summons.clone(),
+                supports: state.supports.clone(),
+                ended_round: state.ended_round,
+                defeated_this_round: state.defeated_this_round,
+                pending_rerolls: state.pending_rerolls,
+                skills_used: state.skills_used.clone(),
+                played: state.played.clone(),
+            }
+        };
+
+        Observation {
+            viewer,
+            round: self.round,
+            phase: self.phase,
+            turn: self.turn,
+            first_player: self.first,
+            decision: self.decision,
+            outcome: self.outcome,
+            players: [view(PlayerId::First), view(PlayerId::Second)],
+        }
+    }
+}
+
+impl Observation {
+    /// The viewer's own side of the field
+    pub fn own(&self) -> &PlayerView {
+        &self.players[self.viewer.index()]
+    }
+
+    pub fn opponent(&self) -> &PlayerView {
+        &self.players[self.viewer.opponent().index()]
+    }
+
+    /// Guesses a full state of the match that the viewer would observe exactly like this
+    ///
+    /// The opponent's hand and pile are dealt at random from `opponent_deck`, minus the cards
+    /// they already played, and their dice are rolled again. Both piles are shuffled, and so
+    /// are the dice that will be rolled from then on, all depending on `seed`.
+    ///
+    /// Returns `None` if the observation can't come from a match against this deck, for
+    /// example if the opponent played a card the deck doesn't have.
+    pub fn sample(&self, opponent_deck: &Deck, seed: u64) -> Option<GameState> {
+        let mut rng = Rng::new(seed);
+        let own = self.own();
+        let opponent = self.opponent();
+
+        let mut pool: Vec<ActionCard> = opponent_deck.iter().collect();
+
+        for card in &opponent.played {
+            let position = pool.iter().position(|held| held == card)?;
+            pool.remove(position);
+        }
+
+        // cards left over were discarded for Elemental Tuning, or drawn with a full hand
+        if pool.len() < opponent.hand_size + opponent.pile_size {
+            return None
+        }
+
+        rng.shuffle(&mut pool);
+        let hidden_hand: Vec<ActionCard> = pool.drain(..opponent.hand_size).collect();
+        pool.truncate(opponent.pile_size);
+
+        let mut own_pile = own.pile.clone()?;
+        rng.shuffle(&mut own_pile);
+
+        let state = |view: &PlayerView, hand: Vec<ActionCard>, pile: Vec<ActionCard>, dice: Dice| PlayerState {
+            characters: view.characters.clone(),
+            active: view.active,
+            hand,
+            pile,
+            dice,
+            combat_statuses: view.combat_statuses.clone(),
+            summons: view.summons.clone(),
+            supports: view.supports.clone(),
+            ended_round: view.ended_round,
+            defeated_this_round: view.defeated_this_round,
+            pending_rerolls: view.pending_rerolls,
+            skills_used: view.skills_used.clone(),
+            played: view.played.clone(),
+        };
+
+        let own_state = state(own, own.hand.clone()?, own_pile, own.dice?);
+        let opponent_state = state(opponent, hidden_hand, pool, Dice::new());
+
+        let players = match self.viewer {
+            PlayerId::First => [own_state, opponent_state],
+            PlayerId::Second => [opponent_state, own_state],
+        };
+
+        let next_id = players.iter().flat_map(PlayerState::entities).map(Entity::id).max().unwrap_or(0);
+
+        let mut game = GameState {
+            round: self.round,
+            phase: self.phase,
+            turn: self.turn,
+            first: self.first_player,
+            players,
+            rng: Rng::new(rng.next_u64()),
+            next_id,
+            decision: self.decision,
+            outcome: self.outcome,
+            events: Vec::new(),
+        };
+
+        let dice: Dice = (0..opponent.dice_count).map(|_| game.random_die()).collect();
+        game.player_mut(self.viewer.opponent()).dice = dice;
+
+        Some(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    fn game() -> (GameState, Deck) {
+        let opponent = deck(OPPONENTS);
+        let mut game = GameState::new(&deck([CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle]), &opponent, 5);
+
+        skip_decisions(&mut game);
+        (game, opponent)
+    }
+
+    #[test]
+    fn opponents_hand_is_hidden() {
+        let (game, _) = game();
+        let observation = game.observe(PlayerId::First);
+
+        assert_eq!(observation.own().hand.as_deref(), Some(game.player(PlayerId::First).hand()));
+        assert_eq!(observation.opponent().hand, None);
+        assert_eq!(observation.opponent().dice, None);
+        assert_eq!(observation.opponent().hand_size, game.player(PlayerId::Second).hand().len());
+        assert_eq!(observation.opponent().pile_size, game.player(PlayerId::Second).pile().len());
+        assert_eq!(observation.opponent().dice_count, 8);
+    }
+
+    #[test]
+    fn pile_order_is_hidden() {
+        let (mut game, _) = game();
+        let observation = game.observe(PlayerId::First);
+
+        game.player_mut(PlayerId::First).pile.reverse();
+        assert_eq!(game.observe(PlayerId::First), observation);
+    }
+
+    #[test]
+    fn samples_are_consistent() {
+        let (mut game, opponent) = game();
+        game.turn = PlayerId::Second;
+        game.player_mut(PlayerId::Second).dice = [Die::Omni; 8].into_iter().collect();
+
+        let paimon = ActionCard::from(CompanionCard::Paimon);
+        let state = game.player_mut(PlayerId::Second);
+
+        if !state.hand.contains(&paimon) {
+            let position = state.pile.iter().position(|card| *card == paimon).unwrap();
+            state.pile.remove(position);
+            state.hand.push(paimon);
+        }
+
+        game.apply(Action::PlayCard { card: paimon, target: None }).unwrap();
+
+        let observation = game.observe(PlayerId::First);
+
+        for seed in 0..5 {
+            let sample = observation.sample(&opponent, seed).unwrap();
+            assert_eq!(sample.observe(PlayerId::First), observation);
+        }
+
+        // the other deck has no Paimon to play
+        let other = deck([CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle]);
+        let without_paimon = Deck::from_iter(
+            other.characters().iter().map(|card| Card::from(*card))
+                .chain(other.iter().filter(|card| card.companion().is_none()).map(Card::from))
+                .chain([Card::from(NormalEventCard::CalxsArts), NormalEventCard::GuardiansOath.into()])
+        ).unwrap();
+
+        assert!(observation.sample(&without_paimon, 0).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn observations_serialize() {
+        let (game, _) = game();
+        let observation = game.observe(PlayerId::Second);
+
+        let json = serde_json::to_string(&observation).unwrap();
+        assert_eq!(serde_json::from_str::<Observation>(&json).unwrap(), observation);
+    }
+}
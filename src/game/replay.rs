@@ -0,0 +1,422 @@
+use std::fmt::{self, Debug, Display};
+use crate::*;
+use super::event::die_name;
+
+/// First line of every replay file, followed by the format version
+const HEADER: &str = "genius-invokation replay";
+
+/// Everything needed to play a match again: both decks, the seed and every decision the
+/// players made, alongside the event log it produced
+///
+/// Replays are saved as plain text with [`Display`] and loaded back with [`Replay::parse`].
+///
+/// ## Example
+///
+/// ```
+/// # use genius_invokation::*;
+/// # let characters = [CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle].map(Card::from);
+/// # let events = NormalEventCard::ALL.into_iter().cycle().take(30).map(Card::from);
+/// # let deck = Deck::from_iter(characters.into_iter().chain(events)).unwrap();
+/// let mut game = GameState::new(&deck, &deck, 42);
+/// game.apply(game.legal_actions()[0]).unwrap();
+///
+/// let replay = Replay::record(&deck, &deck, 42, &game);
+/// let loaded = Replay::parse(&replay.to_string()).unwrap();
+///
+/// assert_eq!(loaded.play().unwrap(), game);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    decks: [Deck; 2],
+    seed: u64,
+    actions: Vec<Action>,
+    log: Vec<String>,
+}
+
+impl Replay {
+    /// Version of the replay format written by this crate
+    pub const VERSION: u32 = 1;
+
+    /// Records a match started with `GameState::new(first, second, seed)`, using the actions
+    /// in its event log
+    pub fn record(first: &Deck, second: &Deck, seed: u64, game: &GameState) -> Self {
+        let actions = game.events().iter()
+            .filter_map(|event| match event {
+                Event::Action { action, .. } => Some(*action),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            decks: [first.clone(), second.clone()],
+            seed,
+            actions,
+            log: game.events().iter().map(Event::to_string).collect(),
+        }
+    }
+
+    pub fn decks(&self) -> &[Deck; 2] {
+        &self.decks
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Every action and decision taken during the match, in order
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// The recorded event log, one line per [`Event`]
+    pub fn log(&self) -> &[String] {
+        &self.log
+    }
+
+    /// Plays the match again, checking that it produces the same event log as the recorded one
+    ///
+    /// Returns the state the match ended up in.
+    pub fn play(&self) -> Result<GameState, ReplayError> {
+        let mut game = GameState::new(&self.decks[0], &self.decks[1], self.seed);
+
+        for (index, action) in self.actions.iter().enumerate() {
+            game.apply(*action).map_err(|error| ReplayError::IllegalAction { index, error })?;
+        }
+
+        let replayed: Vec<String> = game.events().iter().map(Event::to_string).collect();
+        let line = (0..self.log.len().max(replayed.len()))
+            .find(|line| self.log.get(*line) != replayed.get(*line));
+
+        match line {
+            Some(line) => Err(ReplayError::Mismatch {
+                line,
+                expected: self.log.get(line).cloned(),
+                found: replayed.get(line).cloned(),
+            }),
+            None => Ok(game),
+        }
+    }
+
+    /// Loads a replay saved with [`Display`]
+    pub fn parse(text: &str) -> Result<Self, ReplayError> {
+        let mut lines = text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+
+        let (_, header) = lines.next().ok_or(ReplayError::InvalidLine(0))?;
+        let version = header.strip_prefix(HEADER)
+            .and_then(|version| version.trim().parse::<u32>().ok())
+            .ok_or(ReplayError::InvalidLine(0))?;
+
+        if version != Self::VERSION {
+            return Err(ReplayError::UnknownVersion(version))
+        }
+
+        let mut seed = None;
+        let mut decks = Vec::new();
+        let mut actions = Vec::new();
+        let mut log = Vec::new();
+
+        for (index, line) in lines {
+            let invalid = ReplayError::InvalidLine(index);
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+
+            match key {
+                "seed" => seed = Some(value.parse::<u64>().map_err(|_| invalid)?),
+                "deck" => {
+                    let cards = value.split(';')
+                        .map(|name| Card::from_name(name.trim()))
+                        .collect::<Option<Vec<Card>>>()
+                        .ok_or(invalid)?;
+
+                    // decks may follow any rules, but a match can't start without a character
+                    let deck = Deck::from_checked(cards);
+
+                    if deck.character_slice().is_empty() {
+                        return Err(ReplayError::InvalidDeck(DeckError::NotEnoughCharacterCards(0)))
+                    }
+
+                    decks.push(deck);
+                },
+                "action" => actions.push(parse_action(value).ok_or(invalid)?),
+                "event" => log.push(value.to_string()),
+                _ => return Err(invalid),
+            }
+        }
+
+        let decks = <[Deck; 2]>::try_from(decks).map_err(|_| ReplayError::MissingDecks)?;
+        let seed = seed.ok_or(ReplayError::MissingSeed)?;
+
+        Ok(Self { decks, seed, actions, log })
+    }
+}
+
+impl Display for Replay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER} {}", Self::VERSION)?;
+        writeln!(f, "seed {}", self.seed)?;
+
+        for deck in &self.decks {
+            let names: Vec<&str> = deck.character_slice().iter().map(CharacterCard::name)
+                .chain(deck.iter().map(|card| card.name()))
+                .collect();
+
+            writeln!(f, "deck {}", names.join("; "))?;
+        }
+
+        for action in &self.actions {
+            writeln!(f, "action {}", write_action(action))?;
+        }
+
+        for event in &self.log {
+            writeln!(f, "event {event}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The replay was saved by a newer version of the format
+    UnknownVersion(u32),
+    /// The line (counting from 0) couldn't be read
+    InvalidLine(usize),
+    /// One of the decks can't start a match. Decks are otherwise kept as saved, since they may
+    /// follow rules other than the standard ones
+    InvalidDeck(DeckError),
+    /// The replay doesn't have exactly two decks
+    MissingDecks,
+    MissingSeed,
+    /// The engine rejected the action at this position in [`Replay::actions`]
+    IllegalAction { index: usize, error: ActionError },
+    /// Playing the match again produced a different event log, starting at this line
+    Mismatch { line: usize, expected: Option<String>, found: Option<String> },
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownVersion(version) => write!(f, "Unknown replay version {version}"),
+            Self::InvalidLine(line)       => write!(f, "Line {line} of the replay is invalid"),
+            Self::InvalidDeck(error)      => write!(f, "Invalid deck in the replay: {error}"),
+            Self::MissingDecks            => write!(f, "Replays must contain exactly two decks"),
+            Self::MissingSeed             => write!(f, "The replay has no seed"),
+            Self::IllegalAction { index, error } => write!(f, "Action {index} of the replay is illegal: {error}"),
+            Self::Mismatch { line, expected, found } => write!(
+                f,
+                "Event {line} doesn't match: expected `{}`, found `{}`",
+                expected.as_deref().unwrap_or("nothing"),
+                found.as_deref().unwrap_or("nothing"),
+            ),
+        }
+    }
+}
+
+impl Debug for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+// Card names come last in action lines since they may contain spaces
+
+pub(crate) fn write_action(action: &Action) -> String {
+    match *action {
+        Action::PlayCard { card, target } => format!("play {} {}", write_target(target), card.name()),
+        Action::ElementalTuning { card, die } => format!("tune {} {}", die_name(die), card.name()),
+        Action::UseSkill { skill } => format!("skill {skill}"),
+        Action::SwitchCharacter { to } => format!("switch {to}"),
+        Action::EndRound => "end".to_string(),
+        Action::ChooseActive { character } => format!("choose {character}"),
+        Action::SwapCards { cards } => format!("swap {cards}"),
+        Action::Reroll { dice } => {
+            let dice: Vec<String> = dice.iter().map(|(die, amount)| format!("{}:{amount}", die_name(die))).collect();
+            format!("reroll {}", if dice.is_empty() { "-".to_string() } else { dice.join(",") })
+        },
+    }
+}
+
+fn parse_action(text: &str) -> Option<Action> {
+    let (kind, rest) = text.split_once(' ').unwrap_or((text, ""));
+
+    let action = match kind {
+        "play" => {
+            let (target, card) = rest.split_once(' ')?;
+            Action::PlayCard { card: action_card(card)?, target: parse_target(target)? }
+        },
+        "tune" => {
+            let (die, card) = rest.split_once(' ')?;
+            Action::ElementalTuning { card: action_card(card)?, die: parse_die(die)? }
+        },
+        "skill" => Action::UseSkill { skill: rest.parse().ok()? },
+        "switch" => Action::SwitchCharacter { to: rest.parse().ok()? },
+        "end" => Action::EndRound,
+        "choose" => Action::ChooseActive { character: rest.parse().ok()? },
+        "swap" => Action::SwapCards { cards: rest.parse().ok()? },
+        "reroll" if rest == "-" => Action::Reroll { dice: Dice::new() },
+        "reroll" => {
+            let mut dice = Dice::new();
+
+            for entry in rest.split(',') {
+                let (die, amount) = entry.split_once(':')?;
+                dice.add(parse_die(die)?, amount.parse().ok()?);
+            }
+
+            Action::Reroll { dice }
+        },
+        _ => return None,
+    };
+
+    Some(action)
+}
+
+fn action_card(name: &str) -> Option<ActionCard> {
+    match Card::from_name(name)? {
+        Card::Action(card) => Some(card),
+        Card::Character(_) => None,
+    }
+}
+
+fn parse_die(name: &str) -> Option<Die> {
+    if name == "Omni" {
+        return Some(Die::Omni)
+    }
+
+    Element::ALL.into_iter().find(|element| format!("{element:?}") == name).map(Die::Element)
+}
+
+fn write_target(target: Option<Target>) -> String {
+    match target {
+        None => "-".to_string(),
+        Some(Target::Character(index)) => format!("character:{index}"),
+        Some(Target::Summon(player, index)) => format!("summon:{player:?}:{index}"),
+        Some(Target::Support(index)) => format!("support:{index}"),
+        Some(Target::Transfer(from, to)) => format!("transfer:{from}:{to}"),
+    }
+}
+
+/// `None` if the target can't be read, `Some(None)` if there's no target
+fn parse_target(text: &str) -> Option<Option<Target>> {
+    let parts: Vec<&str> = text.split(':').collect();
+    let index = |part: &str| part.parse::<usize>().ok();
+
+    let target = match parts[..] {
+        ["-"] => None,
+        ["character", character] => Some(Target::Character(index(character)?)),
+        ["summon", "First", summon] => Some(Target::Summon(PlayerId::First, index(summon)?)),
+        ["summon", "Second", summon] => Some(Target::Summon(PlayerId::Second, index(summon)?)),
+        ["support", support] => Some(Target::Support(index(support)?)),
+        ["transfer", from, to] => Some(Target::Transfer(index(from)?, index(to)?)),
+        _ => return None,
+    };
+
+    Some(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    /// Plays a varied sequence of legal actions until the match is over or the limit is reached
+    fn play_out(game: &mut GameState, limit: usize) {
+        for step in 0..limit {
+            let actions = game.legal_actions();
+
+            if actions.is_empty() {
+                break
+            }
+
+            game.apply(actions[step * 7 % actions.len()]).unwrap();
+        }
+    }
+
+    #[test]
+    fn card_names_are_unique() {
+        for card in Card::all() {
+            assert_eq!(Card::from_name(card.name()), Some(card));
+        }
+    }
+
+    #[test]
+    fn events_are_logged() {
+        let mut game = duel(CharacterCard::Fischl);
+
+        use_skill(&mut game, 1);
+
+        let events = game.events();
+        assert!(events.contains(&Event::SkillUsed { player: PlayerId::First, character: 0, skill: SkillKind::ElementalSkill }));
+        assert!(events.contains(&Event::Summoned { player: PlayerId::First, summon: SummonKind::Oz }));
+        assert!(events.iter().any(|event| matches!(event, Event::DamageDealt { amount: 1, .. })));
+        assert!(events.iter().any(|event| matches!(event, Event::DiceSpent { player: PlayerId::First, .. })));
+
+        end_round(&mut game);
+
+        let events = game.events();
+        assert!(events.contains(&Event::PhaseChanged { round: 1, phase: Phase::EndPhase }));
+        assert!(events.contains(&Event::SummonTriggered { player: PlayerId::First, summon: SummonKind::Oz }));
+        assert!(events.contains(&Event::PhaseChanged { round: 2, phase: Phase::ActionPhase }));
+    }
+
+    #[test]
+    fn rejected_actions_are_not_logged() {
+        let mut game = duel(CharacterCard::Fischl);
+        let logged = game.events().len();
+
+        assert!(game.apply(Action::SwitchCharacter { to: 0 }).is_err());
+        assert_eq!(game.events().len(), logged);
+    }
+
+    #[test]
+    fn replays_round_trip() {
+        let first = deck([CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle]);
+        let second = deck(OPPONENTS);
+        let mut game = GameState::new(&first, &second, 7);
+
+        play_out(&mut game, 200);
+
+        let replay = Replay::record(&first, &second, 7, &game);
+        let loaded = Replay::parse(&replay.to_string()).unwrap();
+
+        assert_eq!(loaded, replay);
+        assert_eq!(loaded.play().unwrap(), game);
+    }
+
+    #[test]
+    fn replays_keep_decks_of_other_rules() {
+        let rules = DeckRules::standard().with_characters(2).with_actions(4).with_copies(4);
+        let cards = |characters: [CharacterCard; 2]| characters.map(Card::from).into_iter()
+            .chain([NormalEventCard::Strategize; 4].map(Card::from));
+        let first = Deck::from_iter_with(&rules, cards([CharacterCard::Keqing, CharacterCard::Fischl])).unwrap();
+        let second = Deck::from_iter_with(&rules, cards([CharacterCard::Ganyu, CharacterCard::Xiangling])).unwrap();
+        let mut game = GameState::new(&first, &second, 7);
+
+        play_out(&mut game, 100);
+
+        let replay = Replay::record(&first, &second, 7, &game);
+        let loaded = Replay::parse(&replay.to_string()).unwrap();
+
+        assert_eq!(loaded, replay);
+        assert_eq!(loaded.play().unwrap(), game);
+        assert_eq!(
+            Replay::parse("genius-invokation replay 1\nseed 7\ndeck Strategize\ndeck Keqing"),
+            Err(ReplayError::InvalidDeck(DeckError::NotEnoughCharacterCards(0))),
+        );
+    }
+
+    #[test]
+    fn tampered_replays_are_detected() {
+        let first = deck([CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Noelle]);
+        let second = deck(OPPONENTS);
+        let mut game = GameState::new(&first, &second, 7);
+
+        play_out(&mut game, 50);
+
+        let text = Replay::record(&first, &second, 7, &game).to_string().replacen("seed 7", "seed 8", 1);
+        let replay = Replay::parse(&text).unwrap();
+
+        assert!(matches!(replay.play(), Err(ReplayError::Mismatch { .. } | ReplayError::IllegalAction { .. })));
+        assert_eq!(Replay::parse("genius-invokation replay 99"), Err(ReplayError::UnknownVersion(99)));
+    }
+}
@@ -0,0 +1,259 @@
+use crate::*;
+use super::*;
+use Element::{Anemo, Cryo};
+use SkillKind::*;
+
+const ANEMO: DamageKind = DamageKind::Elemental(Anemo);
+
+pub(crate) static JEAN: [Skill; 3] = [
+    Skill::new("Favonius Bladework", NormalAttack, SkillCost::new(Anemo, 1, 2, 0), physical_attack),
+    Skill::new("Gale Blade", ElementalSkill, SkillCost::new(Anemo, 3, 0, 0), |ctx| {
+        ctx.damage(3, ANEMO);
+
+        let opponent = ctx.player().opponent();
+        if let Some(next) = ctx.game().next_character(opponent) {
+            ctx.game_mut().switch_active(opponent, next);
+        }
+    }),
+    Skill::new("Dandelion Breeze", ElementalBurst, SkillCost::new(Anemo, 4, 0, 3), |ctx| {
+        ctx.heal_all(2);
+        ctx.summon(SummonKind::DandelionField);
+    }),
+];
+
+pub(crate) static SUCROSE: [Skill; 3] = [
+    Skill::new("Wind Spirit Creation", NormalAttack, SkillCost::new(Anemo, 1, 2, 0), catalyst_attack),
+    Skill::new("Astable Anemohypostasis Creation-6308", ElementalSkill, SkillCost::new(Anemo, 3, 0, 0), |ctx| {
+        ctx.damage(3, ANEMO);
+
+        let opponent = ctx.player().opponent();
+        if let Some(previous) = ctx.game().previous_character(opponent) {
+            ctx.game_mut().switch_active(opponent, previous);
+        }
+    }),
+    Skill::new("Forbidden Creation-Isomer 75/Type II", ElementalBurst, SkillCost::new(Anemo, 3, 0, 2), |ctx| {
+        ctx.damage(1, ANEMO);
+        ctx.summon(SummonKind::LargeWindSpirit);
+    }),
+];
+
+pub(crate) static MAGUU_KENKI: [Skill; 4] = [
+    Skill::new("Ichimonji", NormalAttack, SkillCost::new(Anemo, 1, 2, 0), physical_attack),
+    Skill::new("Blustering Blade", ElementalSkill, SkillCost::new(Anemo, 3, 0, 0), |ctx| {
+        ctx.summon(SummonKind::ShadowswordLoneGale);
+    }),
+    Skill::new("Frosty Assault", ElementalSkill, SkillCost::new(Cryo, 3, 0, 0), |ctx| {
+        ctx.summon(SummonKind::ShadowswordGallopingFrost);
+    }),
+    Skill::new("Pseudo Tengu Sweeper", ElementalBurst, SkillCost::new(Anemo, 3, 0, 3), |ctx| {
+        ctx.damage(4, ANEMO);
+
+        // every Shadowsword strikes once more, without using up usages
+        let player = ctx.player();
+        let shadowswords: Vec<SummonKind> = ctx.game().player(player).summons().iter()
+            .filter_map(|summon| match summon.kind() {
+                EntityKind::Summon(kind @ (SummonKind::ShadowswordLoneGale | SummonKind::ShadowswordGallopingFrost)) => Some(kind),
+                _ => None,
+            })
+            .collect();
+
+        for kind in shadowswords {
+            let target = ctx.game().player(player.opponent()).active_index();
+            let info = DamageInfo { attacker: player, source: DamageSource::Summon(kind), target };
+            let (amount, element) = shadowsword(ctx.game(), player, kind);
+
+            ctx.game_mut().deal_damage(info, amount, element);
+        }
+    }),
+];
+
+/// Damage dealt by one of Maguu Kenki's Shadowswords, 1 more with his talent
+fn shadowsword(game: &GameState, player: PlayerId, kind: SummonKind) -> (u8, DamageKind) {
+    let amount = 1 + has_talent(game, player, TalentCard::TranscendentAutomaton) as u8;
+    let element = if kind == SummonKind::ShadowswordGallopingFrost { Cryo } else { Anemo };
+
+    (amount, DamageKind::Elemental(element))
+}
+
+/// Deals 2 Anemo damage and heals the active character for 1 HP during the End Phase, twice.
+/// With Jean's talent, Anemo damage dealt by the player's characters is 1 higher
+pub(crate) struct DandelionField;
+
+impl Effect for DandelionField {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase, Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        let owner = ctx.owner();
+
+        match hook {
+            Hook::EndPhase => {
+                summon_damage(ctx, SummonKind::DandelionField, 2, ANEMO);
+
+                let active = ctx.game().player(owner).active_index();
+                ctx.game_mut().heal(owner, active, 1);
+                ctx.consume();
+            },
+            Hook::OutgoingDamage { info, calc } => {
+                let talent = has_talent(ctx.game(), owner, TalentCard::LandsOfDandelion);
+
+                if talent && own_skill(ctx, info).is_some() && calc.kind() == ANEMO {
+                    calc.bonus(ModifierSource::Card(TalentCard::LandsOfDandelion.into()), 1);
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Deals 2 Anemo damage during the End Phase, three times. The first time the player's
+/// characters trigger Swirl, its damage takes the swirled element (stored in the counter, as
+/// the element's position in [`Element::ALL`] plus one). With Sucrose's talent, it then deals 1
+/// more damage
+pub(crate) struct LargeWindSpirit;
+
+impl Effect for LargeWindSpirit {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase, Trigger::AfterDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        let converted = match ctx.entity().counter() {
+            0 => None,
+            n => Some(Element::ALL[n as usize - 1]),
+        };
+
+        match hook {
+            Hook::EndPhase => {
+                let talent = has_talent(ctx.game(), ctx.owner(), TalentCard::ChaoticEntropy);
+                let amount = 2 + (talent && converted.is_some()) as u8;
+
+                summon_damage(ctx, SummonKind::LargeWindSpirit, amount, DamageKind::Elemental(converted.unwrap_or(Anemo)));
+                ctx.consume();
+            },
+            Hook::AfterDamage { info, breakdown } => {
+                if let (None, Some(Reaction::Swirl(element))) = (converted, breakdown.reaction()) {
+                    if own_skill(ctx, info).is_some() {
+                        let index = Element::ALL.iter().position(|el| *el == element).unwrap();
+                        ctx.set_counter(index as u8 + 1);
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Deals 1 Anemo damage during the End Phase, twice
+pub(crate) struct ShadowswordLoneGale;
+
+impl Effect for ShadowswordLoneGale {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let (amount, kind) = shadowsword(ctx.game(), ctx.owner(), SummonKind::ShadowswordLoneGale);
+
+        summon_damage(ctx, SummonKind::ShadowswordLoneGale, amount, kind);
+        ctx.consume();
+    }
+}
+
+/// Deals 1 Cryo damage during the End Phase, twice
+pub(crate) struct ShadowswordGallopingFrost;
+
+impl Effect for ShadowswordGallopingFrost {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let (amount, kind) = shadowsword(ctx.game(), ctx.owner(), SummonKind::ShadowswordGallopingFrost);
+
+        summon_damage(ctx, SummonKind::ShadowswordGallopingFrost, amount, kind);
+        ctx.consume();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    #[test]
+    fn jean() {
+        let mut game = duel(CharacterCard::Jean);
+
+        use_skill(&mut game, 1);
+        assert_eq!(game.player(PlayerId::Second).character(0).hp(), 7);
+        assert_eq!(game.player(PlayerId::Second).active_index(), 1);
+
+        game.player_mut(PlayerId::First).characters[0].hp = 5;
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(game.player(PlayerId::First).active().hp(), 7);
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 8);
+        assert_eq!(game.player(PlayerId::First).active().hp(), 8);
+    }
+
+    #[test]
+    fn sucrose() {
+        let mut game = duel(CharacterCard::Sucrose);
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 9);
+
+        use_skill(&mut game, 1);
+        assert_eq!(game.player(PlayerId::Second).character(0).hp(), 6);
+        assert_eq!(game.player(PlayerId::Second).active_index(), 2);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 9);
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 7);
+    }
+
+    #[test]
+    fn large_wind_spirit_converts() {
+        let mut game = duel(CharacterCard::Sucrose);
+        charge(&mut game);
+        use_skill(&mut game, 2);
+
+        game.player_mut(PlayerId::Second).characters[0].aura.apply(Element::Pyro);
+        use_skill(&mut game, 0);
+        assert_eq!(summon_counter(&game, SummonKind::LargeWindSpirit), Some(3));
+
+        end_round(&mut game);
+        assert!(opponent(&game).aura().contains(Element::Pyro));
+    }
+
+    #[test]
+    fn maguu_kenki() {
+        let mut game = duel(CharacterCard::MaguuKenki);
+
+        use_skill(&mut game, 1);
+        use_skill(&mut game, 2);
+        assert_eq!(summons(&game), [SummonKind::ShadowswordLoneGale, SummonKind::ShadowswordGallopingFrost]);
+
+        charge(&mut game);
+        use_skill(&mut game, 3);
+        assert_eq!(opponent_hp(&game), 4);
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 2);
+    }
+}
@@ -0,0 +1,450 @@
+use crate::*;
+use super::*;
+use Element::Cryo;
+use SkillKind::*;
+
+const CRYO: DamageKind = DamageKind::Elemental(Cryo);
+
+pub(crate) static KAMISATO_AYAKA: [Skill; 3] = [
+    Skill::new("Kamisato Art: Kabuki", NormalAttack, SkillCost::new(Cryo, 1, 2, 0), physical_attack),
+    Skill::new("Kamisato Art: Hyouka", ElementalSkill, SkillCost::new(Cryo, 3, 0, 0), |ctx| {
+        ctx.damage(3, CRYO);
+    }),
+    Skill::new("Kamisato Art: Soumetsu", ElementalBurst, SkillCost::new(Cryo, 3, 0, 3), |ctx| {
+        ctx.damage(4, CRYO);
+        ctx.summon(SummonKind::FrostflakeSekiNoTo);
+    }),
+];
+
+pub(crate) static CHONGYUN: [Skill; 3] = [
+    Skill::new("Demonbane", NormalAttack, SkillCost::new(Cryo, 1, 2, 0), physical_attack),
+    Skill::new("Chonghua's Layered Frost", ElementalSkill, SkillCost::new(Cryo, 3, 0, 0), |ctx| {
+        ctx.damage(3, CRYO);
+
+        let field = ctx.combat_status(StatusKind::ChonghuaFrostField);
+        if ctx.has_talent() {
+            ctx.game_mut().set_counter(field, 1);
+        }
+    }),
+    Skill::new("Cloud-Parting Star", ElementalBurst, SkillCost::new(Cryo, 3, 0, 3), |ctx| {
+        ctx.damage(7, CRYO);
+    }),
+];
+
+pub(crate) static DIONA: [Skill; 3] = [
+    Skill::new("Kätzlein Style", NormalAttack, SkillCost::new(Cryo, 1, 2, 0), physical_attack),
+    Skill::new("Icy Paws", ElementalSkill, SkillCost::new(Cryo, 3, 0, 0), |ctx| {
+        ctx.damage(2, CRYO);
+
+        let shield = ctx.combat_status(StatusKind::CatClawShield);
+        if ctx.has_talent() {
+            ctx.game_mut().set_usages(shield, 2);
+        }
+    }),
+    Skill::new("Signature Mix", ElementalBurst, SkillCost::new(Cryo, 3, 0, 3), |ctx| {
+        ctx.damage(1, CRYO);
+        ctx.heal(ctx.character(), 2);
+        ctx.summon(SummonKind::DrunkenMist);
+    }),
+];
+
+pub(crate) static GANYU: [Skill; 4] = [
+    Skill::new("Liutian Archery", NormalAttack, SkillCost::new(Cryo, 1, 2, 0), physical_attack),
+    Skill::new("Trail of the Qilin", ElementalSkill, SkillCost::new(Cryo, 3, 0, 0), |ctx| {
+        ctx.damage(1, CRYO);
+        ctx.combat_status(StatusKind::IceLotus);
+    }),
+    Skill::new("Frostflake Arrow", NormalAttack, SkillCost::new(Cryo, 5, 0, 0), |ctx| {
+        let used_before = ctx.game().player(ctx.player()).character(ctx.character()).skill_uses(2) > 0;
+
+        ctx.damage(2, CRYO);
+        ctx.pierce_standby(if ctx.has_talent() && used_before { 3 } else { 2 });
+    }),
+    Skill::new("Celestial Shower", ElementalBurst, SkillCost::new(Cryo, 3, 0, 3), |ctx| {
+        ctx.damage(2, CRYO);
+        ctx.pierce_standby(1);
+        ctx.summon(SummonKind::SacredCryoPearl);
+    }),
+];
+
+pub(crate) static KAEYA: [Skill; 3] = [
+    Skill::new("Ceremonial Bladework", NormalAttack, SkillCost::new(Cryo, 1, 2, 0), physical_attack),
+    Skill::new("Frostgnaw", ElementalSkill, SkillCost::new(Cryo, 3, 0, 0), |ctx| {
+        ctx.damage(3, CRYO);
+
+        if ctx.has_talent() && ctx.uses_this_round() == 0 {
+            ctx.heal(ctx.character(), 2);
+        }
+    }),
+    Skill::new("Glacial Waltz", ElementalBurst, SkillCost::new(Cryo, 4, 0, 2), |ctx| {
+        ctx.damage(1, CRYO);
+        ctx.combat_status(StatusKind::Icicle);
+    }),
+];
+
+pub(crate) static SHENHE: [Skill; 3] = [
+    Skill::new("Dawnstar Piercer", NormalAttack, SkillCost::new(Cryo, 1, 2, 0), physical_attack),
+    Skill::new("Spring Spirit Summoning", ElementalSkill, SkillCost::new(Cryo, 3, 0, 0), |ctx| {
+        ctx.damage(2, CRYO);
+        ctx.combat_status(StatusKind::IcyQuill);
+    }),
+    Skill::new("Divine Maiden's Deliverance", ElementalBurst, SkillCost::new(Cryo, 3, 0, 2), |ctx| {
+        ctx.damage(1, CRYO);
+        ctx.summon(SummonKind::TalismanSpirit);
+    }),
+];
+
+/// Passive of Kamisato Ayaka: infuses her attacks with Cryo whenever she's switched in
+pub(crate) struct KamisatoArtSenho;
+
+impl Effect for KamisatoArtSenho {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSwitch]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterSwitch { player, to, .. } = *hook {
+            if player == ctx.owner() && Some(to) == ctx.character() {
+                ctx.game_mut().add_character_status(player, to, EntityKind::Status(StatusKind::CryoElementalInfusion));
+            }
+        }
+    }
+}
+
+/// Converts physical damage to Cryo this round, dealing 1 more damage with Kamisato Ayaka's
+/// talent
+pub(crate) struct CryoElementalInfusion;
+
+impl Effect for CryoElementalInfusion {
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        let talent = ctx.character()
+            .map(|index| ctx.game().player(ctx.owner()).character(index).talent().is_some())
+            .unwrap_or(false);
+
+        if infuse(ctx, hook, Cryo, StatusKind::CryoElementalInfusion) && talent {
+            if let Hook::OutgoingDamage { calc, .. } = hook {
+                calc.bonus(ModifierSource::Card(TalentCard::KantenSenmyouBlessing.into()), 1);
+            }
+        }
+    }
+}
+
+/// Sword, claymore and polearm wielders deal Cryo instead of physical damage for 2 rounds. With
+/// Chongyun's talent (counter set), their normal attacks also deal 1 more damage
+pub(crate) struct ChonghuaFrostField;
+
+impl Effect for ChonghuaFrostField {
+    fn duration(&self) -> Duration { Duration::Rounds(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            let Some((character, skill)) = own_skill(ctx, info) else { return };
+            let weapon = ctx.game().player(ctx.owner()).character(character).card().weapon();
+
+            if matches!(weapon, Some(WeaponType::Sword | WeaponType::Claymore | WeaponType::Polearm)) {
+                calc.convert(Cryo, ModifierSource::Status(StatusKind::ChonghuaFrostField.name()));
+
+                if skill == NormalAttack && ctx.entity().counter() > 0 {
+                    calc.bonus(ModifierSource::Card(TalentCard::SteadyBreathing.into()), 1);
+                }
+            }
+        }
+    }
+}
+
+/// Shield of 1 point, or 2 with Diona's talent
+pub(crate) struct CatClawShield;
+
+impl Effect for CatClawShield {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Shield)
+    }
+}
+
+/// Reduces damage taken by the active character by 1, twice
+pub(crate) struct IceLotus;
+
+impl Effect for IceLotus {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Reduction { amount: 1, min: 1 })
+    }
+}
+
+/// Deals 2 Cryo damage after the player switches characters, three times
+pub(crate) struct Icicle;
+
+impl Effect for Icicle {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSwitch]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterSwitch { player, .. } = *hook {
+            if player == ctx.owner() {
+                status_damage(ctx, StatusKind::Icicle, 2, CRYO);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+/// Deals 2 Cryo damage during the End Phase, twice
+pub(crate) struct FrostflakeSekiNoTo;
+
+impl Effect for FrostflakeSekiNoTo {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::FrostflakeSekiNoTo, 2, CRYO);
+        ctx.consume();
+    }
+}
+
+/// Deals 1 Cryo damage and heals the active character for 2 HP during the End Phase, twice
+pub(crate) struct DrunkenMist;
+
+impl Effect for DrunkenMist {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::DrunkenMist, 1, CRYO);
+
+        let owner = ctx.owner();
+        let active = ctx.game().player(owner).active_index();
+        ctx.game_mut().heal(owner, active, 2);
+        ctx.consume();
+    }
+}
+
+/// Deals 1 Cryo damage and 1 piercing damage to every standby character during the End Phase,
+/// twice
+pub(crate) struct SacredCryoPearl;
+
+impl Effect for SacredCryoPearl {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::SacredCryoPearl, 1, CRYO);
+
+        let attacker = ctx.owner();
+        for target in ctx.game().standby_characters(attacker.opponent()) {
+            let info = DamageInfo { attacker, source: DamageSource::Summon(SummonKind::SacredCryoPearl), target };
+            ctx.game_mut().deal_damage(info, 1, DamageKind::Piercing);
+        }
+
+        ctx.consume();
+    }
+}
+
+
+/// The player's characters deal 1 more Cryo damage with their skills, twice. With Shenhe's
+/// talent, the first normal attack each round doesn't use it up
+pub(crate) struct IcyQuill;
+
+impl Effect for IcyQuill {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            let Some((_, skill)) = own_skill(ctx, info) else { return };
+
+            if calc.kind() == CRYO {
+                calc.bonus(ModifierSource::Status(StatusKind::IcyQuill.name()), 1);
+
+                let talent = has_talent(ctx.game(), ctx.owner(), TalentCard::MysticalAbandon);
+                if talent && skill == NormalAttack && ctx.can_use_this_round() {
+                    ctx.use_this_round();
+                } else {
+                    ctx.consume();
+                }
+            }
+        }
+    }
+}
+
+/// Deals 1 Cryo damage during the End Phase, twice. While it's on the field, the player's
+/// characters deal 1 more Cryo and physical damage
+pub(crate) struct TalismanSpirit;
+
+impl Effect for TalismanSpirit {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        match hook {
+            Hook::OutgoingDamage { info, calc } if info.attacker == ctx.owner() => {
+                if matches!(calc.kind(), CRYO | DamageKind::Physical) {
+                    calc.bonus(ModifierSource::Status(SummonKind::TalismanSpirit.name()), 1);
+                }
+            },
+            Hook::EndPhase => {
+                summon_damage(ctx, SummonKind::TalismanSpirit, 1, CRYO);
+                ctx.consume();
+            },
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    #[test]
+    fn kamisato_ayaka() {
+        let mut game = duel(CharacterCard::KamisatoAyaka);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 7);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 3);
+        assert_eq!(summons(&game), [SummonKind::FrostflakeSekiNoTo]);
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 1);
+    }
+
+    #[test]
+    fn kamisato_ayaka_infusion() {
+        let mut game = duel_with([CharacterCard::Keqing, CharacterCard::KamisatoAyaka, CharacterCard::Bennett]);
+
+        game.switch_active(PlayerId::First, 1);
+        use_skill(&mut game, 0);
+
+        assert_eq!(opponent_hp(&game), 8);
+        assert!(opponent(&game).aura().contains(Element::Cryo));
+    }
+
+    #[test]
+    fn chongyun() {
+        let mut game = duel(CharacterCard::Chongyun);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 7);
+
+        // the field turns his normal attacks into Cryo damage
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 5);
+        assert!(opponent(&game).aura().contains(Element::Cryo));
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 0);
+    }
+
+    #[test]
+    fn diona() {
+        let mut game = duel(CharacterCard::Diona);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 8);
+        assert_eq!(combat_status_usages(&game, StatusKind::CatClawShield), Some(1));
+
+        game.player_mut(PlayerId::First).characters[0].hp = 5;
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 7);
+        assert_eq!(game.player(PlayerId::First).active().hp(), 7);
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 6);
+        assert_eq!(game.player(PlayerId::First).active().hp(), 9);
+    }
+
+    #[test]
+    fn ganyu() {
+        let mut game = duel(CharacterCard::Ganyu);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 9);
+        assert_eq!(combat_status_usages(&game, StatusKind::IceLotus), Some(2));
+
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 7);
+        assert_eq!(standby_hp(&game), [8, 8]);
+
+        charge(&mut game);
+        use_skill(&mut game, 3);
+        assert_eq!(opponent_hp(&game), 5);
+        assert_eq!(standby_hp(&game), [7, 7]);
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 4);
+        assert_eq!(standby_hp(&game), [6, 6]);
+    }
+
+    #[test]
+    fn kaeya() {
+        let mut game = duel_with([CharacterCard::Kaeya, CharacterCard::Keqing, CharacterCard::Bennett]);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 7);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 6);
+
+        game.switch_active(PlayerId::First, 1);
+        assert_eq!(opponent_hp(&game), 4);
+        assert_eq!(combat_status_usages(&game, StatusKind::Icicle), Some(2));
+    }
+
+    #[test]
+    fn shenhe() {
+        let mut game = duel(CharacterCard::Shenhe);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 8);
+        assert_eq!(combat_status_usages(&game, StatusKind::IcyQuill), Some(2));
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 6);
+        assert_eq!(combat_status_usages(&game, StatusKind::IcyQuill), Some(1));
+        assert_eq!(summons(&game), [SummonKind::TalismanSpirit]);
+
+        // the spirit boosts physical damage too
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 3);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::*;
+use super::*;
+use Element::Dendro;
+use SkillKind::*;
+
+const DENDRO: DamageKind = DamageKind::Elemental(Dendro);
+
+pub(crate) static COLLEI: [Skill; 3] = [
+    Skill::new("Supplicant's Bowmanship", NormalAttack, SkillCost::new(Dendro, 1, 2, 0), physical_attack),
+    Skill::new("Floral Brush", ElementalSkill, SkillCost::new(Dendro, 3, 0, 0), |ctx| {
+        ctx.damage(3, DENDRO);
+
+        if ctx.has_talent() && ctx.uses_this_round() == 0 {
+            ctx.combat_status(StatusKind::Sprout);
+        }
+    }),
+    Skill::new("Trump-Card Kitty", ElementalBurst, SkillCost::new(Dendro, 3, 0, 2), |ctx| {
+        ctx.damage(2, DENDRO);
+        ctx.summon(SummonKind::CuileinAnbar);
+    }),
+];
+
+pub(crate) static JADEPLUME_TERRORSHROOM: [Skill; 3] = [
+    Skill::new("Majestic Dance", NormalAttack, SkillCost::new(Dendro, 1, 2, 0), physical_attack),
+    Skill::new("Volatile Spore Cloud", ElementalSkill, SkillCost::new(Dendro, 3, 0, 0), |ctx| {
+        ctx.damage(3, DENDRO);
+    }),
+    Skill::new("Feather Spreading", ElementalBurst, SkillCost::new(Dendro, 3, 0, 2), |ctx| {
+        let Some(vitality) = ctx.find_status(StatusKind::RadicalVitality).map(|status| (status.id(), status.counter())) else {
+            ctx.damage(4, DENDRO);
+            return
+        };
+
+        // spent after dealing damage, so that this hit doesn't add a stack back
+        let (id, stacks) = vitality;
+        ctx.damage(4 + stacks, DENDRO);
+        ctx.game_mut().set_counter(id, 0);
+    }),
+];
+
+pub(crate) static TIGHNARI: [Skill; 3] = [
+    Skill::new("Khanda Barrier-Buster", NormalAttack, SkillCost::new(Dendro, 1, 2, 0), physical_attack),
+    Skill::new("Vijnana-Phala Mine", ElementalSkill, SkillCost::new(Dendro, 3, 0, 0), |ctx| {
+        ctx.damage(2, DENDRO);
+        ctx.status(StatusKind::VijnanaSuffusion);
+    }),
+    Skill::new("Fashioner's Tanglevine Shaft", ElementalBurst, SkillCost::new(Dendro, 3, 0, 2), |ctx| {
+        ctx.damage(4, DENDRO);
+        ctx.pierce_standby(1);
+    }),
+];
+
+/// Created by Collei's talent: the next time this round the player's characters trigger a
+/// Dendro reaction, deals 1 Dendro damage
+pub(crate) struct Sprout;
+
+impl Effect for Sprout {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterDamage { info, breakdown } = hook {
+            let dendro = matches!(breakdown.reaction(), Some(Reaction::Bloom | Reaction::Burning | Reaction::Quicken));
+
+            if info.attacker == ctx.owner() && dendro {
+                ctx.consume();
+                status_damage(ctx, StatusKind::Sprout, 1, DENDRO);
+            }
+        }
+    }
+}
+
+/// Passive of Jadeplume Terrorshroom: gains a stack (up to 3, or 4 with his talent) every time
+/// he deals damage with a skill or takes damage, which his burst spends for extra damage
+pub(crate) struct RadicalVitality;
+
+impl Effect for RadicalVitality {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        let Hook::AfterDamage { info, .. } = hook else { return };
+        let Some(character) = ctx.character() else { return };
+
+        let owner = ctx.owner();
+        let dealt = own_skill(ctx, info).map(|(attacker, _)| attacker) == Some(character);
+        let taken = info.attacker == owner.opponent() && info.target == character;
+
+        if dealt || taken {
+            let talent = ctx.game().player(owner).character(character).talent() == Some(TalentCard::ProliferatingSpores);
+            let max = if talent { 4 } else { 3 };
+
+            ctx.set_counter((ctx.entity().counter() + 1).min(max));
+        }
+    }
+}
+
+/// Deals 2 Dendro damage during the End Phase, twice
+pub(crate) struct CuileinAnbar;
+
+impl Effect for CuileinAnbar {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::CuileinAnbar, 2, DENDRO);
+        ctx.consume();
+    }
+}
+
+
+/// Tighnari's next two normal attacks deal Dendro damage, each summoning a Clusterbloom Arrow
+pub(crate) struct VijnanaSuffusion;
+
+impl Effect for VijnanaSuffusion {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        match hook {
+            Hook::OutgoingDamage { info, calc } if holder_skill(ctx, info) == Some(NormalAttack) => {
+                calc.convert(Dendro, ModifierSource::Status(StatusKind::VijnanaSuffusion.name()));
+            },
+            Hook::AfterSkill { player, character, skill: NormalAttack } if *player == ctx.owner() && Some(*character) == ctx.character() => {
+                ctx.game_mut().add_summon(*player, SummonKind::ClusterbloomArrow);
+                ctx.consume();
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Tighnari's talent: his normal attacks cost 1 less unaligned die while he has Vijnana
+/// Suffusion
+pub(crate) struct KeenSight;
+
+impl Effect for KeenSight {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::SkillCost]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::SkillCost { character, skill: NormalAttack, cost, .. } = hook {
+            let suffused = ctx.game().player(ctx.owner()).character(*character)
+                .has_status(EntityKind::Status(StatusKind::VijnanaSuffusion));
+
+            if Some(*character) == ctx.character() && suffused {
+                cost.discount_unaligned(1);
+            }
+        }
+    }
+}
+
+/// Deals 1 Dendro damage during the End Phase, stacking up to 2 usages
+pub(crate) struct ClusterbloomArrow;
+
+impl Effect for ClusterbloomArrow {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn stacking(&self) -> Stacking { Stacking::Stack { max: 2 } }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::ClusterbloomArrow, 1, DENDRO);
+        ctx.consume();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    #[test]
+    fn collei() {
+        let mut game = duel(CharacterCard::Collei);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 7);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 5);
+        assert_eq!(summons(&game), [SummonKind::CuileinAnbar]);
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 3);
+    }
+
+    #[test]
+    fn jadeplume_terrorshroom() {
+        let mut game = duel(CharacterCard::JadeplumeTerrorshroom);
+
+        use_skill(&mut game, 0);
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 5);
+
+        let stacks = |game: &GameState| {
+            game.player(PlayerId::First).active().statuses().iter()
+                .find(|status| status.kind() == EntityKind::Status(StatusKind::RadicalVitality))
+                .map(|status| status.counter())
+        };
+        assert_eq!(stacks(&game), Some(2));
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 0);
+        assert_eq!(stacks(&game), Some(0));
+    }
+
+    #[test]
+    fn tighnari() {
+        let mut game = duel(CharacterCard::Tighnari);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 8);
+
+        use_skill(&mut game, 0);
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 4);
+        assert!(opponent(&game).aura().contains(Element::Dendro));
+        assert_eq!(summon_usages(&game, SummonKind::ClusterbloomArrow), Some(2));
+        assert!(!game.player(PlayerId::First).active().has_status(EntityKind::Status(StatusKind::VijnanaSuffusion)));
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 2);
+    }
+}
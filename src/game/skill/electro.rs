@@ -0,0 +1,381 @@
+use crate::*;
+use super::*;
+use Element::Electro;
+use SkillKind::*;
+
+const ELECTRO: DamageKind = DamageKind::Elemental(Electro);
+
+pub(crate) static CYNO: [Skill; 3] = [
+    Skill::new("Invoker's Spear", NormalAttack, SkillCost::new(Electro, 1, 2, 0), physical_attack),
+    Skill::new("Secret Rite: Chasmic Soulfarer", ElementalSkill, SkillCost::new(Electro, 3, 0, 0), |ctx| {
+        ctx.damage(3, ELECTRO);
+    }),
+    Skill::new("Sacred Rite: Wolf's Swiftness", ElementalBurst, SkillCost::new(Electro, 4, 0, 2), |ctx| {
+        ctx.damage(4, ELECTRO);
+
+        if let Some(pathclearer) = ctx.find_status(StatusKind::PactswornPathclearer).copied() {
+            ctx.game_mut().set_counter(pathclearer.id(), pathclearer.counter() + 2);
+        }
+    }),
+];
+
+pub(crate) static FISCHL: [Skill; 3] = [
+    Skill::new("Bolts of Downfall", NormalAttack, SkillCost::new(Electro, 1, 2, 0), physical_attack),
+    Skill::new("Nightrider", ElementalSkill, SkillCost::new(Electro, 3, 0, 0), |ctx| {
+        ctx.damage(1, ELECTRO);
+        ctx.summon(SummonKind::Oz);
+    }),
+    Skill::new("Midnight Phantasmagoria", ElementalBurst, SkillCost::new(Electro, 3, 0, 3), |ctx| {
+        ctx.damage(4, ELECTRO);
+        ctx.pierce_standby(2);
+    }),
+];
+
+pub(crate) static KEQING: [Skill; 3] = [
+    Skill::new("Yunlai Swordsmanship", NormalAttack, SkillCost::new(Electro, 1, 2, 0), physical_attack),
+    Skill::new("Stellar Restoration", ElementalSkill, SkillCost::new(Electro, 3, 0, 0), |ctx| {
+        ctx.damage(3, ELECTRO);
+
+        match ctx.find_status(StatusKind::LightningStiletto).map(|stiletto| stiletto.id()) {
+            Some(stiletto) => {
+                ctx.game_mut().remove_entity(stiletto);
+
+                let infusion = ctx.status(StatusKind::ElectroElementalInfusion);
+                if ctx.has_talent() {
+                    ctx.game_mut().set_duration(infusion, 3);
+                }
+            },
+            None => {
+                ctx.status(StatusKind::LightningStiletto);
+            },
+        }
+    }),
+    Skill::new("Starward Sword", ElementalBurst, SkillCost::new(Electro, 4, 0, 3), |ctx| {
+        ctx.damage(4, ELECTRO);
+        ctx.pierce_standby(3);
+    }),
+];
+
+pub(crate) static RAZOR: [Skill; 3] = [
+    Skill::new("Steel Fang", NormalAttack, SkillCost::new(Electro, 1, 2, 0), physical_attack),
+    Skill::new("Claw and Thunder", ElementalSkill, SkillCost::new(Electro, 3, 0, 0), |ctx| {
+        ctx.damage(3, ELECTRO);
+
+        if ctx.has_talent() {
+            let player = ctx.player();
+            let standby = ctx.game().standby_characters(player).into_iter().find(|index| {
+                let ch = ctx.game().player(player).character(*index);
+                ch.card().element() == Electro && ch.energy() < ch.max_energy()
+            });
+
+            if let Some(index) = standby {
+                ctx.game_mut().gain_energy(player, index, 1);
+            }
+        }
+    }),
+    Skill::new("Lightning Fang", ElementalBurst, SkillCost::new(Electro, 3, 0, 2), |ctx| {
+        ctx.damage(5, ELECTRO);
+        ctx.status(StatusKind::TheWolfWithin);
+    }),
+];
+
+pub(crate) static YAE_MIKO: [Skill; 3] = [
+    Skill::new("Spiritfox Sin-Eater", NormalAttack, SkillCost::new(Electro, 1, 2, 0), catalyst_attack),
+    Skill::new("Yakan Evocation: Sesshou Sakura", ElementalSkill, SkillCost::new(Electro, 3, 0, 0), |ctx| {
+        ctx.summon(SummonKind::SesshouSakura);
+    }),
+    Skill::new("Great Secret Art: Tenko Kenshin", ElementalBurst, SkillCost::new(Electro, 3, 0, 2), |ctx| {
+        ctx.damage(4, ELECTRO);
+
+        let sakura = ctx.game().player(ctx.player()).summons().iter()
+            .find(|summon| summon.kind() == EntityKind::Summon(SummonKind::SesshouSakura))
+            .map(|summon| summon.id());
+
+        if let Some(sakura) = sakura {
+            ctx.game_mut().remove_entity(sakura);
+            ctx.combat_status(StatusKind::TenkoThunderbolts);
+
+            if ctx.has_talent() {
+                ctx.status(StatusKind::RiteOfDispatch);
+            }
+        }
+    }),
+];
+
+/// Passive of Cyno: levels up during each End Phase, going back to 4 after reaching 6. From
+/// level 2 his damage is Electro, from level 4 it's also 2 higher. With his talent, his
+/// elemental skill deals 1 more damage at odd levels
+pub(crate) struct PactswornPathclearer;
+
+impl Effect for PactswornPathclearer {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        let level = ctx.entity().counter();
+
+        match hook {
+            Hook::EndPhase => {
+                ctx.set_counter(if level + 1 >= 6 { 4 } else { level + 1 });
+            },
+            Hook::OutgoingDamage { info, calc } => {
+                let Some(skill) = holder_skill(ctx, info) else { return };
+                let source = ModifierSource::Status(StatusKind::PactswornPathclearer.name());
+                let talent = ctx.character()
+                    .map(|index| ctx.game().player(ctx.owner()).character(index).talent().is_some())
+                    .unwrap_or(false);
+
+                if level >= 2 {
+                    calc.convert(Electro, source);
+                }
+
+                if level >= 4 {
+                    calc.bonus(source, 2);
+                }
+
+                if talent && skill == ElementalSkill && level % 2 == 1 {
+                    calc.bonus(ModifierSource::Card(TalentCard::FeatherfallJudgment.into()), 1);
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Created by Keqing's first Stellar Restoration, turning the next one into an Electro
+/// infusion. Stands in for the Lightning Stiletto card of the real game
+pub(crate) struct LightningStiletto;
+
+impl Effect for LightningStiletto {}
+
+/// Converts the character's physical damage to Electro for 2 rounds (3 with Keqing's talent,
+/// which also adds 1 damage)
+pub(crate) struct ElectroElementalInfusion;
+
+impl Effect for ElectroElementalInfusion {
+    fn duration(&self) -> Duration { Duration::Rounds(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        let talent = ctx.character()
+            .map(|index| ctx.game().player(ctx.owner()).character(index).talent() == Some(TalentCard::ThunderingPenance))
+            .unwrap_or(false);
+
+        if infuse(ctx, hook, Electro, StatusKind::ElectroElementalInfusion) && talent {
+            if let Hook::OutgoingDamage { calc, .. } = hook {
+                calc.bonus(ModifierSource::Card(TalentCard::ThunderingPenance.into()), 1);
+            }
+        }
+    }
+}
+
+/// For 2 rounds, deals 2 Electro damage after the character uses a normal attack or an
+/// elemental skill
+pub(crate) struct TheWolfWithin;
+
+impl Effect for TheWolfWithin {
+    fn duration(&self) -> Duration { Duration::Rounds(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterSkill { player, character, skill } = *hook {
+            if player == ctx.owner() && Some(character) == ctx.character() && skill != ElementalBurst {
+                status_damage(ctx, StatusKind::TheWolfWithin, 2, ELECTRO);
+            }
+        }
+    }
+}
+
+/// Deals 1 Electro damage during the End Phase, twice. With Fischl's talent, also deals 2
+/// Electro damage after she uses a normal attack, using up a usage
+pub(crate) struct Oz;
+
+impl Effect for Oz {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase, Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        match *hook {
+            Hook::EndPhase => {
+                summon_damage(ctx, SummonKind::Oz, 1, ELECTRO);
+                ctx.consume();
+            },
+            Hook::AfterSkill { player, character, skill: NormalAttack }
+                if player == ctx.owner() && ctx.game().player(player).character(character).talent() == Some(TalentCard::StellarPredator) =>
+            {
+                summon_damage(ctx, SummonKind::Oz, 2, ELECTRO);
+                ctx.consume();
+            },
+            _ => (),
+        }
+    }
+}
+
+
+/// Deals 1 Electro damage during the End Phase, three times, stacking up to 6 usages
+pub(crate) struct SesshouSakura;
+
+impl Effect for SesshouSakura {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn stacking(&self) -> Stacking { Stacking::Stack { max: 6 } }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::SesshouSakura, 1, ELECTRO);
+        ctx.consume();
+    }
+}
+
+/// Deals 3 Electro damage at the start of the next Action Phase
+pub(crate) struct TenkoThunderbolts;
+
+impl Effect for TenkoThunderbolts {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::ActionPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        status_damage(ctx, StatusKind::TenkoThunderbolts, 3, ELECTRO);
+        ctx.consume();
+    }
+}
+
+/// Created by Yae Miko's talent: her next elemental skill this round costs 2 less Electro dice
+pub(crate) struct RiteOfDispatch;
+
+impl Effect for RiteOfDispatch {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::SkillCost]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::SkillCost { character, skill: ElementalSkill, cost, consumed, commit } = hook {
+            if Some(*character) == ctx.character() {
+                cost.discount_exact(2);
+                consumed.push(ctx.id());
+
+                if *commit {
+                    ctx.consume();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    #[test]
+    fn cyno() {
+        let mut game = duel(CharacterCard::Cyno);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 7);
+
+        // level 2 after the burst, which infuses his attacks with Electro
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 3);
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 1);
+        assert!(opponent(&game).aura().contains(Element::Electro));
+    }
+
+    #[test]
+    fn fischl() {
+        let mut game = duel(CharacterCard::Fischl);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 9);
+        assert_eq!(summons(&game), [SummonKind::Oz]);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 5);
+        assert_eq!(standby_hp(&game), [8, 8]);
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 4);
+    }
+
+    #[test]
+    fn keqing() {
+        let mut game = duel(CharacterCard::Keqing);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 7);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 4);
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 2);
+        assert!(opponent(&game).aura().contains(Element::Electro));
+    }
+
+    #[test]
+    fn keqing_burst() {
+        let mut game = duel(CharacterCard::Keqing);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 6);
+        assert_eq!(standby_hp(&game), [7, 7]);
+    }
+
+    #[test]
+    fn razor() {
+        let mut game = duel(CharacterCard::Razor);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 7);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 2);
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 0);
+    }
+
+    #[test]
+    fn yae_miko() {
+        let mut game = duel(CharacterCard::YaeMiko);
+
+        use_skill(&mut game, 1);
+        use_skill(&mut game, 1);
+        assert_eq!(summon_usages(&game, SummonKind::SesshouSakura), Some(6));
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 6);
+        assert!(summons(&game).is_empty());
+        assert_eq!(combat_status_usages(&game, StatusKind::TenkoThunderbolts), Some(1));
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 3);
+    }
+}
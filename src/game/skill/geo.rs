@@ -0,0 +1,237 @@
+use crate::*;
+use crate::game::effect::holder_skill;
+use super::*;
+use Element::Geo;
+use SkillKind::*;
+
+const GEO: DamageKind = DamageKind::Elemental(Geo);
+
+pub(crate) static NINGGUANG: [Skill; 3] = [
+    Skill::new("Sparkling Scatter", NormalAttack, SkillCost::new(Geo, 1, 2, 0), catalyst_attack),
+    Skill::new("Jade Screen", ElementalSkill, SkillCost::new(Geo, 3, 0, 0), |ctx| {
+        ctx.damage(2, GEO);
+        ctx.combat_status(StatusKind::JadeScreen);
+    }),
+    Skill::new("Starshatter", ElementalBurst, SkillCost::new(Geo, 3, 0, 3), |ctx| {
+        let screen = ctx.game().player(ctx.player()).has_combat_status(EntityKind::Status(StatusKind::JadeScreen));
+        ctx.damage(if screen { 8 } else { 6 }, GEO);
+    }),
+];
+
+pub(crate) static NOELLE: [Skill; 3] = [
+    Skill::new("Favonius Bladework - Maid", NormalAttack, SkillCost::new(Geo, 1, 2, 0), physical_attack),
+    Skill::new("Breastplate", ElementalSkill, SkillCost::new(Geo, 3, 0, 0), |ctx| {
+        ctx.damage(1, GEO);
+        ctx.combat_status(StatusKind::FullPlate);
+    }),
+    Skill::new("Sweeping Time", ElementalBurst, SkillCost::new(Geo, 4, 0, 2), |ctx| {
+        ctx.damage(4, GEO);
+        ctx.status(StatusKind::SweepingTime);
+    }),
+];
+
+pub(crate) static STONEHIDE_LAWACHURL: [Skill; 3] = [
+    Skill::new("Plama Lawa", NormalAttack, SkillCost::new(Geo, 1, 2, 0), physical_attack),
+    Skill::new("Movo Lawa", ElementalSkill, SkillCost::new(Geo, 3, 0, 0), |ctx| {
+        ctx.damage(3, GEO);
+    }),
+    Skill::new("Upa Shato", ElementalBurst, SkillCost::new(Geo, 3, 0, 2), |ctx| {
+        ctx.damage(5, GEO);
+        ctx.status(StatusKind::Stonehide);
+        ctx.status(StatusKind::StoneForce);
+    }),
+];
+
+/// Reduces damage of at least 2 taken by the active character by 1, twice. With Ningguang's
+/// talent, the player's characters also deal 1 more Geo damage
+pub(crate) struct JadeScreen;
+
+impl Effect for JadeScreen {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            let talent = has_talent(ctx.game(), ctx.owner(), TalentCard::StrategicReserve);
+
+            if talent && own_skill(ctx, info).is_some() && calc.kind() == GEO {
+                calc.bonus(ModifierSource::Card(TalentCard::StrategicReserve.into()), 1);
+            }
+        }
+    }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Reduction { amount: 1, min: 2 })
+    }
+}
+
+/// Shield of 2 points. With Noelle's talent, her normal attacks heal every character of the
+/// player for 1 HP, once per round
+pub(crate) struct FullPlate;
+
+impl Effect for FullPlate {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterSkill { player, character, skill: NormalAttack } = *hook {
+            let owner = ctx.owner();
+            let holder = ctx.game().player(owner).character(character);
+
+            if player == owner && holder.talent() == Some(TalentCard::IGotYourBack) && ctx.can_use_this_round() {
+                for index in 0..ctx.game().player(owner).characters().len() {
+                    ctx.game_mut().heal(owner, index, 1);
+                }
+
+                ctx.use_this_round();
+            }
+        }
+    }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Shield)
+    }
+}
+
+/// For 2 rounds, the character's normal attacks deal Geo damage and 2 more damage, and cost 1
+/// less Geo die once per round
+pub(crate) struct SweepingTime;
+
+impl Effect for SweepingTime {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::SkillCost, Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        match hook {
+            Hook::SkillCost { character, skill: NormalAttack, cost, consumed, commit }
+                if Some(*character) == ctx.character() && ctx.can_use_this_round() =>
+            {
+                cost.discount_exact(1);
+                consumed.push(ctx.id());
+
+                if *commit {
+                    ctx.use_this_round();
+                }
+            },
+            Hook::OutgoingDamage { info, calc } if holder_skill(ctx, info) == Some(NormalAttack) => {
+                calc.convert(Geo, ModifierSource::Status(StatusKind::SweepingTime.name()));
+                calc.bonus(ModifierSource::Status(StatusKind::SweepingTime.name()), 2);
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Passive of Stonehide Lawachurl: reduces damage taken by 1, three times
+pub(crate) struct Stonehide;
+
+impl Effect for Stonehide {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Reduction { amount: 1, min: 1 })
+    }
+}
+
+/// Passive of Stonehide Lawachurl: while he has [`Stonehide`], his physical damage is converted
+/// to Geo and he deals 1 more damage once per round (every time with his talent)
+pub(crate) struct StoneForce;
+
+impl Effect for StoneForce {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        let Some(character) = ctx.character() else { return };
+        let holder = ctx.game().player(ctx.owner()).character(character);
+
+        if !holder.has_status(EntityKind::Status(StatusKind::Stonehide)) {
+            ctx.discard();
+            return
+        }
+
+        let talent = holder.talent() == Some(TalentCard::StonehideReforged);
+
+        if infuse(ctx, hook, Geo, StatusKind::StoneForce) && (talent || ctx.can_use_this_round()) {
+            if let Hook::OutgoingDamage { calc, .. } = hook {
+                calc.bonus(ModifierSource::Status(StatusKind::StoneForce.name()), 1);
+            }
+
+            ctx.use_this_round();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    #[test]
+    fn ningguang() {
+        let mut game = duel(CharacterCard::Ningguang);
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 9);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 7);
+        assert_eq!(combat_status_usages(&game, StatusKind::JadeScreen), Some(2));
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 0);
+    }
+
+    #[test]
+    fn noelle() {
+        let mut game = duel(CharacterCard::Noelle);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 9);
+        assert_eq!(combat_status_usages(&game, StatusKind::FullPlate), Some(2));
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 5);
+
+        // normal attacks deal Geo damage, and cost one die less
+        let (cost, _) = game.skill_cost(PlayerId::First, 0);
+        assert_eq!(cost.dice(), 2);
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 1);
+    }
+
+    #[test]
+    fn stonehide_lawachurl() {
+        let mut game = duel(CharacterCard::StonehideLawachurl);
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 7);
+
+        // the bonus only applies once per round
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 4);
+
+        let holder = game.player(PlayerId::First).active();
+        assert!(holder.has_status(EntityKind::Status(StatusKind::Stonehide)));
+        assert!(holder.has_status(EntityKind::Status(StatusKind::StoneForce)));
+    }
+}
@@ -0,0 +1,516 @@
+use crate::*;
+use super::*;
+use Element::Hydro;
+use SkillKind::*;
+
+const HYDRO: DamageKind = DamageKind::Elemental(Hydro);
+
+pub(crate) static BARBARA: [Skill; 3] = [
+    Skill::new("Whisper of Water", NormalAttack, SkillCost::new(Hydro, 1, 2, 0), catalyst_attack),
+    Skill::new("Let the Show Begin", ElementalSkill, SkillCost::new(Hydro, 3, 0, 0), |ctx| {
+        ctx.damage(1, HYDRO);
+        ctx.summon(SummonKind::MelodyLoop);
+    }),
+    Skill::new("Shining Miracle", ElementalBurst, SkillCost::new(Hydro, 3, 0, 3), |ctx| {
+        ctx.heal_all(4);
+    }),
+];
+
+pub(crate) static MONA: [Skill; 3] = [
+    Skill::new("Ripple of Fate", NormalAttack, SkillCost::new(Hydro, 1, 2, 0), catalyst_attack),
+    Skill::new("Mirror Reflection of Doom", ElementalSkill, SkillCost::new(Hydro, 3, 0, 0), |ctx| {
+        ctx.damage(1, HYDRO);
+        ctx.summon(SummonKind::Reflection);
+    }),
+    Skill::new("Stellaris Phantasm", ElementalBurst, SkillCost::new(Hydro, 3, 0, 3), |ctx| {
+        ctx.damage(4, HYDRO);
+        ctx.combat_status(StatusKind::IllusoryBubble);
+    }),
+];
+
+pub(crate) static XINGQIU: [Skill; 3] = [
+    Skill::new("Guhua Style", NormalAttack, SkillCost::new(Hydro, 1, 2, 0), physical_attack),
+    Skill::new("Fatal Rainscreen", ElementalSkill, SkillCost::new(Hydro, 3, 0, 0), |ctx| {
+        ctx.damage(2, HYDRO);
+        ctx.apply_self(Hydro);
+
+        let rain_sword = ctx.combat_status(StatusKind::RainSword);
+        if ctx.has_talent() {
+            ctx.game_mut().set_usages(rain_sword, 3);
+        }
+    }),
+    Skill::new("Raincutter", ElementalBurst, SkillCost::new(Hydro, 3, 0, 2), |ctx| {
+        ctx.damage(1, HYDRO);
+        ctx.apply_self(Hydro);
+        ctx.combat_status(StatusKind::RainbowBladework);
+    }),
+];
+
+pub(crate) static MIRROR_MAIDEN: [Skill; 3] = [
+    Skill::new("Water Ball", NormalAttack, SkillCost::new(Hydro, 1, 2, 0), catalyst_attack),
+    Skill::new("Influx Blast", ElementalSkill, SkillCost::new(Hydro, 3, 0, 0), |ctx| {
+        ctx.damage(2, HYDRO);
+
+        let opponent = ctx.player().opponent();
+        let target = ctx.game().player(opponent).active_index();
+        let refraction = ctx.game_mut().add_character_status(opponent, target, EntityKind::Status(StatusKind::Refraction));
+
+        if ctx.has_talent() {
+            ctx.game_mut().set_duration(refraction, 3);
+        }
+    }),
+    Skill::new("Rippled Reflection", ElementalBurst, SkillCost::new(Hydro, 3, 0, 2), |ctx| {
+        ctx.damage(5, HYDRO);
+    }),
+];
+
+pub(crate) static FURINA: [Skill; 3] = [
+    Skill::new("Soloist's Solicitation", NormalAttack, SkillCost::new(Hydro, 1, 2, 0), physical_attack),
+    Skill::new("Salon Solitaire", ElementalSkill, SkillCost::new(Hydro, 3, 0, 0), |ctx| {
+        ctx.summon(SummonKind::SalonMembers);
+    }),
+    Skill::new("Let the People Rejoice", ElementalBurst, SkillCost::new(Hydro, 4, 0, 2), |ctx| {
+        ctx.damage(2, HYDRO);
+        ctx.combat_status(StatusKind::UniversalRevelry);
+    }),
+];
+
+pub(crate) static RHODEIA_OF_LOCH: [Skill; 4] = [
+    Skill::new("Surge", NormalAttack, SkillCost::new(Hydro, 1, 2, 0), catalyst_attack),
+    Skill::new("Oceanid Mimic Summoning", ElementalSkill, SkillCost::new(Hydro, 3, 0, 0), |ctx| {
+        summon_mimic(ctx);
+    }),
+    Skill::new("The Myriad Wilds", ElementalSkill, SkillCost::new(Hydro, 5, 0, 0), |ctx| {
+        summon_mimic(ctx);
+        summon_mimic(ctx);
+    }),
+    Skill::new("Tide and Torrent", ElementalBurst, SkillCost::new(Hydro, 3, 0, 3), |ctx| {
+        let summons: Vec<EntityId> = ctx.game().player(ctx.player()).summons().iter().map(|summon| summon.id()).collect();
+
+        ctx.damage(2 + 2 * summons.len() as u8, HYDRO);
+
+        if ctx.has_talent() {
+            for id in summons {
+                let usages = ctx.game().entity(id).and_then(|(_, summon)| summon.usages()).unwrap_or(0);
+                ctx.game_mut().set_usages(id, usages + 1);
+            }
+        }
+    }),
+];
+
+/// Summons a random Oceanic Mimic, preferring the ones that aren't on the field yet
+fn summon_mimic(ctx: &mut SkillContext) {
+    const MIMICS: [SummonKind; 3] = [
+        SummonKind::OceanicMimicSquirrel, SummonKind::OceanicMimicRaptor, SummonKind::OceanicMimicFrog,
+    ];
+
+    let summons = ctx.game().player(ctx.player()).summons();
+    let mut options: Vec<SummonKind> = MIMICS.into_iter()
+        .filter(|mimic| !summons.iter().any(|summon| summon.kind() == EntityKind::Summon(*mimic)))
+        .collect();
+
+    if options.is_empty() {
+        options = MIMICS.to_vec();
+    }
+
+    let index = ctx.game_mut().rng().below(options.len());
+    ctx.summon(options[index]);
+}
+
+/// Heals every character for 1 HP and attaches Hydro to the active character during the End
+/// Phase, twice. With Barbara's talent, also makes the first switch of each round cheaper
+pub(crate) struct MelodyLoop;
+
+impl Effect for MelodyLoop {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase, Trigger::Speed]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        let owner = ctx.owner();
+
+        match hook {
+            Hook::EndPhase => {
+                for index in 0..ctx.game().player(owner).characters().len() {
+                    ctx.game_mut().heal(owner, index, 1);
+                }
+
+                let active = ctx.game().player(owner).active_index();
+                ctx.game_mut().apply_element(owner, active, Hydro);
+                ctx.consume();
+            },
+            Hook::Speed { action: CostAction::Switch { .. }, cost, consumed, commit, .. } => {
+                let talent = has_talent(ctx.game(), owner, TalentCard::GloriousSeason);
+
+                if talent && cost.amount() > 0 && ctx.can_use_this_round() {
+                    cost.discount(1);
+                    consumed.push(ctx.id());
+
+                    if *commit {
+                        ctx.use_this_round();
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Reduces damage taken by the active character by 1 once, then deals 1 Hydro damage during
+/// the End Phase before leaving
+pub(crate) struct Reflection;
+
+impl Effect for Reflection {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::Reflection, 1, HYDRO);
+        ctx.discard();
+    }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Reduction { amount: 1, min: 1 })
+    }
+
+    fn discard_when_spent(&self) -> bool { false }
+}
+
+/// Passive of Mona: switching away from her is a fast action once per round. With her talent,
+/// Hydro-related reactions deal 2 more damage while she's the active character
+pub(crate) struct IllusoryTorrent;
+
+impl Effect for IllusoryTorrent {
+    fn per_round(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::Speed, Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        match hook {
+            Hook::Speed { action: CostAction::Switch { from, .. }, fast, consumed, commit, .. }
+                if Some(*from) == ctx.character() && !*fast && ctx.can_use_this_round() =>
+            {
+                *fast = true;
+                consumed.push(ctx.id());
+
+                if *commit {
+                    ctx.use_this_round();
+                }
+            },
+            Hook::OutgoingDamage { info, calc } => {
+                let holder = ctx.character().map(|index| ctx.game().player(ctx.owner()).character(index));
+                let talent = holder.map(|ch| ch.talent() == Some(TalentCard::ProphecyOfSubmersion)).unwrap_or(false);
+                let reaction = ctx.game().predict_reaction(info, calc.kind());
+                let hydro_related = matches!(reaction, Some(
+                    Reaction::Vaporize | Reaction::ElectroCharged | Reaction::Frozen | Reaction::Bloom
+                    | Reaction::Swirl(Hydro) | Reaction::Crystallize(Hydro)
+                ));
+
+                if talent && ctx.is_active() && info.attacker == ctx.owner() && hydro_related {
+                    calc.bonus(ModifierSource::Card(TalentCard::ProphecyOfSubmersion.into()), 2);
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Doubles the next damage dealt by one of the player's characters
+pub(crate) struct IllusoryBubble;
+
+impl Effect for IllusoryBubble {
+    fn usages(&self) -> Option<u8> { Some(1) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            if own_skill(ctx, info).is_some() {
+                calc.multiply(ModifierSource::Status(StatusKind::IllusoryBubble.name()), 2);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+/// Reduces damage of 3 or more taken by the active character by 1, twice (three times with
+/// Xingqiu's talent)
+pub(crate) struct RainSword;
+
+impl Effect for RainSword {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Reduction { amount: 1, min: 3 })
+    }
+}
+
+/// Deals 1 Hydro damage after each normal attack, three times
+pub(crate) struct RainbowBladework;
+
+impl Effect for RainbowBladework {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterSkill { player, skill: NormalAttack, .. } = *hook {
+            if player == ctx.owner() {
+                status_damage(ctx, StatusKind::RainbowBladework, 1, HYDRO);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+/// Switching away from the character costs 1 more die, for 2 rounds (3 with Mirror Maiden's
+/// talent)
+pub(crate) struct Refraction;
+
+impl Effect for Refraction {
+    fn duration(&self) -> Duration { Duration::Rounds(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::Speed]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::Speed { action: CostAction::Switch { from, .. }, cost, .. } = hook {
+            if Some(*from) == ctx.character() {
+                cost.increase(1);
+            }
+        }
+    }
+}
+
+/// Deals 2 Hydro damage during the End Phase, twice
+pub(crate) struct OceanicMimicSquirrel;
+
+impl Effect for OceanicMimicSquirrel {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::OceanicMimicSquirrel, 2, HYDRO);
+        ctx.consume();
+    }
+}
+
+/// Deals 1 Hydro damage during the End Phase, three times
+pub(crate) struct OceanicMimicRaptor;
+
+impl Effect for OceanicMimicRaptor {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::OceanicMimicRaptor, 1, HYDRO);
+        ctx.consume();
+    }
+}
+
+/// Reduces damage taken by the active character by 1, twice. Once used up, deals 2 Hydro
+/// damage during the End Phase and leaves
+pub(crate) struct OceanicMimicFrog;
+
+impl Effect for OceanicMimicFrog {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        if ctx.entity().usages() == Some(0) {
+            summon_damage(ctx, SummonKind::OceanicMimicFrog, 2, HYDRO);
+            ctx.discard();
+        }
+    }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Reduction { amount: 1, min: 1 })
+    }
+
+    fn discard_when_spent(&self) -> bool { false }
+}
+
+
+/// Deals 1 Hydro damage during the End Phase, twice. With Furina's talent, also heals the
+/// active character for 1 HP
+pub(crate) struct SalonMembers;
+
+impl Effect for SalonMembers {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::SalonMembers, 1, HYDRO);
+
+        let owner = ctx.owner();
+        if has_talent(ctx.game(), owner, TalentCard::EndlessWaltz) {
+            let active = ctx.game().player(owner).active_index();
+            ctx.game_mut().heal(owner, active, 1);
+        }
+
+        ctx.consume();
+    }
+}
+
+/// For 2 rounds, the player's skills deal 1 more damage, three times
+pub(crate) struct UniversalRevelry;
+
+impl Effect for UniversalRevelry {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn duration(&self) -> Duration { Duration::Rounds(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            if own_skill(ctx, info).is_some() {
+                calc.bonus(ModifierSource::Status(StatusKind::UniversalRevelry.name()), 1);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    #[test]
+    fn barbara() {
+        let mut game = duel(CharacterCard::Barbara);
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 9);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 8);
+        assert_eq!(summons(&game), [SummonKind::MelodyLoop]);
+
+        game.player_mut(PlayerId::First).characters[0].hp = 3;
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(game.player(PlayerId::First).active().hp(), 7);
+
+        end_round(&mut game);
+        assert_eq!(game.player(PlayerId::First).active().hp(), 8);
+        assert!(game.player(PlayerId::First).active().aura().contains(Element::Hydro));
+    }
+
+    #[test]
+    fn mona() {
+        let mut game = duel(CharacterCard::Mona);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 9);
+        assert_eq!(summons(&game), [SummonKind::Reflection]);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 5);
+
+        // the bubble doubles the next damage
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 3);
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 2);
+        assert!(summons(&game).is_empty());
+    }
+
+    #[test]
+    fn xingqiu() {
+        let mut game = duel(CharacterCard::Xingqiu);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 8);
+        assert!(game.player(PlayerId::First).active().aura().contains(Element::Hydro));
+        assert_eq!(combat_status_usages(&game, StatusKind::RainSword), Some(2));
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 7);
+
+        // 2 physical from the attack, then 1 Hydro from Rainbow Bladework
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 4);
+        assert_eq!(combat_status_usages(&game, StatusKind::RainbowBladework), Some(2));
+    }
+
+    #[test]
+    fn mirror_maiden() {
+        let mut game = duel(CharacterCard::MirrorMaiden);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 8);
+        assert!(opponent(&game).has_status(EntityKind::Status(StatusKind::Refraction)));
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 3);
+    }
+
+    #[test]
+    fn rhodeia_of_loch() {
+        let mut game = duel(CharacterCard::RhodeiaOfLoch);
+
+        use_skill(&mut game, 2);
+        assert_eq!(summons(&game).len(), 2);
+
+        use_skill(&mut game, 1);
+        assert_eq!(summons(&game).len(), 3);
+
+        charge(&mut game);
+        use_skill(&mut game, 3);
+        assert_eq!(opponent_hp(&game), 2);
+    }
+
+    #[test]
+    fn furina() {
+        let mut game = duel(CharacterCard::Furina);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 10);
+        assert_eq!(summons(&game), [SummonKind::SalonMembers]);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 8);
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 5);
+        assert_eq!(combat_status_usages(&game, StatusKind::UniversalRevelry), Some(2));
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 4);
+    }
+}
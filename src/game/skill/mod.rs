@@ -0,0 +1,424 @@
+use crate::*;
+use crate::game::effect::holder_skill;
+
+pub(crate) mod cryo;
+pub(crate) mod hydro;
+pub(crate) mod pyro;
+pub(crate) mod electro;
+pub(crate) mod anemo;
+pub(crate) mod geo;
+pub(crate) mod dendro;
+
+/// The three kinds of skills every character has
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SkillKind {
+    NormalAttack,
+    ElementalSkill,
+    ElementalBurst,
+}
+
+/// Dice and energy required to use a skill
+/// 
+/// Skills are paid with dice of the character's element plus any unaligned dice. It converts
+/// into the more general [`CardCost`], which is how it gets paid.
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub struct SkillCost {
+    element: Element,
+    exact: u8,
+    unaligned: u8,
+    energy: u8,
+}
+
+impl SkillCost {
+    pub const fn new(element: Element, exact: u8, unaligned: u8, energy: u8) -> Self {
+        Self { element, exact, unaligned, energy }
+    }
+
+    pub fn element(&self) -> Element {
+        self.element
+    }
+
+    /// Amount of dice of [`element`](Self::element) required
+    pub fn exact(&self) -> u8 {
+        self.exact
+    }
+
+    /// Amount of dice of any element required
+    pub fn unaligned(&self) -> u8 {
+        self.unaligned
+    }
+
+    pub fn energy(&self) -> u8 {
+        self.energy
+    }
+
+    /// Reduces the amount of unaligned dice required
+    pub fn discount_unaligned(&mut self, amount: u8) {
+        self.unaligned = self.unaligned.saturating_sub(amount);
+    }
+
+    /// Reduces the amount of dice of the skill's element required, discounting unaligned dice
+    /// instead once there's none left
+    pub fn discount_exact(&mut self, amount: u8) {
+        let exact = self.exact.min(amount);
+        self.exact -= exact;
+        self.unaligned = self.unaligned.saturating_sub(amount - exact);
+    }
+
+    pub fn dice(&self) -> u8 {
+        self.exact + self.unaligned
+    }
+}
+
+impl From<SkillCost> for CardCost {
+    fn from(cost: SkillCost) -> Self {
+        CardCost::ZERO
+            .with_exact(cost.element, cost.exact)
+            .with_unaligned(cost.unaligned)
+            .with_energy(cost.energy)
+    }
+}
+
+/// One of the skills printed on a character card
+/// 
+/// Passive skills aren't listed here, they're statuses the character starts the match with
+/// (see [`CharacterCard::passives`]).
+pub struct Skill {
+    name: &'static str,
+    kind: SkillKind,
+    cost: SkillCost,
+    effect: fn(&mut SkillContext),
+}
+
+impl Skill {
+    pub(crate) const fn new(name: &'static str, kind: SkillKind, cost: SkillCost, effect: fn(&mut SkillContext)) -> Self {
+        Self { name, kind, cost, effect }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn kind(&self) -> SkillKind {
+        self.kind
+    }
+
+    /// The cost printed on the card, before any discount
+    pub fn cost(&self) -> SkillCost {
+        self.cost
+    }
+}
+
+impl std::fmt::Debug for Skill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Skill")
+            .field("name", &self.name)
+            .field("kind", &self.kind)
+            .field("cost", &self.cost)
+            .finish()
+    }
+}
+
+impl CharacterCard {
+    /// Every skill of this character that can be used as an action, in the order printed on
+    /// the card
+    pub fn skills(&self) -> &'static [Skill] {
+        match self {
+            Self::KamisatoAyaka         => &cryo::KAMISATO_AYAKA,
+            Self::Shenhe                => &cryo::SHENHE,
+            Self::Chongyun              => &cryo::CHONGYUN,
+            Self::Diona                 => &cryo::DIONA,
+            Self::Ganyu                 => &cryo::GANYU,
+            Self::Kaeya                 => &cryo::KAEYA,
+            Self::Barbara               => &hydro::BARBARA,
+            Self::Mona                  => &hydro::MONA,
+            Self::Xingqiu               => &hydro::XINGQIU,
+            Self::MirrorMaiden          => &hydro::MIRROR_MAIDEN,
+            Self::RhodeiaOfLoch         => &hydro::RHODEIA_OF_LOCH,
+            Self::Furina                => &hydro::FURINA,
+            Self::Bennett               => &pyro::BENNETT,
+            Self::Diluc                 => &pyro::DILUC,
+            Self::Xiangling             => &pyro::XIANGLING,
+            Self::Yoimiya               => &pyro::YOIMIYA,
+            Self::FatuiPyroAgent        => &pyro::FATUI_PYRO_AGENT,
+            Self::Dehya                 => &pyro::DEHYA,
+            Self::Cyno                  => &electro::CYNO,
+            Self::Fischl                => &electro::FISCHL,
+            Self::Keqing                => &electro::KEQING,
+            Self::Razor                 => &electro::RAZOR,
+            Self::YaeMiko               => &electro::YAE_MIKO,
+            Self::Jean                  => &anemo::JEAN,
+            Self::Sucrose               => &anemo::SUCROSE,
+            Self::MaguuKenki            => &anemo::MAGUU_KENKI,
+            Self::Ningguang             => &geo::NINGGUANG,
+            Self::Noelle                => &geo::NOELLE,
+            Self::StonehideLawachurl    => &geo::STONEHIDE_LAWACHURL,
+            Self::Collei                => &dendro::COLLEI,
+            Self::JadeplumeTerrorshroom => &dendro::JADEPLUME_TERRORSHROOM,
+            Self::Tighnari              => &dendro::TIGHNARI,
+        }
+    }
+
+    /// Statuses this character starts the match with, which implement its passive skills
+    pub fn passives(&self) -> &'static [StatusKind] {
+        match self {
+            Self::KamisatoAyaka         => &[StatusKind::KamisatoArtSenho],
+            Self::Mona                  => &[StatusKind::IllusoryTorrent],
+            Self::FatuiPyroAgent        => &[StatusKind::Stealth],
+            Self::Cyno                  => &[StatusKind::PactswornPathclearer],
+            Self::StonehideLawachurl    => &[StatusKind::Stonehide, StatusKind::StoneForce],
+            Self::JadeplumeTerrorshroom => &[StatusKind::RadicalVitality],
+            _ => &[],
+        }
+    }
+}
+
+impl TalentCard {
+    /// The skill (as an index into [`CharacterCard::skills`]) used right after equipping this
+    /// talent, for talents that are combat actions
+    pub fn skill(&self) -> Option<usize> {
+        match self {
+            Self::KantenSenmyouBlessing => None,
+            Self::UndividedHeart        => Some(2),
+            Self::ProphecyOfSubmersion  |
+            Self::LandsOfDandelion      |
+            Self::ChaoticEntropy        |
+            Self::GrandExpectation      |
+            Self::StonehideReforged     |
+            Self::TheShrinesSacredShade => Some(2),
+            Self::StreamingSurge        => Some(3),
+            _ => Some(1),
+        }
+    }
+}
+
+/// Extra behaviour of an equipped talent card, for talents that do more than change how their
+/// character's skills work
+pub(crate) fn talent_effect(card: TalentCard) -> Option<&'static dyn Effect> {
+    match card {
+        TalentCard::FlowingFlame    => Some(&pyro::FlowingFlame),
+        TalentCard::StalwartAndTrue => Some(&pyro::StalwartAndTrue),
+        TalentCard::KeenSight       => Some(&dendro::KeenSight),
+        _ => None,
+    }
+}
+
+/// Handle given to a skill when it's used
+pub struct SkillContext<'g> {
+    game: &'g mut GameState,
+    player: PlayerId,
+    character: usize,
+    kind: SkillKind,
+}
+
+impl<'g> SkillContext<'g> {
+    pub fn game(&self) -> &GameState {
+        self.game
+    }
+
+    pub fn game_mut(&mut self) -> &mut GameState {
+        self.game
+    }
+
+    /// The player using the skill
+    pub fn player(&self) -> PlayerId {
+        self.player
+    }
+
+    /// The position of the character using the skill
+    pub fn character(&self) -> usize {
+        self.character
+    }
+
+    pub fn kind(&self) -> SkillKind {
+        self.kind
+    }
+
+    /// Whether the character has their talent card equipped
+    pub fn has_talent(&self) -> bool {
+        self.game.player(self.player).character(self.character).talent().is_some()
+    }
+
+    /// How many times this character already used this kind of skill during this round, not
+    /// counting the current use
+    pub fn uses_this_round(&self) -> usize {
+        self.game.player(self.player).uses_this_round(self.character, self.kind)
+    }
+
+    /// Damage of the character's own element
+    pub fn elemental(&self) -> DamageKind {
+        let card = self.game.player(self.player).character(self.character).card();
+        DamageKind::Elemental(card.element())
+    }
+
+    /// Deals damage to the opposing active character
+    pub fn damage(&mut self, amount: u8, kind: DamageKind) -> DamageBreakdown {
+        let target = self.game.player(self.player.opponent()).active_index();
+        let source = DamageSource::Skill { character: self.character, skill: self.kind };
+
+        self.game.deal_damage(DamageInfo { attacker: self.player, source, target }, amount, kind)
+    }
+
+    /// Deals piercing damage to every opposing standby character
+    pub fn pierce_standby(&mut self, amount: u8) {
+        let opponent = self.player.opponent();
+        let source = DamageSource::Skill { character: self.character, skill: self.kind };
+
+        for target in self.game.standby_characters(opponent) {
+            self.game.deal_damage(DamageInfo { attacker: self.player, source, target }, amount, DamageKind::Piercing);
+        }
+    }
+
+    pub fn heal(&mut self, character: usize, amount: u8) {
+        self.game.heal(self.player, character, amount);
+    }
+
+    pub fn heal_all(&mut self, amount: u8) {
+        for index in 0..self.game.player(self.player).characters().len() {
+            self.game.heal(self.player, index, amount);
+        }
+    }
+
+    /// Attaches an element to the character using the skill
+    pub fn apply_self(&mut self, element: Element) {
+        self.game.apply_element(self.player, self.character, element);
+    }
+
+    /// Creates a summon, unless the player's summon zone is full
+    pub fn summon(&mut self, kind: SummonKind) -> Option<EntityId> {
+        self.game.add_summon(self.player, kind)
+    }
+
+    /// Adds a status to the character using the skill
+    pub fn status(&mut self, kind: StatusKind) -> EntityId {
+        self.game.add_character_status(self.player, self.character, EntityKind::Status(kind))
+    }
+
+    pub fn combat_status(&mut self, kind: StatusKind) -> EntityId {
+        self.game.add_combat_status(self.player, EntityKind::Status(kind))
+    }
+
+    /// The status of this kind attached to the character using the skill, if any
+    pub fn find_status(&self, kind: StatusKind) -> Option<&Entity> {
+        self.game.player(self.player).character(self.character).statuses().iter()
+            .find(|status| status.kind() == EntityKind::Status(kind))
+    }
+}
+
+impl GameState {
+    /// Alive characters of the player other than the active one
+    pub fn standby_characters(&self, player: PlayerId) -> Vec<usize> {
+        let state = self.player(player);
+
+        (0..state.characters().len())
+            .filter(|index| *index != state.active_index() && state.character(*index).is_alive())
+            .collect()
+    }
+
+    /// The cost of one of the active character's skills after every discount, alongside the
+    /// entities whose usages the discounts would use up
+    pub fn skill_cost(&self, player: PlayerId, index: usize) -> (SkillCost, Vec<EntityId>) {
+        let mut hook = self.skill_hook(player, index, false);
+        self.preview(player, &mut hook);
+
+        let Hook::SkillCost { cost, consumed, .. } = hook else { unreachable!() };
+        (cost, consumed)
+    }
+
+    /// Same as [`skill_cost`](Self::skill_cost), but actually using up the discounts
+    pub(crate) fn commit_skill_cost(&mut self, player: PlayerId, index: usize) -> SkillCost {
+        let mut hook = self.skill_hook(player, index, true);
+        self.dispatch(player, &mut hook);
+
+        let Hook::SkillCost { cost, .. } = hook else { unreachable!() };
+        cost
+    }
+
+    fn skill_hook(&self, player: PlayerId, index: usize, commit: bool) -> Hook {
+        let character = self.player(player).active_index();
+        let skill = &self.player(player).active().card().skills()[index];
+
+        Hook::SkillCost { character, skill: skill.kind(), cost: skill.cost(), consumed: Vec::new(), commit }
+    }
+
+    /// Makes the active character use a skill, once it's been paid for
+    pub(crate) fn use_skill(&mut self, player: PlayerId, index: usize) {
+        let character = self.player(player).active_index();
+        let skill = &self.player(player).active().card().skills()[index];
+        let kind = skill.kind();
+
+        self.record(Event::SkillUsed { player, character, skill: kind });
+
+        if kind == SkillKind::ElementalBurst {
+            self.lose_energy(player, character, u8::MAX);
+        }
+
+        (skill.effect)(&mut SkillContext { game: self, player, character, kind });
+
+        if kind != SkillKind::ElementalBurst {
+            self.gain_energy(player, character, 1);
+        }
+
+        let state = self.player_mut(player);
+        state.skills_used.push((character, kind));
+        state.characters[character].skill_uses[index] += 1;
+
+        self.dispatch_all(&mut Hook::AfterSkill { player, character, skill: kind });
+    }
+}
+
+/// Summons that deal damage to the opposing active character during the End Phase, which most
+/// summons do
+pub(crate) fn summon_damage(ctx: &mut Context, summon: SummonKind, amount: u8, kind: DamageKind) -> DamageBreakdown {
+    let attacker = ctx.owner();
+    let target = ctx.game().player(attacker.opponent()).active_index();
+    let info = DamageInfo { attacker, source: DamageSource::Summon(summon), target };
+
+    ctx.game_mut().deal_damage(info, amount, kind)
+}
+
+/// Deals damage to the opposing active character on behalf of a status
+pub(crate) fn status_damage(ctx: &mut Context, status: StatusKind, amount: u8, kind: DamageKind) -> DamageBreakdown {
+    let attacker = ctx.owner();
+    let target = ctx.game().player(attacker.opponent()).active_index();
+    let info = DamageInfo { attacker, source: DamageSource::Effect(EntityKind::Status(status)), target };
+
+    ctx.game_mut().deal_damage(info, amount, kind)
+}
+
+/// Whether the owner has a character with this talent equipped
+pub(crate) fn has_talent(game: &GameState, player: PlayerId, talent: TalentCard) -> bool {
+    game.player(player).characters().iter().any(|ch| ch.talent() == Some(talent) && ch.is_alive())
+}
+
+/// Normal attack of characters holding a real weapon
+pub(crate) fn physical_attack(ctx: &mut SkillContext) {
+    ctx.damage(2, DamageKind::Physical);
+}
+
+/// Normal attack of catalyst users, which deals damage of their element
+pub(crate) fn catalyst_attack(ctx: &mut SkillContext) {
+    let kind = ctx.elemental();
+    ctx.damage(1, kind);
+}
+
+/// Converts the physical damage dealt by the skills of the character holding this status,
+/// returning whether the damage came from one of those skills
+pub(crate) fn infuse(ctx: &Context, hook: &mut Hook, element: Element, status: StatusKind) -> bool {
+    match hook {
+        Hook::OutgoingDamage { info, calc } if holder_skill(ctx, info).is_some() => {
+            calc.convert(element, ModifierSource::Status(status.name()));
+            true
+        },
+        _ => false,
+    }
+}
+
+/// Whether the damage was dealt by a skill of one of the owner's characters
+pub(crate) fn own_skill(ctx: &Context, info: &DamageInfo) -> Option<(usize, SkillKind)> {
+    match info.source {
+        DamageSource::Skill { character, skill } if info.attacker == ctx.owner() => Some((character, skill)),
+        _ => None,
+    }
+}
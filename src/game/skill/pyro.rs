@@ -0,0 +1,456 @@
+use crate::*;
+use super::*;
+use Element::Pyro;
+use SkillKind::*;
+
+const PYRO: DamageKind = DamageKind::Elemental(Pyro);
+
+pub(crate) static BENNETT: [Skill; 3] = [
+    Skill::new("Strike of Fortune", NormalAttack, SkillCost::new(Pyro, 1, 2, 0), physical_attack),
+    Skill::new("Passion Overload", ElementalSkill, SkillCost::new(Pyro, 3, 0, 0), |ctx| {
+        ctx.damage(3, PYRO);
+    }),
+    Skill::new("Fantastic Voyage", ElementalBurst, SkillCost::new(Pyro, 4, 0, 2), |ctx| {
+        ctx.damage(2, PYRO);
+
+        let field = ctx.combat_status(StatusKind::InspirationField);
+        if ctx.has_talent() {
+            ctx.game_mut().set_counter(field, 1);
+        }
+    }),
+];
+
+pub(crate) static DILUC: [Skill; 3] = [
+    Skill::new("Tempered Sword", NormalAttack, SkillCost::new(Pyro, 1, 2, 0), physical_attack),
+    Skill::new("Searing Onslaught", ElementalSkill, SkillCost::new(Pyro, 3, 0, 0), |ctx| {
+        // every third use in a round hits harder
+        let amount = if ctx.uses_this_round() == 2 { 5 } else { 3 };
+        ctx.damage(amount, PYRO);
+    }),
+    Skill::new("Dawn", ElementalBurst, SkillCost::new(Pyro, 4, 0, 3), |ctx| {
+        ctx.damage(8, PYRO);
+        ctx.status(StatusKind::PyroElementalInfusion);
+    }),
+];
+
+pub(crate) static XIANGLING: [Skill; 3] = [
+    Skill::new("Dough-Fu", NormalAttack, SkillCost::new(Pyro, 1, 2, 0), physical_attack),
+    Skill::new("Guoba Attack", ElementalSkill, SkillCost::new(Pyro, 3, 0, 0), |ctx| {
+        if ctx.has_talent() {
+            ctx.damage(1, PYRO);
+        }
+
+        ctx.summon(SummonKind::Guoba);
+    }),
+    Skill::new("Pyronado", ElementalBurst, SkillCost::new(Pyro, 4, 0, 2), |ctx| {
+        ctx.damage(2, PYRO);
+        ctx.combat_status(StatusKind::Pyronado);
+    }),
+];
+
+pub(crate) static YOIMIYA: [Skill; 3] = [
+    Skill::new("Firework Flare-Up", NormalAttack, SkillCost::new(Pyro, 1, 2, 0), physical_attack),
+    Skill::new("Niwabi Fire-Dance", ElementalSkill, SkillCost::new(Pyro, 1, 0, 0), |ctx| {
+        ctx.status(StatusKind::NiwabiEnshou);
+    }),
+    Skill::new("Ryuukin Saxifrage", ElementalBurst, SkillCost::new(Pyro, 3, 0, 3), |ctx| {
+        ctx.damage(3, PYRO);
+        ctx.combat_status(StatusKind::AurousBlaze);
+    }),
+];
+
+pub(crate) static FATUI_PYRO_AGENT: [Skill; 3] = [
+    Skill::new("Thrust", NormalAttack, SkillCost::new(Pyro, 1, 2, 0), physical_attack),
+    Skill::new("Prowl", ElementalSkill, SkillCost::new(Pyro, 3, 0, 0), |ctx| {
+        ctx.damage(1, PYRO);
+
+        let stealth = ctx.status(StatusKind::Stealth);
+        if ctx.has_talent() {
+            ctx.game_mut().set_usages(stealth, 3);
+        }
+    }),
+    Skill::new("Blade Ablaze", ElementalBurst, SkillCost::new(Pyro, 3, 0, 2), |ctx| {
+        ctx.damage(5, PYRO);
+    }),
+];
+
+pub(crate) static DEHYA: [Skill; 3] = [
+    Skill::new("Sandstorm Assault", NormalAttack, SkillCost::new(Pyro, 1, 2, 0), physical_attack),
+    Skill::new("Molten Inferno", ElementalSkill, SkillCost::new(Pyro, 3, 0, 0), |ctx| {
+        // recasting it while the field is up also deals damage
+        let summoned = ctx.game().player(ctx.player()).summons().iter()
+            .any(|summon| summon.kind() == EntityKind::Summon(SummonKind::FierySanctumField));
+
+        if summoned {
+            ctx.damage(1, PYRO);
+        }
+
+        ctx.summon(SummonKind::FierySanctumField);
+    }),
+    Skill::new("Leonine Bite", ElementalBurst, SkillCost::new(Pyro, 4, 0, 2), |ctx| {
+        // Incineration Drive follows right away, there's no way to prepare a skill
+        ctx.damage(3, PYRO);
+        ctx.damage(3, PYRO);
+    }),
+];
+
+/// Diluc's talent: his second Searing Onslaught of each round costs 1 less Pyro die
+pub(crate) struct FlowingFlame;
+
+impl Effect for FlowingFlame {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::SkillCost]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::SkillCost { character, skill: ElementalSkill, cost, .. } = hook {
+            let uses = ctx.game().player(ctx.owner()).uses_this_round(*character, ElementalSkill);
+
+            if Some(*character) == ctx.character() && uses == 1 {
+                cost.discount_exact(1);
+            }
+        }
+    }
+}
+
+/// For 2 rounds, the player's characters with at least 7 HP deal 2 more damage, while the ones
+/// below that are healed for 2 HP after using a skill. Bennett's talent (counter set) removes
+/// the HP requirement for the damage bonus
+pub(crate) struct InspirationField;
+
+impl Effect for InspirationField {
+    fn duration(&self) -> Duration { Duration::Rounds(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        let owner = ctx.owner();
+
+        match hook {
+            Hook::OutgoingDamage { info, calc } => {
+                if let Some((character, _)) = own_skill(ctx, info) {
+                    let hp = ctx.game().player(owner).character(character).hp();
+
+                    if hp >= 7 || ctx.entity().counter() > 0 {
+                        calc.bonus(ModifierSource::Status(StatusKind::InspirationField.name()), 2);
+                    }
+                }
+            },
+            Hook::AfterSkill { player, character, .. } if *player == owner => {
+                let character = *character;
+
+                if ctx.game().player(owner).character(character).hp() < 7 {
+                    ctx.game_mut().heal(owner, character, 2);
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Converts the character's physical damage to Pyro for 2 rounds
+pub(crate) struct PyroElementalInfusion;
+
+impl Effect for PyroElementalInfusion {
+    fn duration(&self) -> Duration { Duration::Rounds(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        infuse(ctx, hook, Pyro, StatusKind::PyroElementalInfusion);
+    }
+}
+
+/// Deals 2 Pyro damage after the player's characters use a skill, twice
+pub(crate) struct Pyronado;
+
+impl Effect for Pyronado {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterSkill { player, character, skill } = *hook {
+            let card = ctx.game().player(player).character(character).card();
+
+            // not triggered by the burst that created it
+            let creation = card == CharacterCard::Xiangling && skill == ElementalBurst;
+
+            if player == ctx.owner() && !creation {
+                status_damage(ctx, StatusKind::Pyronado, 2, PYRO);
+                ctx.consume();
+            }
+        }
+    }
+}
+
+/// The character's normal attacks deal Pyro damage and 1 more damage, twice. With Yoimiya's
+/// talent, they're also followed by 1 Pyro damage
+pub(crate) struct NiwabiEnshou;
+
+impl Effect for NiwabiEnshou {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage, Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        match hook {
+            Hook::OutgoingDamage { info, calc } if holder_skill(ctx, info) == Some(NormalAttack) => {
+                calc.convert(Pyro, ModifierSource::Status(StatusKind::NiwabiEnshou.name()));
+                calc.bonus(ModifierSource::Status(StatusKind::NiwabiEnshou.name()), 1);
+            },
+            Hook::AfterSkill { player, character, skill: NormalAttack }
+                if *player == ctx.owner() && Some(*character) == ctx.character() =>
+            {
+                let talent = ctx.game().player(*player).character(*character).talent().is_some();
+
+                if talent {
+                    status_damage(ctx, StatusKind::NiwabiEnshou, 1, PYRO);
+                }
+
+                ctx.consume();
+            },
+            _ => (),
+        }
+    }
+}
+
+/// For 2 rounds, deals 1 Pyro damage after a character other than Yoimiya uses a skill
+pub(crate) struct AurousBlaze;
+
+impl Effect for AurousBlaze {
+    fn duration(&self) -> Duration { Duration::Rounds(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::AfterSkill]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::AfterSkill { player, character, .. } = *hook {
+            let card = ctx.game().player(player).character(character).card();
+
+            if player == ctx.owner() && card != CharacterCard::Yoimiya {
+                status_damage(ctx, StatusKind::AurousBlaze, 1, PYRO);
+            }
+        }
+    }
+}
+
+/// Reduces damage taken by 1 and makes the character's damage Pyro with 1 more damage, with 2
+/// usages shared between both (3 with the Fatui Pyro Agent's talent)
+pub(crate) struct Stealth;
+
+impl Effect for Stealth {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::OutgoingDamage]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, hook: &mut Hook) {
+        if let Hook::OutgoingDamage { info, calc } = hook {
+            if holder_skill(ctx, info).is_some() {
+                calc.convert(Pyro, ModifierSource::Status(StatusKind::Stealth.name()));
+                calc.bonus(ModifierSource::Status(StatusKind::Stealth.name()), 1);
+                ctx.consume();
+            }
+        }
+    }
+
+    fn defense(&self) -> Option<DefenseKind> {
+        Some(DefenseKind::Reduction { amount: 1, min: 1 })
+    }
+}
+
+/// Deals 2 Pyro damage during the End Phase, twice
+pub(crate) struct Guoba;
+
+impl Effect for Guoba {
+    fn usages(&self) -> Option<u8> { Some(2) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::Guoba, 2, PYRO);
+        ctx.consume();
+    }
+}
+
+
+/// Dehya's talent: heals her for 2 HP during the End Phase while she has 6 HP or less
+pub(crate) struct StalwartAndTrue;
+
+impl Effect for StalwartAndTrue {
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        let Some(index) = ctx.character() else { return };
+        let owner = ctx.owner();
+        let dehya = ctx.game().player(owner).character(index);
+
+        if dehya.is_alive() && dehya.hp() <= 6 {
+            ctx.game_mut().heal(owner, index, 2);
+        }
+    }
+}
+
+/// Deals 1 Pyro damage during the End Phase, three times
+pub(crate) struct FierySanctumField;
+
+impl Effect for FierySanctumField {
+    fn usages(&self) -> Option<u8> { Some(3) }
+
+    fn triggers(&self) -> &'static [Trigger] {
+        &[Trigger::EndPhase]
+    }
+
+    fn on_trigger(&self, ctx: &mut Context, _hook: &mut Hook) {
+        summon_damage(ctx, SummonKind::FierySanctumField, 1, PYRO);
+        ctx.consume();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    #[test]
+    fn bennett() {
+        let mut game = duel(CharacterCard::Bennett);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 7);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 5);
+
+        // 2 physical + 2 from the field
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 1);
+    }
+
+    #[test]
+    fn inspiration_field_heals() {
+        let mut game = duel(CharacterCard::Bennett);
+        charge(&mut game);
+        use_skill(&mut game, 2);
+
+        game.player_mut(PlayerId::First).characters[0].hp = 4;
+        use_skill(&mut game, 0);
+
+        assert_eq!(opponent_hp(&game), 6);
+        assert_eq!(game.player(PlayerId::First).active().hp(), 6);
+    }
+
+    #[test]
+    fn diluc() {
+        let mut game = duel(CharacterCard::Diluc);
+
+        use_skill(&mut game, 1);
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 4);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 0);
+    }
+
+    #[test]
+    fn diluc_burst() {
+        let mut game = duel(CharacterCard::Diluc);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 2);
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 0);
+        assert!(game.player(PlayerId::First).active().has_status(EntityKind::Status(StatusKind::PyroElementalInfusion)));
+    }
+
+    #[test]
+    fn xiangling() {
+        let mut game = duel(CharacterCard::Xiangling);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 10);
+        assert_eq!(summons(&game), [SummonKind::Guoba]);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 8);
+
+        // 2 physical, then 2 Pyro from Pyronado
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 4);
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 2);
+    }
+
+    #[test]
+    fn yoimiya() {
+        let mut game = duel(CharacterCard::Yoimiya);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 10);
+
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 7);
+        assert!(opponent(&game).aura().contains(Element::Pyro));
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 4);
+    }
+
+    #[test]
+    fn fatui_pyro_agent() {
+        let mut game = duel(CharacterCard::FatuiPyroAgent);
+        assert!(game.player(PlayerId::First).active().has_status(EntityKind::Status(StatusKind::Stealth)));
+
+        // Pyro and +1 from the starting Stealth
+        use_skill(&mut game, 0);
+        assert_eq!(opponent_hp(&game), 7);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 5);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 0);
+    }
+
+    #[test]
+    fn dehya() {
+        let mut game = duel(CharacterCard::Dehya);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 10);
+        assert_eq!(summons(&game), [SummonKind::FierySanctumField]);
+
+        use_skill(&mut game, 1);
+        assert_eq!(opponent_hp(&game), 9);
+        assert_eq!(summons(&game), [SummonKind::FierySanctumField]);
+
+        charge(&mut game);
+        use_skill(&mut game, 2);
+        assert_eq!(opponent_hp(&game), 3);
+
+        end_round(&mut game);
+        assert_eq!(opponent_hp(&game), 2);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::*;
+
+/// A character card in play, with everything attached to it
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CharacterState {
+    card: CharacterCard,
+    pub(crate) hp: u8,
+    pub(crate) energy: u8,
+    pub(crate) aura: Aura,
+    pub(crate) equipment: Vec<Entity>,
+    pub(crate) statuses: Vec<Entity>,
+    pub(crate) skill_uses: Vec<u8>,
+}
+
+impl CharacterState {
+    pub(crate) fn new(card: CharacterCard) -> Self {
+        Self {
+            card,
+            hp: card.health(),
+            energy: 0,
+            aura: Aura::default(),
+            equipment: Vec::new(),
+            statuses: Vec::new(),
+            skill_uses: vec![0; card.skills().len()],
+        }
+    }
+
+    pub fn card(&self) -> CharacterCard {
+        self.card
+    }
+
+    pub fn hp(&self) -> u8 {
+        self.hp
+    }
+
+    pub fn max_hp(&self) -> u8 {
+        self.card.health()
+    }
+
+    pub fn energy(&self) -> u8 {
+        self.energy
+    }
+
+    pub fn max_energy(&self) -> u8 {
+        self.card.max_energy()
+    }
+
+    pub fn aura(&self) -> Aura {
+        self.aura
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.hp > 0
+    }
+
+    /// Equipped weapon, artifact and talent cards, in the order they were equipped
+    pub fn equipment(&self) -> &[Entity] {
+        &self.equipment
+    }
+
+    pub fn statuses(&self) -> &[Entity] {
+        &self.statuses
+    }
+
+    pub fn weapon(&self) -> Option<WeaponCard> {
+        self.equipped(|card| card.weapon())
+    }
+
+    pub fn artifact(&self) -> Option<ArtifactCard> {
+        self.equipped(|card| card.artifact())
+    }
+
+    pub fn talent(&self) -> Option<TalentCard> {
+        self.equipped(|card| card.talent())
+    }
+
+    /// How many times the skill at `index` in [`CharacterCard::skills`] was used this match
+    pub fn skill_uses(&self, index: usize) -> u8 {
+        self.skill_uses[index]
+    }
+
+    pub fn has_status(&self, kind: EntityKind) -> bool {
+        self.statuses.iter().any(|status| status.kind() == kind)
+    }
+
+    fn equipped<T>(&self, get: impl Fn(ActionCard) -> Option<T>) -> Option<T> {
+        self.equipment.iter().find_map(|entity| match entity.kind() {
+            EntityKind::Card(card) => get(card),
+            _ => None,
+        })
+    }
+}
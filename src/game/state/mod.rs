@@ -0,0 +1,327 @@
+use crate::*;
+use super::rng::Rng;
+
+pub use player::{PlayerState, Location, MAX_HAND_SIZE, MAX_SUPPORTS, MAX_SUMMONS};
+mod player;
+
+pub use character::CharacterState;
+mod character;
+
+mod ops;
+
+/// Amount of cards each player draws at the start of a match
+pub const STARTING_HAND: usize = 5;
+
+/// Amount of dice each player rolls during the Roll Phase
+pub const DICE_PER_ROUND: u8 = 8;
+
+/// Amount of times each player may reroll their dice during the Roll Phase, before any effect
+/// granting more
+pub const REROLLS_PER_ROUND: u8 = 1;
+
+/// The match ends in a draw once this round is over, if neither player won before
+pub const ROUND_LIMIT: u8 = 15;
+
+/// One of the two players in a match
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayerId {
+    First,
+    Second,
+}
+
+impl PlayerId {
+    pub fn opponent(&self) -> Self {
+        match self {
+            Self::First  => Self::Second,
+            Self::Second => Self::First,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        match self {
+            Self::First  => 0,
+            Self::Second => 1,
+        }
+    }
+}
+
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Phase {
+    RollPhase,
+    ActionPhase,
+    EndPhase,
+    /// The match is over, see [`GameState::outcome`]
+    GameOver,
+}
+
+/// How a match ended
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Outcome {
+    /// Every character of the other player was defeated
+    Winner(PlayerId),
+    /// The round limit was reached
+    Draw,
+}
+
+/// The full state of a match, including information hidden from the players (such as the order
+/// of the draw piles)
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub struct GameState {
+    pub(crate) round: u8,
+    pub(crate) phase: Phase,
+    pub(crate) turn: PlayerId,
+    pub(crate) first: PlayerId,
+    pub(crate) players: [PlayerState; 2],
+    pub(crate) rng: Rng,
+    pub(crate) next_id: EntityId,
+    pub(crate) decision: Option<Decision>,
+    pub(crate) outcome: Option<Outcome>,
+    pub(crate) events: Vec<Event>,
+}
+
+impl GameState {
+    /// Starts a match between two decks, shuffling them with the given seed
+    /// 
+    /// The first deck belongs to [`PlayerId::First`], who also acts first in the first round.
+    /// Both players start by deciding which of their starting cards to swap, see
+    /// [`Decision::Mulligan`].
+    pub fn new(first: &Deck, second: &Deck, seed: u64) -> Self {
+        let mut game = Self {
+            round: 1,
+            phase: Phase::RollPhase,
+            turn: PlayerId::First,
+            first: PlayerId::First,
+            players: [PlayerState::new(first), PlayerState::new(second)],
+            rng: Rng::new(seed),
+            next_id: 0,
+            decision: None,
+            outcome: None,
+            events: Vec::new(),
+        };
+
+        for player in [PlayerId::First, PlayerId::Second] {
+            // passive skills are statuses the characters start with
+            let cards: Vec<CharacterCard> = game.player(player).characters().iter().map(|ch| ch.card()).collect();
+
+            for (index, card) in cards.into_iter().enumerate() {
+                for passive in card.passives() {
+                    game.add_character_status(player, index, EntityKind::Status(*passive));
+                }
+            }
+
+            let mut pile = std::mem::take(&mut game.players[player.index()].pile);
+            game.rng.shuffle(&mut pile);
+            game.players[player.index()].pile = pile;
+            game.draw_cards(player, STARTING_HAND);
+        }
+
+        game.decision = Some(Decision::Mulligan { player: PlayerId::First });
+        game
+    }
+
+    pub fn round(&self) -> u8 {
+        self.round
+    }
+
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// The player expected to act next
+    pub fn turn(&self) -> PlayerId {
+        self.turn
+    }
+
+    /// The player who acts first this round
+    pub fn first_player(&self) -> PlayerId {
+        self.first
+    }
+
+    /// How the match ended, or `None` while it's still going on
+    pub fn outcome(&self) -> Option<Outcome> {
+        self.outcome
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// Everything that happened so far in the match, oldest first
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    pub(crate) fn record(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    pub(crate) fn finish(&mut self, outcome: Outcome) {
+        if self.outcome.is_none() {
+            self.outcome = Some(outcome);
+            self.phase = Phase::GameOver;
+            self.decision = None;
+            self.record(Event::GameOver(outcome));
+        }
+    }
+
+    pub fn player(&self, player: PlayerId) -> &PlayerState {
+        &self.players[player.index()]
+    }
+
+    pub(crate) fn player_mut(&mut self, player: PlayerId) -> &mut PlayerState {
+        &mut self.players[player.index()]
+    }
+
+    pub(crate) fn rng(&mut self) -> &mut Rng {
+        &mut self.rng
+    }
+
+    /// Finds an entity anywhere on the field, alongside the player it belongs to
+    pub fn entity(&self, id: EntityId) -> Option<(PlayerId, &Entity)> {
+        [PlayerId::First, PlayerId::Second].into_iter().find_map(|player| {
+            self.player(player).entities().find(|entity| entity.id() == id).map(|entity| (player, entity))
+        })
+    }
+
+    pub(crate) fn entity_mut(&mut self, id: EntityId) -> Option<&mut Entity> {
+        let [first, second] = &mut self.players;
+        first.entity_mut(id).or_else(|| second.entity_mut(id))
+    }
+
+    pub(crate) fn create_entity(&mut self, kind: EntityKind) -> Entity {
+        self.next_id += 1;
+        Entity::new(self.next_id, kind)
+    }
+
+    pub(crate) fn remove_entity(&mut self, id: EntityId) {
+        for player in &mut self.players {
+            player.zones_mut().for_each(|zone| zone.retain(|entity| entity.id() != id));
+        }
+    }
+
+    /// Discards every entity that ran out of usages or duration
+    pub(crate) fn discard_spent(&mut self) {
+        for player in &mut self.players {
+            player.zones_mut().for_each(|zone| zone.retain(|entity| !entity.is_spent()));
+        }
+    }
+
+    /// Triggers every effect on one side of the field that listens to this hook
+    pub fn dispatch(&mut self, player: PlayerId, hook: &mut Hook) {
+        let trigger = hook.trigger();
+        let listeners: Vec<(EntityId, EntityKind)> = self.player(player).entities()
+            .filter(|entity| entity.kind().effect().triggers().contains(&trigger))
+            .map(|entity| (entity.id(), entity.kind()))
+            .collect();
+
+        for (id, kind) in listeners {
+            // earlier effects may have discarded this one
+            if self.entity(id).is_some() {
+                if let (EntityKind::Summon(summon), Trigger::EndPhase) = (kind, trigger) {
+                    self.record(Event::SummonTriggered { player, summon });
+                }
+
+                kind.effect().on_trigger(&mut Context::new(self, player, id), hook);
+            }
+        }
+    }
+
+    /// Same as [`dispatch`](Self::dispatch), for cost hooks that aren't committed: effects only
+    /// read the game then, so asking doesn't need to change or clone it
+    pub(crate) fn preview(&self, player: PlayerId, hook: &mut Hook) {
+        let trigger = hook.trigger();
+        let listeners = self.player(player).entities()
+            .filter(|entity| entity.kind().effect().triggers().contains(&trigger));
+
+        for entity in listeners {
+            entity.kind().effect().on_trigger(&mut Context::preview(self, player, entity.id()), hook);
+        }
+    }
+
+    /// Triggers effects on both sides of the field, starting with the player whose turn it is
+    pub fn dispatch_all(&mut self, hook: &mut Hook) {
+        let turn = self.turn;
+
+        self.dispatch(turn, hook);
+        self.dispatch(turn.opponent(), hook);
+    }
+
+    /// Rolls both players' dice, then lets them reroll before the Action Phase starts
+    pub(crate) fn start_round(&mut self) {
+        self.phase = Phase::RollPhase;
+        self.turn = self.first;
+        self.record(Event::PhaseChanged { round: self.round, phase: Phase::RollPhase });
+
+        for player in [self.first, self.first.opponent()] {
+            let mut hook = Hook::RollPhase { fixed: Vec::new(), rerolls: 0 };
+            self.dispatch(player, &mut hook);
+
+            let Hook::RollPhase { mut fixed, rerolls } = hook else { unreachable!() };
+            fixed.truncate(DICE_PER_ROUND as usize);
+
+            let random: Vec<Die> = (fixed.len()..DICE_PER_ROUND as usize).map(|_| self.random_die()).collect();
+            let state = self.player_mut(player);
+            state.dice = fixed.into_iter().chain(random).collect();
+            state.pending_rerolls = REROLLS_PER_ROUND + rerolls;
+            state.ended_round = false;
+            state.defeated_this_round = false;
+            state.skills_used.clear();
+            state.zones_mut().flat_map(|zone| zone.iter_mut()).for_each(Entity::new_round);
+        }
+
+        self.continue_roll_phase();
+    }
+
+    /// Asks the next player with rerolls left to use them, starting the Action Phase once
+    /// nobody has any
+    pub(crate) fn continue_roll_phase(&mut self) {
+        let rerolling = [self.first, self.first.opponent()].into_iter()
+            .find(|player| self.player(*player).pending_rerolls > 0);
+
+        match rerolling {
+            Some(player) => self.decision = Some(Decision::Reroll { player }),
+            None => {
+                self.phase = Phase::ActionPhase;
+                self.turn = self.first;
+                self.record(Event::PhaseChanged { round: self.round, phase: Phase::ActionPhase });
+                self.dispatch_all(&mut Hook::ActionPhase);
+            },
+        }
+    }
+
+    pub(crate) fn end_round(&mut self) {
+        self.phase = Phase::EndPhase;
+        self.turn = self.first;
+        self.record(Event::PhaseChanged { round: self.round, phase: Phase::EndPhase });
+        self.dispatch_all(&mut Hook::EndPhase);
+
+        if self.is_over() {
+            return
+        }
+
+        for player in [self.first, self.first.opponent()] {
+            self.draw_cards(player, 2);
+            self.player_mut(player).zones_mut().flat_map(|zone| zone.iter_mut()).for_each(Entity::end_round);
+        }
+
+        self.discard_spent();
+
+        if self.round >= ROUND_LIMIT {
+            self.finish(Outcome::Draw);
+            return
+        }
+
+        self.round += 1;
+        self.start_round();
+    }
+
+    pub(crate) fn random_die(&mut self) -> Die {
+        match self.rng.below(8) {
+            0 => Die::Omni,
+            n => Die::Element(Element::ALL[n - 1]),
+        }
+    }
+}
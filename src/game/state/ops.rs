@@ -0,0 +1,665 @@
+//! Basic operations on the game state, used by the engine itself and by card effects
+
+use crate::*;
+
+impl GameState {
+    pub fn heal(&mut self, player: PlayerId, character: usize, amount: u8) {
+        let ch = &mut self.player_mut(player).characters[character];
+
+        if ch.is_alive() {
+            ch.hp = ch.hp.saturating_add(amount).min(ch.max_hp());
+        }
+    }
+
+    pub fn gain_energy(&mut self, player: PlayerId, character: usize, amount: u8) {
+        let ch = &mut self.player_mut(player).characters[character];
+
+        if ch.is_alive() {
+            ch.energy = ch.energy.saturating_add(amount).min(ch.max_energy());
+        }
+    }
+
+    /// Removes up to `amount` energy from a character, returning how much was actually removed
+    pub fn lose_energy(&mut self, player: PlayerId, character: usize, amount: u8) -> u8 {
+        let ch = &mut self.player_mut(player).characters[character];
+        let lost = ch.energy.min(amount);
+        ch.energy -= lost;
+        lost
+    }
+
+    pub fn add_dice(&mut self, player: PlayerId, die: Die, amount: u8) {
+        self.player_mut(player).dice.add(die, amount);
+    }
+
+    /// Draws cards from the top of the player's pile, discarding the ones that don't fit in
+    /// their hand
+    pub fn draw_cards(&mut self, player: PlayerId, amount: usize) {
+        for _ in 0..amount {
+            let state = self.player_mut(player);
+
+            if let Some(card) = state.pile.pop() {
+                if state.hand.len() < MAX_HAND_SIZE {
+                    state.hand.push(card);
+                }
+            }
+        }
+    }
+
+    /// Draws the first card from the top of the pile that matches `predicate`, returning
+    /// whether a card was found
+    pub fn draw_matching(&mut self, player: PlayerId, predicate: impl Fn(ActionCard) -> bool) -> bool {
+        let state = self.player_mut(player);
+
+        match state.pile.iter().rposition(|card| predicate(*card)) {
+            Some(index) => {
+                let card = state.pile.remove(index);
+
+                if state.hand.len() < MAX_HAND_SIZE {
+                    state.hand.push(card);
+                }
+
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Gives the player extra rerolls to be used on their current dice, which they have to
+    /// decide on right away
+    pub fn grant_rerolls(&mut self, player: PlayerId, amount: u8) {
+        let state = self.player_mut(player);
+        state.pending_rerolls = state.pending_rerolls.saturating_add(amount);
+
+        if amount > 0 {
+            self.decision = Some(Decision::Reroll { player });
+        }
+    }
+
+    /// Adds a status to one of the player's characters
+    /// 
+    /// If the character already has a status of the same kind, it's refreshed or stacked
+    /// instead, following its [`Stacking`] rule.
+    pub fn add_character_status(&mut self, player: PlayerId, character: usize, kind: EntityKind) -> EntityId {
+        self.record(Event::StatusApplied { player, character: Some(character), status: kind });
+
+        let entity = self.create_entity(kind);
+        let zone = &mut self.player_mut(player).characters[character].statuses;
+        insert_or_refresh(zone, entity)
+    }
+
+    /// Adds a combat status to the player's side of the field
+    /// 
+    /// If there's already a status of the same kind, it's refreshed or stacked instead,
+    /// following its [`Stacking`] rule.
+    pub fn add_combat_status(&mut self, player: PlayerId, kind: EntityKind) -> EntityId {
+        self.record(Event::StatusApplied { player, character: None, status: kind });
+
+        let entity = self.create_entity(kind);
+        let zone = &mut self.player_mut(player).combat_statuses;
+        insert_or_refresh(zone, entity)
+    }
+
+    /// Adds a summon to the player's side of the field, or refreshes the one already there
+    /// 
+    /// Returns `None` (and does nothing) if the summon zone is already full.
+    pub fn add_summon(&mut self, player: PlayerId, kind: SummonKind) -> Option<EntityId> {
+        let summons = &self.player(player).summons;
+        let present = summons.iter().any(|summon| summon.kind() == EntityKind::Summon(kind));
+
+        if !present && summons.len() >= MAX_SUMMONS {
+            return None
+        }
+
+        self.record(Event::Summoned { player, summon: kind });
+
+        let entity = self.create_entity(EntityKind::Summon(kind));
+        let zone = &mut self.player_mut(player).summons;
+        Some(insert_or_refresh(zone, entity))
+    }
+
+    /// Overrides the usages of an entity, such as shields whose points depend on the board
+    pub fn set_usages(&mut self, id: EntityId, usages: u8) {
+        if let Some(entity) = self.entity_mut(id) {
+            entity.set_usages(usages);
+        }
+
+        self.discard_spent();
+    }
+
+    pub fn set_counter(&mut self, id: EntityId, counter: u8) {
+        if let Some(entity) = self.entity_mut(id) {
+            entity.set_counter(counter);
+        }
+    }
+
+    /// Overrides how many rounds an entity has left
+    pub fn set_duration(&mut self, id: EntityId, rounds: u8) {
+        if let Some(entity) = self.entity_mut(id) {
+            entity.set_duration(rounds);
+        }
+    }
+
+    /// Attaches an element to a character without dealing damage
+    pub fn apply_element(&mut self, player: PlayerId, character: usize, element: Element) -> Option<Reaction> {
+        let ch = &mut self.player_mut(player).characters[character];
+
+        if ch.is_alive() { ch.aura.apply(element) } else { None }
+    }
+
+    /// Puts a support card on the field, discarding the support in `replace` first
+    pub fn add_support(&mut self, player: PlayerId, card: ActionCard, replace: Option<usize>) {
+        let entity = self.create_entity(EntityKind::Card(card));
+        let supports = &mut self.player_mut(player).supports;
+
+        if let Some(index) = replace {
+            supports.remove(index);
+        }
+
+        supports.push(entity);
+    }
+
+    /// Equips a card to a character, replacing the equipment of the same type it already has
+    pub fn equip(&mut self, player: PlayerId, character: usize, card: ActionCard) {
+        let entity = self.create_entity(EntityKind::Card(card));
+        let equipment = &mut self.player_mut(player).characters[character].equipment;
+
+        equipment.retain(|old| !same_slot(old.kind(), card));
+        equipment.push(entity);
+    }
+
+    /// Moves an equipped card from one character to another, keeping its usages
+    pub fn transfer_equipment(&mut self, player: PlayerId, from: usize, to: usize, slot: impl Fn(ActionCard) -> bool) {
+        let state = self.player_mut(player);
+        let position = state.characters[from].equipment.iter().position(|entity| match entity.kind() {
+            EntityKind::Card(card) => slot(card),
+            _ => false,
+        });
+
+        if let Some(position) = position {
+            let entity = state.characters[from].equipment.remove(position);
+
+            if let EntityKind::Card(card) = entity.kind() {
+                state.characters[to].equipment.retain(|old| !same_slot(old.kind(), card));
+            }
+
+            state.characters[to].equipment.push(entity);
+        }
+    }
+
+    /// Switches the player's active character, triggering effects that happen after switching
+    /// 
+    /// Does nothing if the character is already active or defeated.
+    pub fn switch_active(&mut self, player: PlayerId, to: usize) {
+        let from = self.player(player).active_index();
+
+        if from == to || !self.player(player).character(to).is_alive() {
+            return
+        }
+
+        self.player_mut(player).active = to;
+        self.record(Event::Switched { player, from, to });
+        self.dispatch_all(&mut Hook::AfterSwitch { player, from, to });
+    }
+
+    /// The character that should receive energy from effects that give it to "one character
+    /// without full energy": the active character first, then the others in order
+    pub fn missing_energy(&self, player: PlayerId) -> Option<usize> {
+        let state = self.player(player);
+        let count = state.characters.len();
+
+        (0..count)
+            .map(|offset| (state.active + offset) % count)
+            .find(|index| {
+                let ch = &state.characters[*index];
+                ch.is_alive() && ch.energy() < ch.max_energy()
+            })
+    }
+
+    /// The reaction a damage instance would trigger, as of right now
+    pub fn predict_reaction(&self, info: &DamageInfo, kind: DamageKind) -> Option<Reaction> {
+        let mut aura = self.player(info.attacker.opponent()).character(info.target).aura();
+        kind.element().and_then(|element| aura.apply(element))
+    }
+
+    /// The next alive character after the active one, if there's any
+    pub fn next_character(&self, player: PlayerId) -> Option<usize> {
+        let state = self.player(player);
+        let count = state.characters.len();
+
+        (1..count)
+            .map(|offset| (state.active + offset) % count)
+            .find(|index| state.characters[*index].is_alive())
+    }
+
+    /// The previous alive character before the active one, if there's any
+    pub fn previous_character(&self, player: PlayerId) -> Option<usize> {
+        let state = self.player(player);
+        let count = state.characters.len();
+
+        (1..count)
+            .map(|offset| (state.active + count - offset) % count)
+            .find(|index| state.characters[*index].is_alive())
+    }
+
+    /// Deals damage to one of the opponent's characters, going through the outgoing damage
+    /// effects of the attacker and the defenses of the target
+    /// 
+    /// Defeated characters can't be damaged, the damage is simply lost.
+    pub fn deal_damage(&mut self, info: DamageInfo, amount: u8, kind: DamageKind) -> DamageBreakdown {
+        let defender = info.attacker.opponent();
+
+        if !self.player(defender).character(info.target).is_alive() {
+            return DamageBreakdown::missed(kind)
+        }
+
+        let mut hook = Hook::OutgoingDamage { info, calc: DamageCalculation::new(amount, kind) };
+        self.dispatch(info.attacker, &mut hook);
+        let Hook::OutgoingDamage { calc, .. } = hook else { unreachable!() };
+
+        let mut hook = Hook::IncomingDamage { info, calc };
+        self.dispatch(defender, &mut hook);
+        let Hook::IncomingDamage { calc, .. } = hook else { unreachable!() };
+
+        // character statuses protect only their character, combat statuses and summons only the
+        // active one
+        let is_active = self.player(defender).active_index() == info.target;
+        let state = self.player(defender);
+        let protecting: Vec<Entity> = state.characters[info.target].statuses.iter()
+            .chain(state.combat_statuses.iter().filter(|_| is_active))
+            .chain(state.summons.iter().filter(|_| is_active))
+            .filter(|entity| entity.kind().effect().defense().is_some())
+            .copied()
+            .collect();
+
+        let mut defenses: Vec<Defense> = protecting.iter()
+            .map(|entity| {
+                let source = ModifierSource::Status(entity.name());
+                let usages = entity.usages().unwrap_or(0);
+
+                match entity.kind().effect().defense() {
+                    Some(DefenseKind::Reduction { amount, min }) => Defense::reduction(source, amount, min, usages),
+                    _ => Defense::shield(source, usages),
+                }
+            })
+            .collect();
+
+        let target = &mut self.player_mut(defender).characters[info.target];
+        let was_alive = target.is_alive();
+        let breakdown = calc.resolve(&mut target.aura, &mut defenses);
+        target.hp = target.hp.saturating_sub(breakdown.dealt());
+        let defeated = was_alive && !target.is_alive();
+
+        for (entity, defense) in protecting.iter().zip(&defenses) {
+            if let Some(entity) = self.entity_mut(entity.id()) {
+                entity.set_usages(defense.usages());
+            }
+        }
+
+        self.discard_spent();
+        self.record(Event::DamageDealt {
+            info,
+            kind: breakdown.kind(),
+            amount: breakdown.dealt(),
+            reaction: breakdown.reaction(),
+        });
+
+        if defeated {
+            self.defeat(defender, info.target);
+        }
+
+        if let Some(reaction) = breakdown.reaction() {
+            self.reaction_side_effects(info, reaction);
+        }
+
+        self.dispatch_all(&mut Hook::AfterDamage { info, breakdown: breakdown.clone() });
+
+        breakdown
+    }
+
+    /// Handles a character whose HP just dropped to zero: it loses its energy, element,
+    /// equipment and statuses, and can't be healed anymore. If it was the active character, its
+    /// player has to choose the next one, unless it was their last one
+    fn defeat(&mut self, player: PlayerId, character: usize) {
+        let state = self.player_mut(player);
+        let ch = &mut state.characters[character];
+
+        ch.energy = 0;
+        ch.aura.clear();
+        ch.equipment.clear();
+        ch.statuses.clear();
+        state.defeated_this_round = true;
+
+        self.record(Event::Defeated { player, character });
+        self.dispatch_all(&mut Hook::Defeated { player, character });
+
+        let state = self.player(player);
+
+        if !state.characters().iter().any(CharacterState::is_alive) {
+            self.finish(Outcome::Winner(player.opponent()));
+        } else if character == state.active_index() {
+            self.decision = Some(Decision::ChooseActive { player });
+        }
+    }
+
+    /// Whatever a reaction does besides adding damage: damaging the other characters of the
+    /// defender, or creating statuses and summons
+    fn reaction_side_effects(&mut self, info: DamageInfo, reaction: Reaction) {
+        let attacker = info.attacker;
+        let defender = attacker.opponent();
+        let source = DamageSource::Reaction(reaction);
+
+        let others: Vec<usize> = (0..self.player(defender).characters().len())
+            .filter(|index| *index != info.target && self.player(defender).character(*index).is_alive())
+            .collect();
+
+        match reaction {
+            Reaction::Overloaded => {
+                let active = self.player(defender).active_index();
+                let alive = self.player(defender).character(info.target).is_alive();
+
+                if info.target == active && alive {
+                    if let Some(next) = self.next_character(defender) {
+                        self.switch_active(defender, next);
+                    }
+                }
+            },
+            Reaction::Superconduct | Reaction::ElectroCharged => {
+                for target in others {
+                    self.deal_damage(DamageInfo { attacker, source, target }, 1, DamageKind::Piercing);
+                }
+            },
+            Reaction::Swirl(element) => {
+                for target in others {
+                    self.deal_damage(DamageInfo { attacker, source, target }, 1, DamageKind::Elemental(element));
+                }
+            },
+            Reaction::Frozen => {
+                self.add_character_status(defender, info.target, EntityKind::Status(StatusKind::Frozen));
+            },
+            Reaction::Crystallize(_) => {
+                self.add_combat_status(attacker, EntityKind::Status(StatusKind::CrystallizeShield));
+            },
+            Reaction::Bloom => {
+                self.add_combat_status(attacker, EntityKind::Status(StatusKind::DendroCore));
+            },
+            Reaction::Quicken => {
+                self.add_combat_status(attacker, EntityKind::Status(StatusKind::CatalyzingField));
+            },
+            Reaction::Burning => {
+                self.add_summon(attacker, SummonKind::BurningFlame);
+            },
+            _ => (),
+        }
+    }
+
+    /// The cost of switching the active character after every discount, whether the switch is a
+    /// fast action, and the entities whose usages the discounts would use up
+    pub fn switch_cost(&self, player: PlayerId, to: usize) -> (CardCost, bool, Vec<EntityId>) {
+        let mut hook = self.switch_hook(player, to, false);
+        self.preview(player, &mut hook);
+
+        let Hook::Speed { cost, fast, consumed, .. } = hook else { unreachable!() };
+        (cost, fast, consumed)
+    }
+
+    /// Same as [`switch_cost`](Self::switch_cost), but actually using up the discounts
+    pub(crate) fn commit_switch_cost(&mut self, player: PlayerId, to: usize) -> (CardCost, bool) {
+        let mut hook = self.switch_hook(player, to, true);
+        self.dispatch(player, &mut hook);
+
+        let Hook::Speed { cost, fast, .. } = hook else { unreachable!() };
+        (cost, fast)
+    }
+
+    fn switch_hook(&self, player: PlayerId, to: usize, commit: bool) -> Hook {
+        let from = self.player(player).active_index();
+        let action = CostAction::Switch { from, to };
+
+        Hook::Speed { action, cost: CardCost::ONE, fast: false, consumed: Vec::new(), commit }
+    }
+
+    /// The cost of playing a card after every discount, alongside the entities whose usages
+    /// the discounts would use up
+    pub fn card_cost(&self, player: PlayerId, card: ActionCard) -> (CardCost, Vec<EntityId>) {
+        let mut hook = Hook::CardCost { card, cost: card.cost(), consumed: Vec::new(), commit: false };
+        self.preview(player, &mut hook);
+
+        let Hook::CardCost { cost, consumed, .. } = hook else { unreachable!() };
+        (cost, consumed)
+    }
+
+    /// Same as [`card_cost`](Self::card_cost), but actually using up the discounts
+    pub(crate) fn commit_card_cost(&mut self, player: PlayerId, card: ActionCard) -> CardCost {
+        let mut hook = Hook::CardCost { card, cost: card.cost(), consumed: Vec::new(), commit: true };
+        self.dispatch(player, &mut hook);
+
+        let Hook::CardCost { cost, .. } = hook else { unreachable!() };
+        cost
+    }
+}
+
+fn insert_or_refresh(zone: &mut Vec<Entity>, entity: Entity) -> EntityId {
+    match zone.iter_mut().find(|old| old.kind() == entity.kind()) {
+        Some(old) => {
+            let usages = old.usages().unwrap_or(0);
+            *old = Entity::new(old.id(), entity.kind());
+
+            if let (Stacking::Stack { max }, Some(added)) = (entity.kind().effect().stacking(), entity.usages()) {
+                old.set_usages(usages.saturating_add(added).min(max));
+            }
+
+            old.id()
+        },
+        None => {
+            zone.push(entity);
+            entity.id()
+        },
+    }
+}
+
+fn same_slot(kind: EntityKind, card: ActionCard) -> bool {
+    match (kind, card) {
+        (EntityKind::Card(old), new) => {
+            is_weapon(old) && is_weapon(new)
+                || is_artifact(old) && is_artifact(new)
+                || old.talent().is_some() && new.talent().is_some()
+        },
+        _ => false,
+    }
+}
+
+/// Weapon cards, including custom ones
+fn is_weapon(card: ActionCard) -> bool {
+    #[cfg(feature = "custom-cards")]
+    if let ActionCard::Custom(card) = card {
+        return matches!(card.category(), CustomCategory::Weapon(_))
+    }
+
+    card.weapon().is_some()
+}
+
+/// Artifact cards, including custom ones
+fn is_artifact(card: ActionCard) -> bool {
+    #[cfg(feature = "custom-cards")]
+    if let ActionCard::Custom(card) = card {
+        return card.category() == CustomCategory::Artifact
+    }
+
+    card.artifact().is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::game::testing::*;
+
+    /// Deals damage from the first player's active character to the opposing active character
+    fn hit(game: &mut GameState, amount: u8, kind: DamageKind) -> DamageBreakdown {
+        let target = game.player(PlayerId::Second).active_index();
+        let source = DamageSource::Skill { character: 0, skill: SkillKind::NormalAttack };
+
+        game.deal_damage(DamageInfo { attacker: PlayerId::First, source, target }, amount, kind)
+    }
+
+    #[test]
+    fn summon_zone_is_limited() {
+        let mut game = duel(CharacterCard::Fischl);
+        let kinds = [SummonKind::Oz, SummonKind::Guoba, SummonKind::MelodyLoop, SummonKind::DrunkenMist];
+
+        for kind in kinds {
+            assert!(game.add_summon(PlayerId::First, kind).is_some());
+        }
+
+        assert_eq!(game.add_summon(PlayerId::First, SummonKind::CuileinAnbar), None);
+        assert_eq!(summons(&game), kinds);
+
+        // summons already on the field can still be refreshed
+        game.player_mut(PlayerId::First).summons[0].set_usages(1);
+        assert!(game.add_summon(PlayerId::First, SummonKind::Oz).is_some());
+        assert_eq!(game.player(PlayerId::First).summons()[0].usages(), Some(2));
+    }
+
+    #[test]
+    fn crystallize_shield_stacks() {
+        let mut game = duel(CharacterCard::Noelle);
+
+        for element in [Element::Pyro, Element::Hydro, Element::Cryo] {
+            game.player_mut(PlayerId::Second).characters[0].aura.apply(element);
+            hit(&mut game, 1, DamageKind::Elemental(Element::Geo));
+        }
+
+        assert_eq!(combat_status_usages(&game, StatusKind::CrystallizeShield), Some(2));
+    }
+
+    #[test]
+    fn superconduct_pierces_standby() {
+        let mut game = duel(CharacterCard::Fischl);
+        game.player_mut(PlayerId::Second).characters[0].aura.apply(Element::Cryo);
+
+        let breakdown = hit(&mut game, 1, DamageKind::Elemental(Element::Electro));
+        assert_eq!(breakdown.reaction(), Some(Reaction::Superconduct));
+        assert_eq!(opponent_hp(&game), 8);
+        assert_eq!(standby_hp(&game), [9, 9]);
+    }
+
+    #[test]
+    fn swirl_spreads_the_element() {
+        let mut game = duel(CharacterCard::Sucrose);
+        game.player_mut(PlayerId::Second).characters[0].aura.apply(Element::Pyro);
+
+        hit(&mut game, 1, DamageKind::Elemental(Element::Anemo));
+        assert_eq!(opponent_hp(&game), 9);
+        assert_eq!(standby_hp(&game), [9, 9]);
+
+        let standby = game.player(PlayerId::Second).character(1);
+        assert!(standby.aura().contains(Element::Pyro));
+    }
+
+    #[test]
+    fn frozen_blocks_skills_until_shattered() {
+        let mut game = duel(CharacterCard::Ganyu);
+        game.player_mut(PlayerId::Second).characters[0].aura.apply(Element::Hydro);
+        hit(&mut game, 1, DamageKind::Elemental(Element::Cryo));
+
+        let frozen = EntityKind::Status(StatusKind::Frozen);
+        assert!(opponent(&game).has_status(frozen));
+
+        game.turn = PlayerId::Second;
+        assert!(!game.legal_actions().iter().any(|action| matches!(action, Action::UseSkill { .. })));
+
+        // 2 physical + 2 from shattering
+        hit(&mut game, 2, DamageKind::Physical);
+        assert_eq!(opponent_hp(&game), 4);
+        assert!(!opponent(&game).has_status(frozen));
+    }
+
+    #[test]
+    fn dendro_core_boosts_next_hit() {
+        let mut game = duel(CharacterCard::Collei);
+        game.player_mut(PlayerId::Second).characters[0].aura.apply(Element::Hydro);
+        hit(&mut game, 1, DamageKind::Elemental(Element::Dendro));
+
+        assert_eq!(combat_status_usages(&game, StatusKind::DendroCore), Some(1));
+        assert_eq!(hit(&mut game, 1, DamageKind::Elemental(Element::Pyro)).dealt(), 3);
+        assert_eq!(combat_status_usages(&game, StatusKind::DendroCore), None);
+    }
+
+    #[test]
+    fn overloaded_switches_the_target_out() {
+        let mut game = duel(CharacterCard::Fischl);
+        game.player_mut(PlayerId::Second).characters[0].aura.apply(Element::Pyro);
+
+        hit(&mut game, 1, DamageKind::Elemental(Element::Electro));
+        assert_eq!(game.player(PlayerId::Second).active_index(), 1);
+        assert_eq!(game.player(PlayerId::Second).character(0).hp(), 7);
+    }
+
+    #[test]
+    fn defeat_clears_the_character() {
+        let mut game = duel(CharacterCard::Keqing);
+        let earrings = ActionCard::Equipment(EquipmentCard::Artifact(ArtifactCard::GamblersEarrings));
+        game.equip(PlayerId::First, 0, earrings);
+
+        let opponent = PlayerId::Second;
+        game.equip(opponent, 0, ActionCard::Equipment(EquipmentCard::Weapon(WeaponCard::WhiteTassel)));
+        game.add_character_status(opponent, 0, EntityKind::Status(StatusKind::Satiated));
+        game.gain_energy(opponent, 0, 2);
+        game.player_mut(opponent).characters[0].hp = 1;
+
+        let omni = game.player(PlayerId::First).dice().count(Die::Omni);
+        hit(&mut game, 1, DamageKind::Elemental(Element::Electro));
+
+        let bennett = game.player(opponent).character(0);
+        assert!(!bennett.is_alive());
+        assert!(bennett.equipment().is_empty() && bennett.statuses().is_empty());
+        assert_eq!((bennett.energy(), bennett.aura()), (0, Aura::default()));
+        assert!(game.player(opponent).defeated_this_round());
+
+        // the earrings saw it happen
+        assert_eq!(game.player(PlayerId::First).dice().count(Die::Omni), omni + 2);
+
+        game.heal(opponent, 0, 5);
+        assert_eq!(hit(&mut game, 3, DamageKind::Physical).dealt(), 0);
+        assert_eq!(game.player(opponent).character(0).hp(), 0);
+    }
+
+    #[test]
+    fn healing_and_energy_are_capped_without_overflowing() {
+        let mut game = duel(CharacterCard::Fischl);
+        game.player_mut(PlayerId::First).characters[0].hp = 5;
+
+        game.heal(PlayerId::First, 0, u8::MAX);
+        game.gain_energy(PlayerId::First, 0, u8::MAX);
+
+        let fischl = game.player(PlayerId::First).character(0);
+        assert_eq!((fischl.hp(), fischl.energy()), (fischl.max_hp(), fischl.max_energy()));
+    }
+
+    #[test]
+    fn defeating_every_character_wins() {
+        let mut game = duel(CharacterCard::Keqing);
+
+        for index in 0..3 {
+            game.player_mut(PlayerId::Second).characters[index].hp = 1;
+        }
+
+        game.player_mut(PlayerId::Second).characters[0].aura.apply(Element::Cryo);
+        hit(&mut game, 1, DamageKind::Elemental(Element::Electro));
+
+        assert_eq!(game.outcome(), Some(Outcome::Winner(PlayerId::First)));
+        assert_eq!(game.phase(), Phase::GameOver);
+        assert_eq!(game.decision(), None);
+        assert!(game.legal_actions().is_empty());
+    }
+
+    #[test]
+    fn round_limit_ends_in_a_draw() {
+        let mut game = duel(CharacterCard::Keqing);
+        game.round = ROUND_LIMIT;
+
+        end_round(&mut game);
+        assert_eq!(game.outcome(), Some(Outcome::Draw));
+        assert_eq!(game.round(), ROUND_LIMIT);
+        assert_eq!(game.apply(Action::EndRound), Err(ActionError::WrongPhase));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::*;
+
+/// Maximum amount of cards a player can hold, extra cards drawn are discarded
+pub const MAX_HAND_SIZE: usize = 10;
+
+/// Maximum amount of supports a player can have on the field
+pub const MAX_SUPPORTS: usize = 4;
+
+/// Maximum amount of summons a player can have on the field, extra summons are not created
+pub const MAX_SUMMONS: usize = 4;
+
+/// Everything on one side of the field, plus that player's hand and draw pile
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub struct PlayerState {
+    pub(crate) characters: Vec<CharacterState>,
+    pub(crate) active: usize,
+    pub(crate) hand: Vec<ActionCard>,
+    pub(crate) pile: Vec<ActionCard>,
+    pub(crate) dice: Dice,
+    pub(crate) combat_statuses: Vec<Entity>,
+    pub(crate) summons: Vec<Entity>,
+    pub(crate) supports: Vec<Entity>,
+    pub(crate) ended_round: bool,
+    pub(crate) defeated_this_round: bool,
+    pub(crate) pending_rerolls: u8,
+    pub(crate) skills_used: Vec<(usize, SkillKind)>,
+    pub(crate) played: Vec<ActionCard>,
+}
+
+/// Where an entity is on a player's side of the field
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Equipment(usize),
+    CharacterStatus(usize),
+    CombatStatus,
+    Summon,
+    Support,
+    Nowhere,
+}
+
+impl Location {
+    /// The character this location belongs to, if any
+    pub fn character(&self) -> Option<usize> {
+        match self {
+            Self::Equipment(index) | Self::CharacterStatus(index) => Some(*index),
+            _ => None,
+        }
+    }
+}
+
+impl PlayerState {
+    pub(crate) fn new(deck: &Deck) -> Self {
+        Self {
+            characters: deck.character_slice().iter().map(|card| CharacterState::new(*card)).collect(),
+            active: 0,
+            hand: Vec::new(),
+            pile: deck.iter().collect(),
+            dice: Dice::new(),
+            combat_statuses: Vec::new(),
+            summons: Vec::new(),
+            supports: Vec::new(),
+            ended_round: false,
+            defeated_this_round: false,
+            pending_rerolls: 0,
+            skills_used: Vec::new(),
+            played: Vec::new(),
+        }
+    }
+
+    pub fn characters(&self) -> &[CharacterState] {
+        &self.characters
+    }
+
+    pub fn character(&self, index: usize) -> &CharacterState {
+        &self.characters[index]
+    }
+
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    pub fn active(&self) -> &CharacterState {
+        &self.characters[self.active]
+    }
+
+    pub fn hand(&self) -> &[ActionCard] {
+        &self.hand
+    }
+
+    /// The draw pile, with the next card to be drawn at the end
+    pub fn pile(&self) -> &[ActionCard] {
+        &self.pile
+    }
+
+    pub fn dice(&self) -> &Dice {
+        &self.dice
+    }
+
+    /// Every card this player played during the match, in order. Both players see them, unlike
+    /// cards discarded for Elemental Tuning
+    pub fn played(&self) -> &[ActionCard] {
+        &self.played
+    }
+
+    pub fn combat_statuses(&self) -> &[Entity] {
+        &self.combat_statuses
+    }
+
+    pub fn summons(&self) -> &[Entity] {
+        &self.summons
+    }
+
+    pub fn supports(&self) -> &[Entity] {
+        &self.supports
+    }
+
+    /// Whether this player already declared the end of their round
+    pub fn ended_round(&self) -> bool {
+        self.ended_round
+    }
+
+    /// Whether one of this player's characters was defeated during the current round
+    pub fn defeated_this_round(&self) -> bool {
+        self.defeated_this_round
+    }
+
+    /// Rerolls the player has left, which they use by answering [`Decision::Reroll`]
+    pub fn pending_rerolls(&self) -> u8 {
+        self.pending_rerolls
+    }
+
+    /// Whether a character already used this kind of skill during the current round
+    pub fn used_this_round(&self, character: usize, skill: SkillKind) -> bool {
+        self.skills_used.contains(&(character, skill))
+    }
+
+    /// How many times a character used this kind of skill during the current round
+    pub fn uses_this_round(&self, character: usize, skill: SkillKind) -> usize {
+        self.skills_used.iter().filter(|used| **used == (character, skill)).count()
+    }
+
+    /// The elements of every character still alive, which are the dice worth keeping
+    pub fn valuable_elements(&self) -> Vec<Element> {
+        self.characters.iter().filter(|ch| ch.is_alive()).map(|ch| ch.card().element()).collect()
+    }
+
+    pub fn has_combat_status(&self, kind: EntityKind) -> bool {
+        self.combat_statuses.iter().any(|status| status.kind() == kind)
+    }
+
+    /// Every entity on this side of the field, in the order they are triggered: characters
+    /// starting from the active one (equipment first, then statuses), combat statuses, summons
+    /// and finally supports
+    pub fn entities(&self) -> impl Iterator<Item=&Entity> + '_ {
+        let count = self.characters.len();
+
+        (0..count)
+            .map(move |offset| &self.characters[(self.active + offset) % count])
+            .flat_map(|ch| ch.equipment.iter().chain(ch.statuses.iter()))
+            .chain(self.combat_statuses.iter())
+            .chain(self.summons.iter())
+            .chain(self.supports.iter())
+    }
+
+    pub fn entity_location(&self, id: EntityId) -> Location {
+        for (index, ch) in self.characters.iter().enumerate() {
+            if ch.equipment.iter().any(|entity| entity.id() == id) {
+                return Location::Equipment(index)
+            }
+            if ch.statuses.iter().any(|entity| entity.id() == id) {
+                return Location::CharacterStatus(index)
+            }
+        }
+
+        if self.combat_statuses.iter().any(|entity| entity.id() == id) {
+            Location::CombatStatus
+        } else if self.summons.iter().any(|entity| entity.id() == id) {
+            Location::Summon
+        } else if self.supports.iter().any(|entity| entity.id() == id) {
+            Location::Support
+        } else {
+            Location::Nowhere
+        }
+    }
+
+    pub(crate) fn zones_mut(&mut self) -> impl Iterator<Item=&mut Vec<Entity>> + '_ {
+        self.characters.iter_mut()
+            .flat_map(|ch| [&mut ch.equipment, &mut ch.statuses])
+            .chain([&mut self.combat_statuses, &mut self.summons, &mut self.supports])
+    }
+
+    pub(crate) fn entity_mut(&mut self, id: EntityId) -> Option<&mut Entity> {
+        self.zones_mut().flat_map(|zone| zone.iter_mut()).find(|entity| entity.id() == id)
+    }
+}
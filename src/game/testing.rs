@@ -0,0 +1,136 @@
+//! Shortcuts for setting up matches in tests
+
+use crate::*;
+
+/// Action cards that don't depend on the characters in the deck
+const ACTIONS: [ActionCard; 15] = [
+    ActionCard::Event(EventCard::Normal(NormalEventCard::Strategize)),
+    ActionCard::Event(EventCard::Normal(NormalEventCard::LeaveItToMe)),
+    ActionCard::Event(EventCard::Normal(NormalEventCard::TossUp)),
+    ActionCard::Event(EventCard::Normal(NormalEventCard::Starsigns)),
+    ActionCard::Event(EventCard::Normal(NormalEventCard::ChangingShifts)),
+    ActionCard::Event(EventCard::Normal(NormalEventCard::QuickKnit)),
+    ActionCard::Event(EventCard::Normal(NormalEventCard::SendOff)),
+    ActionCard::Event(EventCard::Food(FoodCard::SweetMadame)),
+    ActionCard::Event(EventCard::Food(FoodCard::MondstadtHashBrown)),
+    ActionCard::Event(EventCard::Food(FoodCard::JueyunGuoba)),
+    ActionCard::Event(EventCard::Food(FoodCard::LotusFlowerCrisp)),
+    ActionCard::Support(SupportCard::Companion(CompanionCard::Paimon)),
+    ActionCard::Support(SupportCard::Location(LocationCard::DawnWinery)),
+    ActionCard::Support(SupportCard::Item(ItemCard::NRE)),
+    ActionCard::Equipment(EquipmentCard::Weapon(WeaponCard::WhiteTassel)),
+];
+
+/// Characters of the opponent in [`duel`], none of which has a passive skill
+pub(crate) const OPPONENTS: [CharacterCard; 3] = [CharacterCard::Bennett, CharacterCard::Xingqiu, CharacterCard::Collei];
+
+pub(crate) fn deck(characters: [CharacterCard; 3]) -> Deck {
+    let cards = characters.into_iter().map(Card::from)
+        .chain(ACTIONS.into_iter().chain(ACTIONS).map(Card::from));
+
+    Deck::from_iter(cards).unwrap()
+}
+
+/// A match where the first player's active character is `card`, backed by two characters with
+/// no passive skill, against [`OPPONENTS`]
+pub(crate) fn duel(card: CharacterCard) -> GameState {
+    let mut fillers = [CharacterCard::Xiangling, CharacterCard::Sucrose, CharacterCard::Barbara]
+        .into_iter()
+        .filter(|filler| *filler != card);
+
+    duel_with([card, fillers.next().unwrap(), fillers.next().unwrap()])
+}
+
+/// Same as [`duel`], choosing every character of the first player. The first one is active
+pub(crate) fn duel_with(characters: [CharacterCard; 3]) -> GameState {
+    let mut game = GameState::new(&deck(characters), &deck(OPPONENTS), 7);
+    skip_decisions(&mut game);
+
+    // decks sort their characters, put them back in the order given
+    arrange(&mut game, PlayerId::First, characters);
+    arrange(&mut game, PlayerId::Second, OPPONENTS);
+    game
+}
+
+fn arrange(game: &mut GameState, player: PlayerId, order: [CharacterCard; 3]) {
+    game.player_mut(player).characters.sort_by_key(|ch| order.iter().position(|card| *card == ch.card()));
+}
+
+/// Keeps every starting card and die, until there's nothing left to decide or a character has
+/// to be chosen
+pub(crate) fn skip_decisions(game: &mut GameState) {
+    loop {
+        match game.decision() {
+            Some(Decision::Mulligan { .. }) => game.apply(Action::SwapCards { cards: 0 }).unwrap(),
+            Some(Decision::Reroll { .. }) => game.apply(Action::Reroll { dice: Dice::new() }).unwrap(),
+            _ => break,
+        }
+    }
+}
+
+/// Makes the first player's active character use a skill, giving them enough dice to do so
+pub(crate) fn use_skill(game: &mut GameState, index: usize) {
+    game.turn = PlayerId::First;
+    game.player_mut(PlayerId::First).dice = [Die::Omni; 10].into_iter().collect();
+    game.apply(Action::UseSkill { skill: index }).unwrap();
+}
+
+/// Fills the energy of the first player's active character
+pub(crate) fn charge(game: &mut GameState) {
+    let state = game.player_mut(PlayerId::First);
+    let active = state.active;
+    state.characters[active].energy = state.characters[active].max_energy();
+}
+
+/// Ends the round for both players, going through the End Phase
+pub(crate) fn end_round(game: &mut GameState) {
+    game.turn = PlayerId::First;
+    game.apply(Action::EndRound).unwrap();
+    game.apply(Action::EndRound).unwrap();
+    skip_decisions(game);
+}
+
+/// The second player's active character
+pub(crate) fn opponent(game: &GameState) -> &CharacterState {
+    game.player(PlayerId::Second).active()
+}
+
+pub(crate) fn opponent_hp(game: &GameState) -> u8 {
+    opponent(game).hp()
+}
+
+/// HP of the second player's standby characters, in order
+pub(crate) fn standby_hp(game: &GameState) -> Vec<u8> {
+    game.standby_characters(PlayerId::Second).into_iter()
+        .map(|index| game.player(PlayerId::Second).character(index).hp())
+        .collect()
+}
+
+/// Summons of the first player, in order
+pub(crate) fn summons(game: &GameState) -> Vec<SummonKind> {
+    game.player(PlayerId::First).summons().iter()
+        .filter_map(|summon| match summon.kind() {
+            EntityKind::Summon(kind) => Some(kind),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Counter of one of the first player's summons
+pub(crate) fn summon_counter(game: &GameState, kind: SummonKind) -> Option<u8> {
+    find(game.player(PlayerId::First).summons(), EntityKind::Summon(kind)).map(|summon| summon.counter())
+}
+
+/// Usages of one of the first player's summons
+pub(crate) fn summon_usages(game: &GameState, kind: SummonKind) -> Option<u8> {
+    find(game.player(PlayerId::First).summons(), EntityKind::Summon(kind)).and_then(|summon| summon.usages())
+}
+
+/// Usages of one of the first player's combat statuses
+pub(crate) fn combat_status_usages(game: &GameState, kind: StatusKind) -> Option<u8> {
+    find(game.player(PlayerId::First).combat_statuses(), EntityKind::Status(kind)).and_then(|status| status.usages())
+}
+
+fn find(zone: &[Entity], kind: EntityKind) -> Option<&Entity> {
+    zone.iter().find(|entity| entity.kind() == kind)
+}
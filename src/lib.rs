@@ -9,5 +9,24 @@ mod die;
 pub use element::Element;
 mod element;
 
-pub use deck::{Deck, DeckError};
-mod deck;
\ No newline at end of file
+pub use deck::{Deck, DeckError, DeckRules};
+mod deck;
+
+pub use game::*;
+mod game;
+
+pub use agent::*;
+mod agent;
+
+mod export;
+
+// the card generator of build.rs, so its validation can be tested on broken data
+#[cfg(test)]
+#[path = "../build/cards.rs"]
+mod build_cards;
+
+#[cfg(feature = "server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+pub use server::{Server, ClientMessage, ServerMessage, DeckSpec, MatchId, PROTOCOL_VERSION};
+#[cfg(feature = "server")]
+mod server;
@@ -0,0 +1,480 @@
+//! A local match server, so that clients in any language can play matches
+//!
+//! Clients connect over TCP and speak a protocol of JSON lines: every message is one JSON
+//! object on its own line, tagged by its `type`. [`ClientMessage`] lists what clients can
+//! send, and [`ServerMessage`] what they get back. The server greets every client with its
+//! [`PROTOCOL_VERSION`], which only changes when existing messages do.
+//!
+//! A typical session looks like this, with `>` for client messages and `<` for server ones:
+//!
+//! ```text
+//! < {"type":"welcome","protocol":1}
+//! > {"type":"create","decks":["<deck url or code>",{"characters":[...],"actions":[...]}]}
+//! < {"type":"created","match":0}
+//! > {"type":"join","match":0,"player":"First"}
+//! < {"type":"joined","match":0,"player":"First"}
+//! < {"type":"update","match":0,"observation":{...},"actions":[...],"events":[...]}
+//! > {"type":"act","match":0,"action":"EndRound"}
+//! ```
+//!
+//! Players get an `update` after joining and after every action taken in the match, with
+//! what they can see of it and the legal actions if they are the acting player. Once the
+//! match is over both players get a `finished` message with the replay.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::{mpsc::{self, Sender}, Arc, Mutex};
+use std::thread;
+use serde::{Deserialize, Serialize};
+use crate::*;
+
+/// Version of the protocol spoken by [`Server`]
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Identifies a match hosted by a [`Server`]
+pub type MatchId = u64;
+
+/// A deck sent by a client: a deck builder url, a deck code (the `deck` parameter of such a
+/// url), or the names of its cards
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DeckSpec {
+    Url(String),
+    Cards { characters: Vec<String>, actions: Vec<String> },
+}
+
+impl DeckSpec {
+    /// The deck described, if it's a valid one
+    pub fn deck(&self) -> Result<Deck, String> {
+        let cards: Vec<Card> = match self {
+            Self::Url(url) if url.contains("deck-builder?deck=") => read_url(url)?,
+            Self::Url(code) => read_url(&format!("https://genshin.hotgames.gg/tcg/deck-builder?deck={code}&ver=1"))?,
+            Self::Cards { characters, actions } => characters.iter().chain(actions)
+                .map(|name| Card::from_name(name).ok_or_else(|| format!("unknown card `{name}`")))
+                .collect::<Result<_, _>>()?,
+        };
+
+        Deck::from_iter(cards).map_err(|error| error.to_string())
+    }
+}
+
+fn read_url(url: &str) -> Result<Vec<Card>, String> {
+    match deck_from_url(url) {
+        Ok(cards) => Ok(cards.collect()),
+        Err(UrlDeckError::InvalidUrl(_)) => Err("invalid deck builder url".to_string()),
+        Err(UrlDeckError::UnknownVersion(version)) => Err(format!("unknown deck builder version `{version}`")),
+        Err(UrlDeckError::UnknownCard(card)) => Err(format!("unknown card code `{card}`")),
+    }
+}
+
+/// A message sent by a client to the [`Server`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Creates a match between two decks, answered with [`ServerMessage::Created`]. The seed
+    /// is random if not given
+    Create {
+        decks: [DeckSpec; 2],
+        #[serde(default)]
+        seed: Option<u64>,
+    },
+    /// Takes one of the seats of a match. A seat is freed when its client disconnects
+    Join {
+        #[serde(rename = "match")]
+        id: MatchId,
+        player: PlayerId,
+    },
+    /// Takes an action in a match the client joined, when they are the acting player
+    Act {
+        #[serde(rename = "match")]
+        id: MatchId,
+        action: Action,
+    },
+}
+
+/// A message sent by the [`Server`] to a client
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// Sent once, right after connecting
+    Welcome { protocol: u32 },
+    Created {
+        #[serde(rename = "match")]
+        id: MatchId,
+    },
+    Joined {
+        #[serde(rename = "match")]
+        id: MatchId,
+        player: PlayerId,
+    },
+    /// What the player sees of the match, the actions they can take (empty unless they are
+    /// the acting player) and the events since their last update. Events only the opponent
+    /// should know about, like which card they used for Elemental Tuning, are left out
+    Update {
+        #[serde(rename = "match")]
+        id: MatchId,
+        observation: Box<Observation>,
+        actions: Vec<Action>,
+        events: Vec<Event>,
+    },
+    /// The match is over. `replay` is in the text format of [`Replay`]
+    Finished {
+        #[serde(rename = "match")]
+        id: MatchId,
+        outcome: Outcome,
+        replay: String,
+    },
+    /// A message couldn't be understood or carried out. The connection stays open
+    Error { message: String },
+}
+
+/// Hosts matches for any number of clients, one thread per connection
+///
+/// ```no_run
+/// use genius_invokation::Server;
+///
+/// let server = Server::bind("127.0.0.1:7878")?;
+/// server.run()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Server {
+    listener: TcpListener,
+    lobby: Arc<Mutex<Lobby>>,
+}
+
+impl Server {
+    pub fn bind(address: impl ToSocketAddrs) -> io::Result<Self> {
+        Ok(Self { listener: TcpListener::bind(address)?, lobby: Arc::default() })
+    }
+
+    /// The address the server listens on, useful when bound to port 0
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Accepts connections until the listener fails
+    pub fn run(&self) -> io::Result<()> {
+        for stream in self.listener.incoming() {
+            let stream = stream?;
+            let lobby = Arc::clone(&self.lobby);
+
+            // a connection closing, cleanly or not, only concerns its own client
+            thread::spawn(move || serve(stream, lobby));
+        }
+
+        Ok(())
+    }
+}
+
+fn serve(stream: TcpStream, lobby: Arc<Mutex<Lobby>>) -> io::Result<()> {
+    let (sender, receiver) = mpsc::channel::<ServerMessage>();
+    let mut writer = stream.try_clone()?;
+
+    thread::spawn(move || {
+        for message in receiver {
+            let line = serde_json::to_string(&message).expect("messages are always serializable");
+
+            if writeln!(writer, "{line}").is_err() {
+                break
+            }
+        }
+    });
+
+    let client = lobby.lock().unwrap().connect(sender.clone());
+    let _ = sender.send(ServerMessage::Welcome { protocol: PROTOCOL_VERSION });
+
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else { break };
+
+        if line.trim().is_empty() {
+            continue
+        }
+
+        match serde_json::from_str::<ClientMessage>(&line) {
+            Ok(message) => lobby.lock().unwrap().handle(client, message),
+            Err(error) => { let _ = sender.send(ServerMessage::Error { message: format!("invalid message: {error}") }); },
+        }
+    }
+
+    lobby.lock().unwrap().disconnect(client);
+    Ok(())
+}
+
+type ClientId = u64;
+
+/// Every client and match of a server
+#[derive(Debug, Default)]
+struct Lobby {
+    clients: HashMap<ClientId, Sender<ServerMessage>>,
+    matches: HashMap<MatchId, Hosted>,
+    next_client: ClientId,
+    next_match: MatchId,
+}
+
+#[derive(Debug)]
+struct Hosted {
+    decks: [Deck; 2],
+    seed: u64,
+    game: GameState,
+    seats: [Option<ClientId>; 2],
+    /// How many events each seat has been sent
+    shown: [usize; 2],
+}
+
+impl Lobby {
+    fn connect(&mut self, sender: Sender<ServerMessage>) -> ClientId {
+        let client = self.next_client;
+        self.next_client += 1;
+        self.clients.insert(client, sender);
+        client
+    }
+
+    fn disconnect(&mut self, client: ClientId) {
+        self.clients.remove(&client);
+
+        for hosted in self.matches.values_mut() {
+            for seat in &mut hosted.seats {
+                if *seat == Some(client) {
+                    *seat = None;
+                }
+            }
+        }
+    }
+
+    fn send(&self, client: ClientId, message: ServerMessage) {
+        if let Some(sender) = self.clients.get(&client) {
+            let _ = sender.send(message);
+        }
+    }
+
+    fn handle(&mut self, client: ClientId, message: ClientMessage) {
+        let result = match message {
+            ClientMessage::Create { decks, seed } => self.create(client, &decks, seed),
+            ClientMessage::Join { id, player } => self.join(client, id, player),
+            ClientMessage::Act { id, action } => self.act(client, id, action),
+        };
+
+        if let Err(message) = result {
+            self.send(client, ServerMessage::Error { message });
+        }
+    }
+
+    fn create(&mut self, client: ClientId, decks: &[DeckSpec; 2], seed: Option<u64>) -> Result<(), String> {
+        let decks = [decks[0].deck()?, decks[1].deck()?];
+        let seed = seed.unwrap_or_else(random_seed);
+        let id = self.next_match;
+        self.next_match += 1;
+
+        let game = GameState::new(&decks[0], &decks[1], seed);
+        self.matches.insert(id, Hosted { decks, seed, game, seats: [None; 2], shown: [0; 2] });
+        self.send(client, ServerMessage::Created { id });
+        Ok(())
+    }
+
+    fn join(&mut self, client: ClientId, id: MatchId, player: PlayerId) -> Result<(), String> {
+        let hosted = self.matches.get_mut(&id).ok_or_else(|| format!("there's no match {id}"))?;
+        let seat = &mut hosted.seats[player.index()];
+
+        match *seat {
+            Some(other) if other != client => return Err(format!("{player:?} player of match {id} is taken")),
+            _ => *seat = Some(client),
+        }
+
+        // a player rejoining gets every event again
+        hosted.shown[player.index()] = 0;
+        self.send(client, ServerMessage::Joined { id, player });
+        self.update(id, player);
+        Ok(())
+    }
+
+    fn act(&mut self, client: ClientId, id: MatchId, action: Action) -> Result<(), String> {
+        let hosted = self.matches.get_mut(&id).ok_or_else(|| format!("there's no match {id}"))?;
+        let player = hosted.game.acting_player();
+
+        if hosted.game.is_over() {
+            return Err(format!("match {id} is over"))
+        }
+
+        if hosted.seats[player.index()] != Some(client) {
+            return Err(format!("it's the {player:?} player's turn to act in match {id}"))
+        }
+
+        hosted.game.apply(action).map_err(|error| error.to_string())?;
+
+        for player in [PlayerId::First, PlayerId::Second] {
+            self.update(id, player);
+        }
+
+        Ok(())
+    }
+
+    /// Sends the player of a match whatever they haven't seen yet, if they are seated
+    fn update(&mut self, id: MatchId, player: PlayerId) {
+        let Some(hosted) = self.matches.get_mut(&id) else { return };
+        let Some(client) = hosted.seats[player.index()] else { return };
+
+        let events = hosted.game.events()[hosted.shown[player.index()]..].iter()
+            .filter(|event| visible(event, player))
+            .cloned()
+            .collect();
+        hosted.shown[player.index()] = hosted.game.events().len();
+
+        let acting = !hosted.game.is_over() && hosted.game.acting_player() == player;
+        let update = ServerMessage::Update {
+            id,
+            observation: Box::new(hosted.game.observe(player)),
+            actions: if acting { hosted.game.legal_actions() } else { Vec::new() },
+            events,
+        };
+
+        let finished = hosted.game.outcome().map(|outcome| ServerMessage::Finished {
+            id,
+            outcome,
+            replay: Replay::record(&hosted.decks[0], &hosted.decks[1], hosted.seed, &hosted.game).to_string(),
+        });
+
+        self.send(client, update);
+
+        if let Some(finished) = finished {
+            self.send(client, finished);
+        }
+    }
+}
+
+/// Whether the viewer may know about an event. Only the opponent's own choices about hidden
+/// cards and dice are left out, what they lead to is public
+fn visible(event: &Event, viewer: PlayerId) -> bool {
+    match event {
+        Event::Action { player, action } if *player != viewer => !matches!(action,
+            Action::ElementalTuning { .. } | Action::SwapCards { .. } | Action::Reroll { .. }
+        ),
+        _ => true,
+    }
+}
+
+fn random_seed() -> u64 {
+    use std::hash::{BuildHasher, RandomState};
+    RandomState::new().hash_one(std::time::SystemTime::now())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::testing::{deck, OPPONENTS};
+
+    struct Client {
+        reader: BufReader<TcpStream>,
+        writer: TcpStream,
+    }
+
+    impl Client {
+        fn connect(address: SocketAddr) -> Self {
+            let writer = TcpStream::connect(address).unwrap();
+            let mut client = Self { reader: BufReader::new(writer.try_clone().unwrap()), writer };
+            assert_eq!(client.receive(), ServerMessage::Welcome { protocol: PROTOCOL_VERSION });
+            client
+        }
+
+        fn send(&mut self, message: &ClientMessage) {
+            writeln!(self.writer, "{}", serde_json::to_string(message).unwrap()).unwrap();
+        }
+
+        fn receive(&mut self) -> ServerMessage {
+            let mut line = String::new();
+            self.reader.read_line(&mut line).unwrap();
+            serde_json::from_str(&line).unwrap()
+        }
+    }
+
+    fn start() -> SocketAddr {
+        let server = Server::bind("127.0.0.1:0").unwrap();
+        let address = server.local_addr().unwrap();
+        thread::spawn(move || server.run());
+        address
+    }
+
+    fn decks() -> [DeckSpec; 2] {
+        [deck([CharacterCard::Keqing, CharacterCard::Fischl, CharacterCard::Ganyu]), deck(OPPONENTS)].map(|deck| DeckSpec::Cards {
+            characters: deck.characters().iter().map(|card| card.name().to_string()).collect(),
+            actions: deck.iter().map(|card| card.name().to_string()).collect(),
+        })
+    }
+
+    #[test]
+    fn plays_a_whole_match() {
+        let address = start();
+        let mut clients = [Client::connect(address), Client::connect(address)];
+        let decks = decks();
+
+        clients[0].send(&ClientMessage::Create { decks, seed: Some(7) });
+        let ServerMessage::Created { id } = clients[0].receive() else { panic!("match wasn't created") };
+
+        let mut latest = Vec::new();
+
+        for (client, player) in clients.iter_mut().zip([PlayerId::First, PlayerId::Second]) {
+            client.send(&ClientMessage::Join { id, player });
+            assert_eq!(client.receive(), ServerMessage::Joined { id, player });
+            latest.push(client.receive());
+        }
+
+        for _ in 0..5000 {
+            let acting = latest.iter().position(|message| matches!(message, ServerMessage::Update { actions, .. } if !actions.is_empty()));
+            let Some(acting) = acting else { break };
+            let ServerMessage::Update { actions, .. } = &latest[acting] else { unreachable!() };
+
+            clients[acting].send(&ClientMessage::Act { id, action: actions[0] });
+
+            for (client, message) in clients.iter_mut().zip(&mut latest) {
+                *message = client.receive();
+                assert!(matches!(message, ServerMessage::Update { .. }), "{message:?}");
+            }
+        }
+
+        for client in &mut clients {
+            let ServerMessage::Finished { outcome, replay, .. } = client.receive() else { panic!("match isn't over") };
+            let game = Replay::parse(&replay).unwrap().play().unwrap();
+            assert_eq!(game.outcome(), Some(outcome));
+        }
+    }
+
+    #[test]
+    fn reports_errors() {
+        let address = start();
+        let mut first = Client::connect(address);
+        let mut second = Client::connect(address);
+        let decks = decks();
+
+        writeln!(first.writer, "{{\"type\":\"dance\"}}").unwrap();
+        assert!(matches!(first.receive(), ServerMessage::Error { .. }));
+
+        first.send(&ClientMessage::Join { id: 0, player: PlayerId::First });
+        assert_eq!(first.receive(), ServerMessage::Error { message: "there's no match 0".to_string() });
+
+        first.send(&ClientMessage::Create { decks, seed: None });
+        let ServerMessage::Created { id } = first.receive() else { panic!("match wasn't created") };
+
+        first.send(&ClientMessage::Join { id, player: PlayerId::First });
+        first.receive();
+        first.receive();
+
+        second.send(&ClientMessage::Join { id, player: PlayerId::First });
+        assert_eq!(second.receive(), ServerMessage::Error { message: format!("First player of match {id} is taken") });
+
+        second.send(&ClientMessage::Act { id, action: Action::EndRound });
+        assert!(matches!(second.receive(), ServerMessage::Error { .. }));
+    }
+
+    #[test]
+    fn hides_the_opponents_choices() {
+        let tuning = Event::Action {
+            player: PlayerId::First,
+            action: Action::ElementalTuning { card: NormalEventCard::Strategize.into(), die: Die::Omni },
+        };
+
+        assert!(visible(&tuning, PlayerId::First));
+        assert!(!visible(&tuning, PlayerId::Second));
+        assert!(visible(&Event::Action { player: PlayerId::First, action: Action::EndRound }, PlayerId::Second));
+    }
+}